@@ -61,6 +61,10 @@ pub struct ttfp_name_record {
 #[repr(C)]
 pub enum ttfp_raster_image_format {
     PNG = 0,
+    JPEG,
+    TIFF,
+    BITMAP_MONO,
+    BITMAP_MONO_PACKED,
 }
 
 /// @brief A glyph image.
@@ -752,6 +756,14 @@ pub extern "C" fn ttfp_get_glyph_raster_image(
                     pixels_per_em: image.pixels_per_em,
                     format: match image.format {
                         ttf_parser::RasterImageFormat::PNG => ttfp_raster_image_format::PNG,
+                        ttf_parser::RasterImageFormat::JPEG => ttfp_raster_image_format::JPEG,
+                        ttf_parser::RasterImageFormat::TIFF => ttfp_raster_image_format::TIFF,
+                        ttf_parser::RasterImageFormat::BitmapMono => {
+                            ttfp_raster_image_format::BITMAP_MONO
+                        }
+                        ttf_parser::RasterImageFormat::BitmapMonoPacked => {
+                            ttfp_raster_image_format::BITMAP_MONO_PACKED
+                        }
                     },
                     data: image.data.as_ptr() as _,
                     len: image.data.len() as u32,
@@ -20,6 +20,10 @@
  */
 typedef enum {
     TTFP_RASTER_IMAGE_FORMAT_PNG = 0,
+    TTFP_RASTER_IMAGE_FORMAT_JPEG,
+    TTFP_RASTER_IMAGE_FORMAT_TIFF,
+    TTFP_RASTER_IMAGE_FORMAT_BITMAP_MONO,
+    TTFP_RASTER_IMAGE_FORMAT_BITMAP_MONO_PACKED,
 } ttfp_raster_image_format;
 
 /**
@@ -69,8 +69,73 @@ impl<'a> LayoutTable<'a> {
             })
         }
     }
+
+    /// Returns an iterator over all supported script tags.
+    ///
+    /// Includes the `DFLT` script when present.
+    pub fn scripts(&self) -> impl Iterator<Item = Tag> + 'a {
+        self.scripts.into_iter().map(|script| script.tag)
+    }
+
+    /// Returns an iterator over language system tags supported by a script.
+    ///
+    /// The default language system is reported as `dflt` and goes first.
+    /// Returns an empty iterator when the script is not present.
+    pub fn languages(&self, script: Tag) -> impl Iterator<Item = Tag> + 'a {
+        let script = self.scripts.find(script);
+        let default = script
+            .and_then(|script| script.default_language)
+            .map(|lang_sys| lang_sys.tag);
+        let languages = script.map(|script| script.languages.into_iter());
+        default
+            .into_iter()
+            .chain(languages.into_iter().flatten().map(|lang_sys| lang_sys.tag))
+    }
+
+    /// Checks that the table has a `DFLT` script.
+    ///
+    /// This script is used by shapers when the text's script is not supported by the table.
+    pub fn has_default_script(&self) -> bool {
+        self.scripts.index(DEFAULT_SCRIPT).is_some()
+    }
+
+    /// Returns an iterator over features of a script's language system.
+    ///
+    /// Use the `dflt` language tag to get features of the default language system.
+    /// The required feature, if any, goes first.
+    ///
+    /// Follows the standard shaping fallback rules: when the script is not present,
+    /// the `DFLT` script is used instead. And when the language system is not present,
+    /// the script's default language system is used instead.
+    ///
+    /// Returns an empty iterator when neither the script nor `DFLT` is present.
+    pub fn features(&self, script: Tag, language: Tag) -> impl Iterator<Item = Feature<'a>> + 'a {
+        let features = self.features;
+        let lang_sys = self.resolve_language_system(script, language);
+        let required = lang_sys.and_then(|lang_sys| lang_sys.required_feature);
+        let indices = lang_sys
+            .map(|lang_sys| lang_sys.feature_indices)
+            .unwrap_or_default();
+        required
+            .into_iter()
+            .chain(indices)
+            .filter_map(move |index| features.get(index))
+    }
+
+    fn resolve_language_system(&self, script: Tag, language: Tag) -> Option<LanguageSystem<'a>> {
+        let script = self
+            .scripts
+            .find(script)
+            .or_else(|| self.scripts.find(DEFAULT_SCRIPT))
+            // Some old fonts are using `dflt` instead of `DFLT`.
+            .or_else(|| self.scripts.find(DEFAULT_LANGUAGE))?;
+        script.language(language).or(script.default_language)
+    }
 }
 
+const DEFAULT_SCRIPT: Tag = Tag::from_bytes(b"DFLT");
+const DEFAULT_LANGUAGE: Tag = Tag::from_bytes(b"dflt");
+
 /// An index in [`ScriptList`].
 pub type ScriptIndex = u16;
 /// An index in [`LanguageSystemList`].
@@ -217,13 +282,26 @@ pub struct Script<'a> {
     pub languages: LanguageSystemList<'a>,
 }
 
+impl<'a> Script<'a> {
+    /// Returns a language system by tag.
+    ///
+    /// The `dflt` tag refers to the default language system.
+    pub fn language(&self, tag: Tag) -> Option<LanguageSystem<'a>> {
+        if tag == DEFAULT_LANGUAGE {
+            self.default_language
+        } else {
+            self.languages.find(tag)
+        }
+    }
+}
+
 impl<'a> RecordListItem<'a> for Script<'a> {
     fn parse(tag: Tag, data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
         let mut default_language = None;
         if let Some(offset) = s.read::<Option<Offset16>>()? {
             default_language =
-                LanguageSystem::parse(Tag::from_bytes(b"dflt"), data.get(offset.to_usize()..)?);
+                LanguageSystem::parse(DEFAULT_LANGUAGE, data.get(offset.to_usize()..)?);
         }
         let mut languages = RecordList::parse(s.tail()?)?;
         // Offsets are relative to this table.
@@ -28,7 +28,8 @@ Font parsing starts with a [`Face`].
 - The library forbids unsafe code.
 - No heap allocations, so crash due to OOM is not possible.
 - All recursive methods have a depth limit.
-- Technically, should use less than 64KiB of stack in worst case scenario.
+- Technically, should use less than 64KiB of stack in worst case scenario,
+  unless the composite glyph depth limit was raised via `Face::set_outline_depth_limit`.
 - Most of arithmetic operations are checked.
 - Most of numeric casts are checked.
 */
@@ -62,7 +63,12 @@ macro_rules! try_opt_or {
 mod aat;
 #[cfg(feature = "opentype-layout")]
 mod ggg;
+pub mod outline;
 mod parser;
+#[cfg(feature = "unicode-scripts")]
+pub mod script;
+#[cfg(all(feature = "std", feature = "opentype-layout"))]
+mod shaping;
 mod tables;
 #[cfg(feature = "variable-fonts")]
 mod var_store;
@@ -74,20 +80,24 @@ use parser::{NumFrom, Offset, Offset32, Stream, TryNumFrom};
 #[cfg(feature = "variable-fonts")]
 pub use fvar::VariationAxis;
 
-pub use name::{name_id, PlatformId};
-pub use os2::{ScriptMetrics, Style, Weight, Width};
+pub use name::{name_id, Language, PlatformId};
+pub use os2::{Permissions, ScriptMetrics, Style, Weight, Width};
 pub use tables::CFFError;
 #[cfg(feature = "apple-layout")]
 pub use tables::{ankr, feat, kerx, morx, trak};
 #[cfg(feature = "variable-fonts")]
-pub use tables::{avar, cff2, fvar, gvar, hvar, mvar};
+pub use tables::{avar, cff2, fvar, gvar, hvar, mvar, stat};
 pub use tables::{cbdt, cblc, cff1 as cff, vhea};
 pub use tables::{
-    cmap, glyf, head, hhea, hmtx, kern, loca, maxp, name, os2, post, sbix, svg, vorg,
+    cmap, cpal, gasp, glyf, hdmx, head, hhea, hmtx, kern, loca, maxp, meta, name, os2, post, sbix,
+    svg, vdmx, vorg,
 };
 #[cfg(feature = "opentype-layout")]
 pub use tables::{gdef, gpos, gsub, math};
 
+#[cfg(all(feature = "std", feature = "opentype-layout"))]
+pub use shaping::GlyphPosition;
+
 #[cfg(feature = "opentype-layout")]
 pub mod opentype_layout {
     //! This module contains
@@ -151,6 +161,8 @@ impl FromData for Magic {
 /// The number is stored as f2.16
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "i16", into = "i16"))]
 pub struct NormalizedCoordinate(i16);
 
 impl From<i16> for NormalizedCoordinate {
@@ -163,6 +175,14 @@ impl From<i16> for NormalizedCoordinate {
     }
 }
 
+impl From<NormalizedCoordinate> for i16 {
+    /// Returns the coordinate value as f2.14.
+    #[inline]
+    fn from(n: NormalizedCoordinate) -> Self {
+        n.0
+    }
+}
+
 impl From<f32> for NormalizedCoordinate {
     /// Creates a new coordinate.
     ///
@@ -191,6 +211,7 @@ impl NormalizedCoordinate {
 /// Variation { axis: Tag::from_bytes(b"wght"), value: 500.0 };
 /// ```
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Variation {
     /// An axis tag name.
     pub axis: Tag,
@@ -198,6 +219,36 @@ pub struct Variation {
     pub value: f32,
 }
 
+/// A variation axis description.
+///
+/// Unlike [`VariationAxis`], has the axis name already resolved.
+///
+/// Returned by [`Face::axis_info`].
+#[cfg(feature = "variable-fonts")]
+#[derive(Clone, PartialEq, Debug)]
+// Not `Copy` even without `std`, so that derives do not depend on the `name` field.
+#[cfg_attr(not(feature = "std"), allow(missing_copy_implementations))]
+pub struct AxisInfo {
+    /// An axis tag.
+    pub tag: Tag,
+    /// An axis name ID in the `name` table.
+    pub name_id: u16,
+    /// An axis name.
+    ///
+    /// English name is preferred. `None` when a name is not present or has
+    /// an unsupported encoding.
+    #[cfg(feature = "std")]
+    pub name: Option<std::string::String>,
+    /// A minimum axis value in user space.
+    pub min: f32,
+    /// A default axis value in user space.
+    pub default: f32,
+    /// A maximum axis value in user space.
+    pub max: f32,
+    /// Indicates that the axis should not be exposed directly in user interfaces.
+    pub hidden: bool,
+}
+
 /// A 4-byte tag.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -298,6 +349,51 @@ impl core::fmt::Display for Tag {
     }
 }
 
+// Serialized as a 4-character string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Tag {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Tag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TagVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TagVisitor {
+            type Value = Tag;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a 4-character tag")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                // Each character must fit into a single byte, just like in `Tag::to_chars`.
+                let mut bytes = [0; 4];
+                let mut chars = v.chars();
+                for b in bytes.iter_mut() {
+                    let c = chars
+                        .next()
+                        .ok_or_else(|| E::invalid_length(v.len(), &self))?;
+                    *b = core::convert::TryFrom::try_from(u32::from(c))
+                        .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))?;
+                }
+
+                if chars.next().is_some() {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+
+                Ok(Tag::from_bytes(&bytes))
+            }
+        }
+
+        deserializer.deserialize_str(TagVisitor)
+    }
+}
+
 impl FromData for Tag {
     const SIZE: usize = 4;
 
@@ -312,6 +408,7 @@ impl FromData for Tag {
 /// Used for underline and strikeout.
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineMetrics {
     /// Line position.
     pub position: i16,
@@ -326,6 +423,7 @@ pub struct LineMetrics {
 #[repr(C)]
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub x_min: i16,
     pub y_min: i16,
@@ -335,18 +433,114 @@ pub struct Rect {
 
 impl Rect {
     /// Returns rect's width.
+    ///
+    /// Can overflow for rects larger than `i16::MAX`. Use [`RectI32`] in this case.
     #[inline]
     pub fn width(&self) -> i16 {
         self.x_max - self.x_min
     }
 
     /// Returns rect's height.
+    ///
+    /// Can overflow for rects larger than `i16::MAX`. Use [`RectI32`] in this case.
     #[inline]
     pub fn height(&self) -> i16 {
         self.y_max - self.y_min
     }
 }
 
+/// A rectangle with 32-bit coordinates.
+///
+/// Doesn't guarantee that `x_min` <= `x_max` and/or `y_min` <= `y_max`.
+#[repr(C)]
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RectI32 {
+    pub x_min: i32,
+    pub y_min: i32,
+    pub x_max: i32,
+    pub y_max: i32,
+}
+
+impl RectI32 {
+    /// Returns rect's width.
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.x_max - self.x_min
+    }
+
+    /// Returns rect's height.
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.y_max - self.y_min
+    }
+}
+
+impl From<Rect> for RectI32 {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        RectI32 {
+            x_min: i32::from(rect.x_min),
+            y_min: i32::from(rect.y_min),
+            x_max: i32::from(rect.x_max),
+            y_max: i32::from(rect.y_max),
+        }
+    }
+}
+
+/// A rectangle with floating point coordinates.
+///
+/// Doesn't guarantee that `x_min` <= `x_max` and/or `y_min` <= `y_max`.
+#[repr(C)]
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RectF {
+    pub x_min: f32,
+    pub y_min: f32,
+    pub x_max: f32,
+    pub y_max: f32,
+}
+
+impl RectF {
+    /// Returns rect's width.
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.x_max - self.x_min
+    }
+
+    /// Returns rect's height.
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.y_max - self.y_min
+    }
+}
+
+/// Glyph metrics.
+///
+/// Combines the advance box with the ink box. Side bearings are measured
+/// from the advance box to the ink box, so positive values mean inside.
+///
+/// Produced by [`Face::glyph_metrics`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlyphMetrics {
+    /// Horizontal advance.
+    pub hor_advance: u16,
+    /// Vertical advance.
+    ///
+    /// Falls back to `ascender - descender` when there is no `vmtx` table.
+    pub ver_advance: u16,
+    /// Ink bounding box.
+    pub bbox: Rect,
+    /// Distance from the horizontal origin to the left edge of the ink box.
+    pub left_side_bearing: i32,
+    /// Distance from the right edge of the ink box to the horizontal advance.
+    pub right_side_bearing: i32,
+    /// Distance from the vertical origin to the top edge of the ink box.
+    pub top_side_bearing: i32,
+    /// Distance from the bottom edge of the ink box to the vertical advance.
+    pub bottom_side_bearing: i32,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct BBox {
     x_min: f32,
@@ -391,6 +585,49 @@ impl BBox {
             y_max: i16::try_num_from(self.y_max)?,
         })
     }
+
+    #[inline]
+    fn to_rect_i32(self) -> Option<RectI32> {
+        Some(RectI32 {
+            x_min: i32::try_num_from(self.x_min)?,
+            y_min: i32::try_num_from(self.y_min)?,
+            x_max: i32::try_num_from(self.x_max)?,
+            y_max: i32::try_num_from(self.y_max)?,
+        })
+    }
+}
+
+// Accumulates a signed area of a contour's control polygon.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ContourArea {
+    start: (f32, f32),
+    last: (f32, f32),
+    area: f32,
+}
+
+impl ContourArea {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = (x, y);
+        self.last = (x, y);
+        self.area = 0.0;
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.area += self.last.0 * y - x * self.last.1;
+        self.last = (x, y);
+    }
+
+    // Closes the contour and checks that it's clockwise in a y-up coordinate system.
+    #[inline]
+    fn finish(&mut self) -> bool {
+        let (x, y) = self.start;
+        self.line_to(x, y);
+        let is_clockwise = self.area < 0.0;
+        *self = ContourArea::default();
+        is_clockwise
+    }
 }
 
 /// A trait for glyph outline construction.
@@ -413,164 +650,684 @@ pub trait OutlineBuilder {
     ///
     /// End of a contour.
     fn close(&mut self);
-}
-
-struct DummyOutline;
-impl OutlineBuilder for DummyOutline {
-    fn move_to(&mut self, _: f32, _: f32) {}
-    fn line_to(&mut self, _: f32, _: f32) {}
-    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
-    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
-    fn close(&mut self) {}
-}
 
-/// A glyph raster image format.
-#[allow(missing_docs)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum RasterImageFormat {
-    PNG,
+    /// Reports the current contour winding direction.
+    ///
+    /// Called right before [`close`](Self::close), assuming a y-up coordinate system.
+    /// The direction is determined by the sign of the contour's control polygon area.
+    /// Contours with a zero area are reported as counter-clockwise.
+    ///
+    /// TrueType outer contours are usually clockwise, while CFF ones are counter-clockwise.
+    ///
+    /// Does nothing by default.
+    #[inline]
+    fn contour_winding(&mut self, _clockwise: bool) {}
 }
 
-/// A glyph's raster image.
+/// A trait for glyph outline construction using integer coordinates.
 ///
-/// Note, that glyph metrics are in pixels and not in font units.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct RasterGlyphImage<'a> {
-    /// Horizontal offset.
-    pub x: i16,
-
-    /// Vertical offset.
-    pub y: i16,
-
-    /// Image width.
+/// Used by [`Face::outline_glyph_i32`].
+pub trait IntegerOutlineBuilder {
+    /// Appends a MoveTo segment.
     ///
-    /// It doesn't guarantee that this value is the same as set in the `data`.
-    pub width: u16,
+    /// Start of a contour.
+    fn move_to(&mut self, x: i32, y: i32);
 
-    /// Image height.
-    ///
-    /// It doesn't guarantee that this value is the same as set in the `data`.
-    pub height: u16,
+    /// Appends a LineTo segment.
+    fn line_to(&mut self, x: i32, y: i32);
 
-    /// A pixels per em of the selected strike.
-    pub pixels_per_em: u16,
+    /// Appends a QuadTo segment.
+    fn quad_to(&mut self, x1: i32, y1: i32, x: i32, y: i32);
 
-    /// An image format.
-    pub format: RasterImageFormat,
+    /// Appends a CurveTo segment.
+    fn curve_to(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x: i32, y: i32);
 
-    /// A raw image data. It's up to the caller to decode it.
-    pub data: &'a [u8],
-}
+    /// Appends a ClosePath segment.
+    ///
+    /// End of a contour.
+    fn close(&mut self);
 
-/// A raw table record.
-#[derive(Clone, Copy, Debug)]
-#[allow(missing_docs)]
-pub struct TableRecord {
-    pub tag: Tag,
-    #[allow(dead_code)]
-    pub check_sum: u32,
-    pub offset: u32,
-    pub length: u32,
+    /// Reports the current contour winding direction.
+    ///
+    /// See [`OutlineBuilder::contour_winding`] for details.
+    ///
+    /// Does nothing by default.
+    #[inline]
+    fn contour_winding(&mut self, _clockwise: bool) {}
 }
 
-impl FromData for TableRecord {
-    const SIZE: usize = 16;
+struct IntegerOutline<'a>(&'a mut dyn IntegerOutlineBuilder);
 
+impl IntegerOutline<'_> {
     #[inline]
-    fn parse(data: &[u8]) -> Option<Self> {
-        let mut s = Stream::new(data);
-        Some(TableRecord {
-            tag: s.read::<Tag>()?,
-            check_sum: s.read::<u32>()?,
-            offset: s.read::<u32>()?,
-            length: s.read::<u32>()?,
-        })
+    fn round(v: f32) -> i32 {
+        // We can't use `round()` in `no_std`, so this is the next best thing.
+        let v = if v < 0.0 { v - 0.5 } else { v + 0.5 };
+        // Values outside of the `i32` range are possible only in malformed fonts.
+        i32::try_num_from(v).unwrap_or(0)
     }
 }
 
-#[cfg(feature = "variable-fonts")]
-const MAX_VAR_COORDS: usize = 32;
+impl OutlineBuilder for IntegerOutline<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to(Self::round(x), Self::round(y));
+    }
 
-#[cfg(feature = "variable-fonts")]
-#[derive(Clone, Default)]
-struct VarCoords {
-    data: [NormalizedCoordinate; MAX_VAR_COORDS],
-    len: u8,
-}
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to(Self::round(x), Self::round(y));
+    }
 
-#[cfg(feature = "variable-fonts")]
-impl VarCoords {
     #[inline]
-    fn as_slice(&self) -> &[NormalizedCoordinate] {
-        &self.data[0..usize::from(self.len)]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.quad_to(
+            Self::round(x1),
+            Self::round(y1),
+            Self::round(x),
+            Self::round(y),
+        );
     }
 
     #[inline]
-    fn as_mut_slice(&mut self) -> &mut [NormalizedCoordinate] {
-        let end = usize::from(self.len);
-        &mut self.data[0..end]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.curve_to(
+            Self::round(x1),
+            Self::round(y1),
+            Self::round(x2),
+            Self::round(y2),
+            Self::round(x),
+            Self::round(y),
+        );
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.0.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.0.contour_winding(clockwise);
     }
 }
 
-/// A list of font face parsing errors.
+/// Tells [`Face::outline_glyph_stoppable`] whether to keep outlining.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum FaceParsingError {
-    /// An attempt to read out of bounds detected.
+pub enum ControlFlow {
+    /// Keep emitting segments.
+    Continue,
+    /// Stop outlining.
+    Break,
+}
+
+/// A trait for glyph outline construction that can be stopped early.
+///
+/// Used by [`Face::outline_glyph_stoppable`].
+///
+/// Each method returns [`ControlFlow::Break`] to stop outlining.
+/// No more segments will be emitted after that.
+///
+/// An existing [`OutlineBuilder`] can be passed via [`NeverStop`].
+pub trait StoppableOutlineBuilder {
+    /// Appends a MoveTo segment.
     ///
-    /// Should occur only on malformed fonts.
-    MalformedFont,
+    /// Start of a contour.
+    fn move_to(&mut self, x: f32, y: f32) -> ControlFlow;
 
-    /// Face data must start with `0x00010000`, `0x74727565`, `0x4F54544F` or `0x74746366`.
-    UnknownMagic,
+    /// Appends a LineTo segment.
+    fn line_to(&mut self, x: f32, y: f32) -> ControlFlow;
 
-    /// The face index is larger than the number of faces in the font.
-    FaceIndexOutOfBounds,
+    /// Appends a QuadTo segment.
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) -> ControlFlow;
 
-    /// The `head` table is missing or malformed.
-    NoHeadTable,
+    /// Appends a CurveTo segment.
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> ControlFlow;
 
-    /// The `hhea` table is missing or malformed.
-    NoHheaTable,
+    /// Appends a ClosePath segment.
+    ///
+    /// End of a contour.
+    fn close(&mut self) -> ControlFlow;
 
-    /// The `maxp` table is missing or malformed.
-    NoMaxpTable,
+    /// Reports the current contour winding direction.
+    ///
+    /// See [`OutlineBuilder::contour_winding`] for details.
+    ///
+    /// Does nothing by default.
+    #[inline]
+    fn contour_winding(&mut self, _clockwise: bool) {}
 }
 
-impl core::fmt::Display for FaceParsingError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            FaceParsingError::MalformedFont => write!(f, "malformed font"),
-            FaceParsingError::UnknownMagic => write!(f, "unknown magic"),
-            FaceParsingError::FaceIndexOutOfBounds => write!(f, "face index is out of bounds"),
-            FaceParsingError::NoHeadTable => write!(f, "the head table is missing or malformed"),
-            FaceParsingError::NoHheaTable => write!(f, "the hhea table is missing or malformed"),
-            FaceParsingError::NoMaxpTable => write!(f, "the maxp table is missing or malformed"),
-        }
+/// A [`StoppableOutlineBuilder`] adapter for an [`OutlineBuilder`].
+///
+/// Forwards all segments and always returns [`ControlFlow::Continue`].
+pub struct NeverStop<'a>(pub &'a mut dyn OutlineBuilder);
+
+impl core::fmt::Debug for NeverStop<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "NeverStop {{ ... }}")
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for FaceParsingError {}
+impl StoppableOutlineBuilder for NeverStop<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        self.0.move_to(x, y);
+        ControlFlow::Continue
+    }
 
-/// A raw font face.
-///
-/// You are probably looking for [`Face`]. This is a low-level type.
-///
-/// Unlike [`Face`], [`RawFace`] parses only face table records.
-/// Meaning all you can get from this type is a raw (`&[u8]`) data of a requested table.
-/// Then you can either parse just a singe table from a font/face or populate [`RawFaceTables`]
-/// manually before passing it to [`Face::from_raw_tables`].
-#[derive(Clone, Copy)]
-pub struct RawFace<'a> {
-    /// The input font file data.
-    pub data: &'a [u8],
-    /// An array of table records.
-    pub table_records: LazyArray16<'a, TableRecord>,
-}
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        self.0.line_to(x, y);
+        ControlFlow::Continue
+    }
 
-impl<'a> RawFace<'a> {
-    /// Creates a new [`RawFace`] from a raw data.
-    ///
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) -> ControlFlow {
+        self.0.quad_to(x1, y1, x, y);
+        ControlFlow::Continue
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> ControlFlow {
+        self.0.curve_to(x1, y1, x2, y2, x, y);
+        ControlFlow::Continue
+    }
+
+    #[inline]
+    fn close(&mut self) -> ControlFlow {
+        self.0.close();
+        ControlFlow::Continue
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.0.contour_winding(clockwise);
+    }
+}
+
+pub(crate) struct DummyOutline;
+impl OutlineBuilder for DummyOutline {
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+}
+
+// Calculates a bounding box of all points, like the outline methods do.
+struct BBoxOutline(BBox);
+impl OutlineBuilder for BBoxOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.extend_by(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.extend_by(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.extend_by(x1, y1);
+        self.0.extend_by(x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.extend_by(x1, y1);
+        self.0.extend_by(x2, y2);
+        self.0.extend_by(x, y);
+    }
+
+    fn close(&mut self) {}
+}
+
+// Calculates a nonzero winding number of a point. Expects flattened outlines.
+struct WindingCounter {
+    x: f32,
+    y: f32,
+    winding: i32,
+    start: (f32, f32),
+    last: (f32, f32),
+}
+
+impl WindingCounter {
+    fn add_edge(&mut self, to: (f32, f32)) {
+        let (x0, y0) = self.last;
+        let (x1, y1) = to;
+        self.last = to;
+
+        // Positive when the point is to the left of the edge.
+        let side = (x1 - x0) * (self.y - y0) - (self.x - x0) * (y1 - y0);
+        if y0 <= self.y && y1 > self.y && side > 0.0 {
+            self.winding += 1;
+        } else if y1 <= self.y && y0 > self.y && side < 0.0 {
+            self.winding -= 1;
+        }
+    }
+}
+
+impl OutlineBuilder for WindingCounter {
+    fn move_to(&mut self, x: f32, y: f32) {
+        // Contours are always closed, even when `close` is missing.
+        self.add_edge(self.start);
+        self.start = (x, y);
+        self.last = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.add_edge((x, y));
+    }
+
+    // Unreachable, since curves are flattened.
+    fn quad_to(&mut self, _: f32, _: f32, x: f32, y: f32) {
+        self.add_edge((x, y));
+    }
+
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, x: f32, y: f32) {
+        self.add_edge((x, y));
+    }
+
+    fn close(&mut self) {
+        self.add_edge(self.start);
+    }
+}
+
+/// A list of outline tables present in a face.
+///
+/// Returned by [`Face::outline_sources`].
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct OutlineSources(pub u8);
+
+impl OutlineSources {
+    /// The `glyf` table is present.
+    pub const GLYF: u8 = 0x01;
+    /// The `gvar` table is present.
+    pub const GVAR: u8 = 0x02;
+    /// The `CFF ` table is present.
+    pub const CFF: u8 = 0x04;
+    /// The `CFF2` table is present.
+    pub const CFF2: u8 = 0x08;
+
+    /// Checks that `GLYF` flag is set.
+    #[inline]
+    pub fn glyf(self) -> bool {
+        self.0 & Self::GLYF != 0
+    }
+
+    /// Checks that `GVAR` flag is set.
+    #[inline]
+    pub fn gvar(self) -> bool {
+        self.0 & Self::GVAR != 0
+    }
+
+    /// Checks that `CFF` flag is set.
+    #[inline]
+    pub fn cff(self) -> bool {
+        self.0 & Self::CFF != 0
+    }
+
+    /// Checks that `CFF2` flag is set.
+    #[inline]
+    pub fn cff2(self) -> bool {
+        self.0 & Self::CFF2 != 0
+    }
+
+    /// Checks that no outline tables are present.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks that both `CFF ` and `CFF2` tables are present.
+    ///
+    /// Such faces are malformed. [`Face::outline_glyph`] will use only `CFF ` in this case.
+    #[inline]
+    pub fn has_cff_conflict(self) -> bool {
+        self.cff() && self.cff2()
+    }
+}
+
+/// A color glyph format.
+///
+/// Returned by [`Face::color_glyph_format`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorGlyphFormat {
+    /// An SVG document from the `SVG ` table.
+    Svg,
+    /// A raster image from the `sbix` table.
+    Sbix,
+    /// A raster image from the `CBLC`+`CBDT` tables.
+    Cbdt,
+}
+
+/// A raster image source.
+///
+/// Used by [`Face::glyph_raster_image_with`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RasterSource {
+    /// The `sbix` table.
+    Sbix,
+    /// The `CBLC`+`CBDT` tables.
+    Cbdt,
+    /// The `EBLC`+`EBDT` tables.
+    Ebdt,
+}
+
+/// A rounding mode used for variation-adjusted metrics.
+///
+/// See [`Face::set_rounding_mode`].
+#[cfg(feature = "variable-fonts")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Rounds half away from zero.
+    Round,
+    /// Rounds towards zero.
+    Truncate,
+}
+
+#[cfg(feature = "variable-fonts")]
+impl RoundingMode {
+    // Returns a value that should be truncated by an integer conversion afterwards.
+    #[inline]
+    fn apply(self, v: f32) -> f32 {
+        match self {
+            // We can't use `round()` in `no_std`, so this is the next best thing.
+            RoundingMode::Round if v < 0.0 => v - 0.5,
+            RoundingMode::Round => v + 0.5,
+            RoundingMode::Truncate => v,
+        }
+    }
+}
+
+#[cfg(feature = "variable-fonts")]
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        RoundingMode::Round
+    }
+}
+
+/// An outline source used by [`Face::outline_glyph_from`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutlineSource {
+    /// The first available source, in the same order as [`Face::outline_glyph`] does.
+    Auto,
+    /// `glyf` and `gvar` tables.
+    Glyf,
+    /// `CFF ` table.
+    Cff,
+    /// `CFF2` table.
+    Cff2,
+}
+
+impl Default for OutlineSource {
+    #[inline]
+    fn default() -> Self {
+        OutlineSource::Auto
+    }
+}
+
+/// A glyph raster image format.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RasterImageFormat {
+    PNG,
+    JPEG,
+    TIFF,
+    /// A monochrome bitmap.
+    ///
+    /// The most significant bit of the first byte corresponds to the top-left pixel.
+    /// `1` is black and `0` is white.
+    /// Each row is padded to a byte boundary.
+    BitmapMono,
+    /// A packed monochrome bitmap.
+    ///
+    /// Same as [`BitmapMono`](Self::BitmapMono), but rows are not padded,
+    /// i.e. the next row starts right after the last bit of the previous one.
+    /// Only the last byte of the image may contain padding.
+    BitmapMonoPacked,
+}
+
+/// An SVG image kind.
+///
+/// Returned by [`Face::glyph_svg_image_decompressed`].
+#[cfg(feature = "svgz")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageKind {
+    /// A plain SVG document.
+    Svg,
+    /// A gzip-compressed SVG document, which was decompressed.
+    Svgz,
+}
+
+/// A glyph's raster image.
+///
+/// Note, that glyph metrics are in pixels and not in font units.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RasterGlyphImage<'a> {
+    /// Horizontal offset.
+    pub x: i16,
+
+    /// Vertical offset.
+    pub y: i16,
+
+    /// Image width.
+    ///
+    /// It doesn't guarantee that this value is the same as set in the `data`.
+    pub width: u16,
+
+    /// Image height.
+    ///
+    /// It doesn't guarantee that this value is the same as set in the `data`.
+    pub height: u16,
+
+    /// A pixels per em of the selected strike.
+    pub pixels_per_em: u16,
+
+    /// An image format.
+    pub format: RasterImageFormat,
+
+    /// A raw image data. It's up to the caller to decode it.
+    pub data: &'a [u8],
+}
+
+/// A raw table record.
+#[derive(Clone, Copy, Debug)]
+#[allow(missing_docs)]
+pub struct TableRecord {
+    pub tag: Tag,
+    pub check_sum: u32,
+    pub offset: u32,
+    pub length: u32,
+}
+
+impl FromData for TableRecord {
+    const SIZE: usize = 16;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(TableRecord {
+            tag: s.read::<Tag>()?,
+            check_sum: s.read::<u32>()?,
+            offset: s.read::<u32>()?,
+            length: s.read::<u32>()?,
+        })
+    }
+}
+
+#[cfg(feature = "variable-fonts")]
+const MAX_VAR_COORDS: usize = 32;
+
+/// A set of normalized variation coordinates.
+///
+/// Unlike [`Face::set_variations`], allows using multiple instances of a variable font
+/// without modifying the face, i.e. the same face can be shared between threads.
+///
+/// Coordinates are stored on the stack, therefore only the first 32 axes are supported.
+#[cfg(feature = "variable-fonts")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Variations {
+    data: [NormalizedCoordinate; MAX_VAR_COORDS],
+    len: u8,
+}
+
+#[cfg(feature = "variable-fonts")]
+impl Variations {
+    /// Creates variation coordinates for the specified face.
+    ///
+    /// Values are normalized via `fvar` and mapped via `avar`,
+    /// just like [`Face::set_variations`] does.
+    /// Unknown axes are ignored and missing ones are set to default values.
+    ///
+    /// Coordinates can be used only with the face they were created for.
+    pub fn new(face: &Face, variations: &[Variation]) -> Self {
+        let mut coords = Variations {
+            len: face.coordinates.len,
+            ..Variations::default()
+        };
+
+        let axes = face.variation_axes();
+        for variation in variations {
+            coords.set_unmapped(axes, variation.axis, variation.value);
+        }
+
+        coords.apply_avar(face.tables.avar);
+        coords
+    }
+
+    /// Returns normalized coordinates.
+    #[inline]
+    pub fn coordinates(&self) -> &[NormalizedCoordinate] {
+        self.as_slice()
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[NormalizedCoordinate] {
+        &self.data[0..usize::from(self.len)]
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [NormalizedCoordinate] {
+        let end = usize::from(self.len);
+        &mut self.data[0..end]
+    }
+
+    // Sets a normalized coordinate without the `avar` mapping.
+    fn set_unmapped(&mut self, axes: LazyArray16<VariationAxis>, axis: Tag, value: f32) -> bool {
+        let v = axes.into_iter().enumerate().find(|(_, a)| a.tag == axis);
+        match v {
+            Some((idx, a)) if idx < MAX_VAR_COORDS => {
+                self.data[idx] = a.normalized_value(value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn apply_avar(&mut self, avar: Option<avar::Table>) {
+        if let Some(avar) = avar {
+            // Ignore error.
+            let _ = avar.map_coordinates(self.as_mut_slice());
+        }
+    }
+}
+
+/// A list of font face parsing errors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FaceParsingError {
+    /// An attempt to read out of bounds detected.
+    ///
+    /// Should occur only on malformed fonts.
+    MalformedFont,
+
+    /// Face data must start with `0x00010000`, `0x74727565`, `0x4F54544F` or `0x74746366`.
+    UnknownMagic,
+
+    /// The face index is larger than the number of faces in the font.
+    FaceIndexOutOfBounds,
+
+    /// The `head` table is missing or malformed.
+    NoHeadTable,
+
+    /// The `hhea` table is missing or malformed.
+    NoHheaTable,
+
+    /// The `maxp` table is missing or malformed.
+    NoMaxpTable,
+}
+
+impl core::fmt::Display for FaceParsingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FaceParsingError::MalformedFont => write!(f, "malformed font"),
+            FaceParsingError::UnknownMagic => write!(f, "unknown magic"),
+            FaceParsingError::FaceIndexOutOfBounds => write!(f, "face index is out of bounds"),
+            FaceParsingError::NoHeadTable => write!(f, "the head table is missing or malformed"),
+            FaceParsingError::NoHheaTable => write!(f, "the hhea table is missing or malformed"),
+            FaceParsingError::NoMaxpTable => write!(f, "the maxp table is missing or malformed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FaceParsingError {}
+
+/// A list of glyph outlining errors.
+///
+/// Returned by [`Face::validate_glyph_outline`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutlineError {
+    /// The face doesn't have any outline tables.
+    MissingTable,
+
+    /// A composite glyph references a missing or malformed component.
+    BadComposite,
+
+    /// A composite glyph or a `CFF` subroutine is nested too deep.
+    DepthLimitExceeded,
+
+    /// The glyph has no outline, like space.
+    Empty,
+
+    /// The glyph data is malformed.
+    Malformed,
+}
+
+impl core::fmt::Display for OutlineError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OutlineError::MissingTable => write!(f, "no outline tables"),
+            OutlineError::BadComposite => write!(f, "missing or malformed glyph component"),
+            OutlineError::DepthLimitExceeded => write!(f, "nesting depth limit exceeded"),
+            OutlineError::Empty => write!(f, "glyph has no outline"),
+            OutlineError::Malformed => write!(f, "malformed glyph data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutlineError {}
+
+/// A raw font face.
+///
+/// You are probably looking for [`Face`]. This is a low-level type.
+///
+/// Unlike [`Face`], [`RawFace`] parses only face table records.
+/// Meaning all you can get from this type is a raw (`&[u8]`) data of a requested table.
+/// Then you can either parse just a singe table from a font/face or populate [`RawFaceTables`]
+/// manually before passing it to [`Face::from_raw_tables`].
+#[derive(Clone, Copy)]
+pub struct RawFace<'a> {
+    /// The input font file data.
+    pub data: &'a [u8],
+    /// An array of table records.
+    pub table_records: LazyArray16<'a, TableRecord>,
+}
+
+impl<'a> RawFace<'a> {
+    /// Creates a new [`RawFace`] from a raw data.
+    ///
     /// `index` indicates the specific font face in a font collection.
     /// Use [`fonts_in_collection`] to get the total number of font faces.
     /// Set to 0 if unsure.
@@ -641,16 +1398,109 @@ impl<'a> RawFace<'a> {
         })
     }
 
-    /// Returns the raw data of a selected table.
-    pub fn table(&self, tag: Tag) -> Option<&'a [u8]> {
-        let (_, table) = self
+    /// Returns a table record of a selected table.
+    ///
+    /// Unlike [`table`](Self::table), doesn't check that the table data is in bounds.
+    pub fn table_record(&self, tag: Tag) -> Option<TableRecord> {
+        let (_, record) = self
             .table_records
             .binary_search_by(|record| record.tag.cmp(&tag))?;
+        Some(record)
+    }
+
+    /// Returns the raw data of a selected table.
+    pub fn table(&self, tag: Tag) -> Option<&'a [u8]> {
+        let table = self.table_record(tag)?;
         let offset = usize::num_from(table.offset);
         let length = usize::num_from(table.length);
         let end = offset.checked_add(length)?;
         self.data.get(offset..end)
     }
+
+    /// Validates tables checksums and the whole font checksum adjustment.
+    ///
+    /// Each table checksum is recomputed and compared with [`TableRecord::check_sum`].
+    /// The `head.checkSumAdjustment` is validated only for regular fonts,
+    /// since it's not used by font collections.
+    ///
+    /// Returns `false` on any mismatch or when a table is out of bounds.
+    ///
+    /// Note that some legitimate fonts have intentionally wrong checksums,
+    /// so a failed validation doesn't necessarily mean that the font is broken.
+    pub fn validate_checksums(&self) -> bool {
+        let head = Tag::from_bytes(b"head");
+
+        // Both checksums are computed with the head checksum adjustment set to zero.
+        let mut checksum_adjustment = 0;
+        for record in self.table_records {
+            let data = match self.table(record.tag) {
+                Some(v) => v,
+                None => return false,
+            };
+
+            let mut checksum = calc_checksum(data);
+            if record.tag == head {
+                checksum_adjustment = match Stream::read_at::<u32>(data, 8) {
+                    Some(v) => v,
+                    None => return false,
+                };
+
+                checksum = checksum.wrapping_sub(checksum_adjustment);
+            }
+
+            if checksum != record.check_sum {
+                return false;
+            }
+        }
+
+        if Stream::read_at::<Magic>(self.data, 0) == Some(Magic::FontCollection) {
+            return true;
+        }
+
+        if let Some(record) = self.table_record(head) {
+            // Tables must be 4-byte aligned, otherwise we cannot exclude the adjustment.
+            if record.offset & 3 != 0 {
+                return false;
+            }
+
+            let checksum = calc_checksum(self.data).wrapping_sub(checksum_adjustment);
+            if 0xB1B0AFBA_u32.wrapping_sub(checksum) != checksum_adjustment {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Checks that the face has a selected table.
+    ///
+    /// Like [`table_record`](Self::table_record), doesn't check that the table data is in bounds.
+    #[inline]
+    pub fn has_table(&self, tag: Tag) -> bool {
+        self.table_record(tag).is_some()
+    }
+
+    /// Returns an iterator over tables' tags and lengths.
+    ///
+    /// Tables are listed in the file order.
+    #[inline]
+    pub fn tables(&self) -> impl Iterator<Item = (Tag, u32)> + 'a {
+        self.table_records
+            .into_iter()
+            .map(|record| (record.tag, record.length))
+    }
+}
+
+// A sum of big-endian `u32` words. The last word is padded with zeros.
+fn calc_checksum(data: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    for chunk in data.chunks(4) {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum = sum.wrapping_add(u32::from_be_bytes(word));
+    }
+
+    sum
 }
 
 impl core::fmt::Debug for RawFace<'_> {
@@ -679,15 +1529,22 @@ pub struct RawFaceTables<'a> {
     pub cblc: Option<&'a [u8]>,
     pub cff: Option<&'a [u8]>,
     pub cmap: Option<&'a [u8]>,
+    pub cpal: Option<&'a [u8]>,
+    pub ebdt: Option<&'a [u8]>,
+    pub eblc: Option<&'a [u8]>,
+    pub gasp: Option<&'a [u8]>,
     pub glyf: Option<&'a [u8]>,
+    pub hdmx: Option<&'a [u8]>,
     pub hmtx: Option<&'a [u8]>,
     pub kern: Option<&'a [u8]>,
     pub loca: Option<&'a [u8]>,
+    pub meta: Option<&'a [u8]>,
     pub name: Option<&'a [u8]>,
     pub os2: Option<&'a [u8]>,
     pub post: Option<&'a [u8]>,
     pub sbix: Option<&'a [u8]>,
     pub svg: Option<&'a [u8]>,
+    pub vdmx: Option<&'a [u8]>,
     pub vhea: Option<&'a [u8]>,
     pub vmtx: Option<&'a [u8]>,
     pub vorg: Option<&'a [u8]>,
@@ -725,6 +1582,8 @@ pub struct RawFaceTables<'a> {
     #[cfg(feature = "variable-fonts")]
     pub mvar: Option<&'a [u8]>,
     #[cfg(feature = "variable-fonts")]
+    pub stat: Option<&'a [u8]>,
+    #[cfg(feature = "variable-fonts")]
     pub vvar: Option<&'a [u8]>,
 }
 
@@ -747,14 +1606,21 @@ pub struct FaceTables<'a> {
     pub cbdt: Option<cbdt::Table<'a>>,
     pub cff: Option<cff::Table<'a>>,
     pub cmap: Option<cmap::Table<'a>>,
+    pub cpal: Option<cpal::Table<'a>>,
+    /// `EBLC`+`EBDT` tables. Use the same parser as `CBLC`+`CBDT`.
+    pub ebdt: Option<cbdt::Table<'a>>,
+    pub gasp: Option<gasp::Table<'a>>,
     pub glyf: Option<glyf::Table<'a>>,
+    pub hdmx: Option<hdmx::Table<'a>>,
     pub hmtx: Option<hmtx::Table<'a>>,
     pub kern: Option<kern::Table<'a>>,
+    pub meta: Option<meta::Table<'a>>,
     pub name: Option<name::Table<'a>>,
     pub os2: Option<os2::Table<'a>>,
     pub post: Option<post::Table<'a>>,
     pub sbix: Option<sbix::Table<'a>>,
     pub svg: Option<svg::Table<'a>>,
+    pub vdmx: Option<vdmx::Table<'a>>,
     pub vhea: Option<vhea::Table>,
     pub vmtx: Option<hmtx::Table<'a>>,
     pub vorg: Option<vorg::Table<'a>>,
@@ -792,6 +1658,8 @@ pub struct FaceTables<'a> {
     #[cfg(feature = "variable-fonts")]
     pub mvar: Option<mvar::Table<'a>>,
     #[cfg(feature = "variable-fonts")]
+    pub stat: Option<stat::Table<'a>>,
+    #[cfg(feature = "variable-fonts")]
     pub vvar: Option<hvar::Table<'a>>,
 }
 
@@ -815,7 +1683,9 @@ pub struct Face<'a> {
     raw_face: RawFace<'a>,
     tables: FaceTables<'a>, // Parsed tables.
     #[cfg(feature = "variable-fonts")]
-    coordinates: VarCoords,
+    coordinates: Variations,
+    #[cfg(feature = "variable-fonts")]
+    rounding_mode: RoundingMode,
 }
 
 impl<'a> Face<'a> {
@@ -856,7 +1726,9 @@ impl<'a> Face<'a> {
         let mut face = Face {
             raw_face,
             #[cfg(feature = "variable-fonts")]
-            coordinates: VarCoords::default(),
+            coordinates: Variations::default(),
+            #[cfg(feature = "variable-fonts")]
+            rounding_mode: RoundingMode::default(),
             tables: Self::parse_tables(raw_tables)?,
         };
 
@@ -884,7 +1756,10 @@ impl<'a> Face<'a> {
             match &record.tag.to_bytes() {
                 b"CBDT" => tables.cbdt = table_data,
                 b"CBLC" => tables.cblc = table_data,
+                b"EBDT" => tables.ebdt = table_data,
+                b"EBLC" => tables.eblc = table_data,
                 b"CFF " => tables.cff = table_data,
+                b"CPAL" => tables.cpal = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"CFF2" => tables.cff2 = table_data,
                 #[cfg(feature = "opentype-layout")]
@@ -900,7 +1775,10 @@ impl<'a> Face<'a> {
                 #[cfg(feature = "variable-fonts")]
                 b"MVAR" => tables.mvar = table_data,
                 b"OS/2" => tables.os2 = table_data,
+                #[cfg(feature = "variable-fonts")]
+                b"STAT" => tables.stat = table_data,
                 b"SVG " => tables.svg = table_data,
+                b"VDMX" => tables.vdmx = table_data,
                 b"VORG" => tables.vorg = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"VVAR" => tables.vvar = table_data,
@@ -913,9 +1791,11 @@ impl<'a> Face<'a> {
                 b"feat" => tables.feat = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"fvar" => tables.fvar = table_data,
+                b"gasp" => tables.gasp = table_data,
                 b"glyf" => tables.glyf = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"gvar" => tables.gvar = table_data,
+                b"hdmx" => tables.hdmx = table_data,
                 b"head" => tables.head = table_data.unwrap_or_default(),
                 b"hhea" => tables.hhea = table_data.unwrap_or_default(),
                 b"hmtx" => tables.hmtx = table_data,
@@ -924,6 +1804,7 @@ impl<'a> Face<'a> {
                 b"kerx" => tables.kerx = table_data,
                 b"loca" => tables.loca = table_data,
                 b"maxp" => tables.maxp = table_data.unwrap_or_default(),
+                b"meta" => tables.meta = table_data,
                 #[cfg(feature = "apple-layout")]
                 b"morx" => tables.morx = table_data,
                 b"name" => tables.name = table_data,
@@ -949,7 +1830,9 @@ impl<'a> Face<'a> {
                 table_records: LazyArray16::default(),
             },
             #[cfg(feature = "variable-fonts")]
-            coordinates: VarCoords::default(),
+            coordinates: Variations::default(),
+            #[cfg(feature = "variable-fonts")]
+            rounding_mode: RoundingMode::default(),
             tables: Self::parse_tables(raw_tables)?,
         };
 
@@ -966,7 +1849,9 @@ impl<'a> Face<'a> {
     fn parse_tables(raw_tables: RawFaceTables<'a>) -> Result<FaceTables<'a>, FaceParsingError> {
         let head = head::Table::parse(raw_tables.head).ok_or(FaceParsingError::NoHeadTable)?;
         let hhea = hhea::Table::parse(raw_tables.hhea).ok_or(FaceParsingError::NoHheaTable)?;
-        let maxp = maxp::Table::parse(raw_tables.maxp).ok_or(FaceParsingError::NoMaxpTable)?;
+        let maxp = maxp::Table::parse(raw_tables.maxp)
+            .filter(|maxp| maxp.declared_number_of_glyphs() != 0)
+            .ok_or(FaceParsingError::NoMaxpTable)?;
 
         let hmtx = raw_tables.hmtx.and_then(|data| {
             hmtx::Table::parse(hhea.number_of_metrics, maxp.number_of_glyphs, data)
@@ -984,7 +1869,11 @@ impl<'a> Face<'a> {
         let loca = raw_tables.loca.and_then(|data| {
             loca::Table::parse(maxp.number_of_glyphs, head.index_to_location_format, data)
         });
-        let glyf = if let Some(loca) = loca {
+        // Other glyph data formats are not defined by the spec,
+        // so we cannot parse them reliably.
+        let glyf = if head.glyph_data_format != 0 {
+            None
+        } else if let Some(loca) = loca {
             raw_tables
                 .glyf
                 .and_then(|data| glyf::Table::parse(loca, data))
@@ -1000,6 +1889,14 @@ impl<'a> Face<'a> {
             None
         };
 
+        let ebdt = if let Some(eblc) = raw_tables.eblc.and_then(cblc::Table::parse) {
+            raw_tables
+                .ebdt
+                .and_then(|data| cbdt::Table::parse(eblc, data))
+        } else {
+            None
+        };
+
         Ok(FaceTables {
             head,
             hhea,
@@ -1008,9 +1905,16 @@ impl<'a> Face<'a> {
             cbdt,
             cff: raw_tables.cff.and_then(cff::Table::parse),
             cmap: raw_tables.cmap.and_then(cmap::Table::parse),
+            cpal: raw_tables.cpal.and_then(cpal::Table::parse),
+            ebdt,
+            gasp: raw_tables.gasp.and_then(gasp::Table::parse),
             glyf,
+            hdmx: raw_tables
+                .hdmx
+                .and_then(|data| hdmx::Table::parse(maxp.number_of_glyphs, data)),
             hmtx,
             kern: raw_tables.kern.and_then(kern::Table::parse),
+            meta: raw_tables.meta.and_then(meta::Table::parse),
             name: raw_tables.name.and_then(name::Table::parse),
             os2: raw_tables.os2.and_then(os2::Table::parse),
             post: raw_tables.post.and_then(post::Table::parse),
@@ -1018,6 +1922,7 @@ impl<'a> Face<'a> {
                 .sbix
                 .and_then(|data| sbix::Table::parse(maxp.number_of_glyphs, data)),
             svg: raw_tables.svg.and_then(svg::Table::parse),
+            vdmx: raw_tables.vdmx.and_then(vdmx::Table::parse),
             vhea: raw_tables.vhea.and_then(vhea::Table::parse),
             vmtx,
             vorg: raw_tables.vorg.and_then(vorg::Table::parse),
@@ -1065,6 +1970,8 @@ impl<'a> Face<'a> {
             #[cfg(feature = "variable-fonts")]
             mvar: raw_tables.mvar.and_then(mvar::Table::parse),
             #[cfg(feature = "variable-fonts")]
+            stat: raw_tables.stat.and_then(stat::Table::parse),
+            #[cfg(feature = "variable-fonts")]
             vvar: raw_tables.vvar.and_then(hvar::Table::parse),
         })
     }
@@ -1085,6 +1992,16 @@ impl<'a> Face<'a> {
         &self.raw_face
     }
 
+    /// Checks that the face has a selected table.
+    ///
+    /// A shortcut for `self.raw_face().has_table()`.
+    ///
+    /// Available only for faces created using [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn has_table(&self, tag: Tag) -> bool {
+        self.raw_face.has_table(tag)
+    }
+
     /// Returns the raw data of a selected table.
     ///
     /// Useful if you want to parse the data manually.
@@ -1098,12 +2015,42 @@ impl<'a> Face<'a> {
 
     /// Returns a list of names.
     ///
-    /// Contains face name and other strings.
+    /// Contains face name and other strings. Names are listed in the file order.
     #[inline]
     pub fn names(&self) -> name::Names<'a> {
         self.tables.name.unwrap_or_default().names
     }
 
+    /// Returns face's design languages.
+    ///
+    /// A comma-separated list of BCP 47 language tags from the `meta` table.
+    ///
+    /// Returns `None` when `meta` table is not present or has no `dlng` record.
+    #[inline]
+    pub fn design_languages(&self) -> Option<&'a str> {
+        self.tables.meta?.design_languages()
+    }
+
+    /// Returns face's supported languages.
+    ///
+    /// A comma-separated list of BCP 47 language tags from the `meta` table.
+    ///
+    /// Returns `None` when `meta` table is not present or has no `slng` record.
+    #[inline]
+    pub fn supported_languages(&self) -> Option<&'a str> {
+        self.tables.meta?.supported_languages()
+    }
+
+    /// Returns face's family name in a specified language.
+    ///
+    /// See [`name::Names::get_localized`] for the lookup rules.
+    ///
+    /// Returns `None` when `name` table is not present or has no family name records.
+    #[inline]
+    pub fn family_name_localized(&self, language: Language) -> Option<name::Name<'a>> {
+        self.names().get_localized(name_id::FAMILY, language)
+    }
+
     /// Checks that face is marked as *Regular*.
     ///
     /// Returns `false` when OS/2 table is not present.
@@ -1117,21 +2064,24 @@ impl<'a> Face<'a> {
 
     /// Checks that face is marked as *Italic*.
     ///
-    /// Returns `false` when OS/2 table is not present.
+    /// Fallbacks to `head.macStyle` when OS/2 table is not present.
     #[inline]
     pub fn is_italic(&self) -> bool {
-        self.tables
-            .os2
-            .map(|s| s.style() == Style::Italic)
-            .unwrap_or(false)
+        match self.tables.os2 {
+            Some(os2) => os2.style() == Style::Italic,
+            None => self.tables.head.mac_style.italic(),
+        }
     }
 
     /// Checks that face is marked as *Bold*.
     ///
-    /// Returns `false` when OS/2 table is not present.
+    /// Fallbacks to `head.macStyle` when OS/2 table is not present.
     #[inline]
     pub fn is_bold(&self) -> bool {
-        try_opt_or!(self.tables.os2, false).is_bold()
+        match self.tables.os2 {
+            Some(os2) => os2.is_bold(),
+            None => self.tables.head.mac_style.bold(),
+        }
     }
 
     /// Checks that face is marked as *Oblique*.
@@ -1145,6 +2095,46 @@ impl<'a> Face<'a> {
             .unwrap_or(false)
     }
 
+    /// Checks that bold style has to be synthesized.
+    ///
+    /// Returns `true` when `want_bold` is set and the face isn't bold already.
+    /// A face is considered bold when any of the following is set:
+    ///
+    /// - `OS/2.fsSelection` bold flag
+    /// - `OS/2.usWeightClass` is 600 (*SemiBold*) or higher
+    /// - `head.macStyle` bold flag
+    #[inline]
+    pub fn needs_synthetic_bold(&self, want_bold: bool) -> bool {
+        if !want_bold {
+            return false;
+        }
+
+        let is_bold =
+            self.is_bold() || self.weight().to_number() >= 600 || self.tables.head.mac_style.bold();
+        !is_bold
+    }
+
+    /// Checks that italic style has to be synthesized.
+    ///
+    /// Returns `true` when `want_italic` is set and the face isn't italic already.
+    /// A face is considered italic when any of the following is set:
+    ///
+    /// - `OS/2.fsSelection` italic or oblique flags
+    /// - `head.macStyle` italic flag
+    /// - `post.italicAngle` is not zero
+    #[inline]
+    pub fn needs_synthetic_italic(&self, want_italic: bool) -> bool {
+        if !want_italic {
+            return false;
+        }
+
+        let is_italic = self.is_italic()
+            || self.is_oblique()
+            || self.tables.head.mac_style.italic()
+            || self.italic_angle().unwrap_or(0.0) != 0.0;
+        !is_italic
+    }
+
     /// Returns face style.
     #[inline]
     pub fn style(&self) -> Style {
@@ -1192,6 +2182,30 @@ impl<'a> Face<'a> {
         try_opt_or!(self.tables.os2, Width::default()).width()
     }
 
+    /// Returns face's vendor ID.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[inline]
+    pub fn vendor_id(&self) -> Option<Tag> {
+        self.tables.os2.map(|table| table.vendor_id())
+    }
+
+    /// Returns face's embedding permissions.
+    ///
+    /// Returns `None` when OS/2 table is not present or permissions are malformed.
+    #[inline]
+    pub fn embedding_permissions(&self) -> Option<Permissions> {
+        self.tables.os2?.permissions()
+    }
+
+    /// Returns the range of sizes this face was designed for, in points.
+    ///
+    /// Returns `None` when OS/2 table is not present or when its version is < 5.
+    #[inline]
+    pub fn optical_size_range(&self) -> Option<(f32, f32)> {
+        self.tables.os2?.optical_size_range()
+    }
+
     /// Returns face's italic angle.
     ///
     /// Returns `None` when `post` table is not present.
@@ -1384,6 +2398,25 @@ impl<'a> Face<'a> {
             .map(|v| self.apply_metrics_variation(Tag::from_bytes(b"vlgp"), v))
     }
 
+    /// Returns a horizontal caret slope rise, run and offset.
+    ///
+    /// Returns `None` when both rise and run are zero, since the slope is undefined then.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn caret_slope(&self) -> Option<(i16, i16, i16)> {
+        let hhea = &self.tables.hhea;
+        if hhea.caret_slope_rise == 0 && hhea.caret_slope_run == 0 {
+            return None;
+        }
+
+        Some((
+            self.apply_metrics_variation(Tag::from_bytes(b"hcrs"), hhea.caret_slope_rise),
+            self.apply_metrics_variation(Tag::from_bytes(b"hcrn"), hhea.caret_slope_run),
+            self.apply_metrics_variation(Tag::from_bytes(b"hcof"), hhea.caret_offset),
+        ))
+    }
+
     /// Returns face's units per EM.
     ///
     /// Guarantee to be in a 16..=16384 range.
@@ -1392,6 +2425,24 @@ impl<'a> Face<'a> {
         self.tables.head.units_per_em
     }
 
+    /// Returns face's creation time.
+    ///
+    /// Returns `None` when the stored value is out of range.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn created_time(&self) -> Option<head::DateTime> {
+        head::DateTime::from_long_date_time(self.tables.head.created)
+    }
+
+    /// Returns face's modification time.
+    ///
+    /// Returns `None` when the stored value is out of range.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn modified_time(&self) -> Option<head::DateTime> {
+        head::DateTime::from_long_date_time(self.tables.head.modified)
+    }
+
     /// Returns face's x height.
     ///
     /// This method is affected by variation axes.
@@ -1500,21 +2551,82 @@ impl<'a> Face<'a> {
         self.tables.maxp.number_of_glyphs.get()
     }
 
+    /// Returns an iterator over all glyph IDs in the face.
+    ///
+    /// Yields `GlyphId(0)..GlyphId(number_of_glyphs)`, without the end.
+    #[inline]
+    pub fn glyphs(&self) -> impl Iterator<Item = GlyphId> {
+        (0..self.number_of_glyphs()).map(GlyphId)
+    }
+
+    /// Checks that the glyph ID is in the `0..number_of_glyphs` range.
+    #[inline]
+    pub fn is_valid_glyph(&self, glyph_id: GlyphId) -> bool {
+        glyph_id.0 < self.number_of_glyphs()
+    }
+
     /// Resolves a Glyph ID for a code point.
     ///
     /// Returns `None` instead of `0` when glyph is not found.
     ///
     /// All subtable formats except Mixed Coverage (8) are supported.
     ///
+    /// Subtables are checked in the following order:
+    ///
+    /// 1. Unicode subtables, in the order they are stored in the `cmap` table.
+    /// 2. Windows Symbol subtables. Symbol fonts usually map characters
+    ///    into the `U+F000..U+F0FF` range, therefore code points up to `U+00FF`
+    ///    are looked up with the `0xF000` offset as well.
+    /// 3. Macintosh Roman subtables (usually format 0 or 6), as a last resort.
+    ///    The code point is converted into Mac OS Roman first,
+    ///    so only characters from this encoding can be resolved.
+    ///
     /// If you need a more low-level control, prefer `Face::tables().cmap`.
     #[inline]
     pub fn glyph_index(&self, code_point: char) -> Option<GlyphId> {
-        for subtable in self.tables.cmap?.subtables {
+        self.glyph_index_u32(u32::from(code_point))
+    }
+
+    /// Resolves a Glyph ID for a raw code point.
+    ///
+    /// Same as [`Face::glyph_index`], but accepts values that are not valid `char`s,
+    /// like lone surrogates.
+    pub fn glyph_index_u32(&self, code_point: u32) -> Option<GlyphId> {
+        let subtables = self.tables.cmap?.subtables;
+        for subtable in subtables {
             if !subtable.is_unicode() {
                 continue;
             }
 
-            if let Some(id) = subtable.glyph_index(u32::from(code_point)) {
+            if let Some(id) = subtable.glyph_index(code_point) {
+                return Some(id);
+            }
+        }
+
+        for subtable in subtables {
+            if !subtable.is_symbol() {
+                continue;
+            }
+
+            if let Some(id) = subtable.glyph_index(code_point) {
+                return Some(id);
+            }
+
+            if code_point <= 0xFF {
+                if let Some(id) = subtable.glyph_index(0xF000 + code_point) {
+                    return Some(id);
+                }
+            }
+        }
+
+        // Old Mac fonts may have only a Mac Roman subtable.
+        let code = cmap::mac_roman_code(code_point)?;
+        for subtable in subtables {
+            if !subtable.is_mac_roman() {
+                continue;
+            }
+
+            if let Some(id) = subtable.glyph_index(u32::from(code)) {
                 return Some(id);
             }
         }
@@ -1522,6 +2634,83 @@ impl<'a> Face<'a> {
         None
     }
 
+    /// Returns the number of code points covered by the face per Unicode script.
+    ///
+    /// Code points are collected from all Unicode `cmap` subtables, without duplicates.
+    /// Scripts are returned in [`script::Script`] order and only when at least one
+    /// code point is covered.
+    #[cfg(all(feature = "std", feature = "unicode-scripts"))]
+    pub fn coverage_by_script(&self) -> impl Iterator<Item = (script::Script, u32)> {
+        let mut ranges = std::vec::Vec::new();
+        if let Some(cmap) = self.tables.cmap {
+            for subtable in cmap.subtables {
+                if subtable.is_unicode() {
+                    subtable.codepoint_ranges(|start, end| ranges.push((start, end)));
+                }
+            }
+        }
+
+        ranges.sort_unstable();
+
+        // Ranges are limited to valid Unicode code points,
+        // so each code point is visited at most once, no matter how many ranges overlap.
+        let mut coverage = std::collections::BTreeMap::new();
+        let mut next = 0;
+        for (start, end) in ranges {
+            for c in core::cmp::max(start, next)..=end {
+                *coverage
+                    .entry(script::Script::from_code_point(c))
+                    .or_insert(0) += 1;
+            }
+
+            next = core::cmp::max(next, end + 1);
+        }
+
+        coverage.into_iter()
+    }
+
+    /// Builds a precomputed code point to glyph mapping.
+    ///
+    /// Uses the first Unicode subtable that covers the full repertoire (format 12 or 13)
+    /// and falls back to the first Unicode subtable otherwise.
+    /// Other subtables are ignored, therefore the result may differ from
+    /// [`Face::glyph_index`] in fonts with multiple Unicode subtables.
+    ///
+    /// Returns an empty cache when there are no suitable subtables.
+    #[cfg(feature = "std")]
+    pub fn build_cmap_cache(&self) -> cmap::CmapCache {
+        let subtables = match self.tables.cmap {
+            Some(cmap) => cmap.subtables,
+            None => return cmap::CmapCache::default(),
+        };
+
+        let is_supported = |subtable: &cmap::Subtable| {
+            subtable.is_unicode()
+                && !matches!(
+                    subtable.format,
+                    cmap::Format::MixedCoverage | cmap::Format::UnicodeVariationSequences(..)
+                )
+        };
+
+        let is_full_repertoire = |subtable: &cmap::Subtable| {
+            matches!(
+                subtable.format,
+                cmap::Format::SegmentedCoverage(..) | cmap::Format::ManyToOneRangeMappings(..)
+            )
+        };
+
+        let subtable = subtables
+            .into_iter()
+            .filter(is_supported)
+            .find(is_full_repertoire)
+            .or_else(|| subtables.into_iter().find(is_supported));
+
+        match subtable {
+            Some(subtable) => cmap::CmapCache::from_subtable(&subtable),
+            None => cmap::CmapCache::default(),
+        }
+    }
+
     /// Resolves a Glyph ID for a glyph name.
     ///
     /// Uses the `post` and `CFF` tables as sources.
@@ -1556,19 +2745,23 @@ impl<'a> Face<'a> {
     /// [Unicode Variation Sequences](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-14-unicode-variation-sequences).
     ///
+    /// When a variation sequence is not present in any format 14 subtable,
+    /// the variation selector is ignored and [`glyph_index`](Self::glyph_index) is used instead.
+    ///
     /// Returns `None` instead of `0` when glyph is not found.
     #[inline]
     pub fn glyph_variation_index(&self, code_point: char, variation: char) -> Option<GlyphId> {
         for subtable in self.tables.cmap?.subtables {
             if let cmap::Format::UnicodeVariationSequences(ref table) = subtable.format {
-                return match table.glyph_index(u32::from(code_point), u32::from(variation))? {
-                    cmap::GlyphVariationResult::Found(v) => Some(v),
-                    cmap::GlyphVariationResult::UseDefault => self.glyph_index(code_point),
-                };
+                match table.glyph_index(u32::from(code_point), u32::from(variation)) {
+                    Some(cmap::GlyphVariationResult::Found(v)) => return Some(v),
+                    Some(cmap::GlyphVariationResult::UseDefault) => break,
+                    None => {}
+                }
             }
         }
 
-        None
+        self.glyph_index(code_point)
     }
 
     /// Returns glyph's horizontal advance.
@@ -1578,25 +2771,102 @@ impl<'a> Face<'a> {
     pub fn glyph_hor_advance(&self, glyph_id: GlyphId) -> Option<u16> {
         #[cfg(feature = "variable-fonts")]
         {
-            let mut advance = self.tables.hmtx?.advance(glyph_id)? as f32;
+            let advance = self.glyph_hor_advance_unrounded(glyph_id)?;
+            u16::try_num_from(self.rounding_mode.apply(advance))
+        }
 
-            if self.is_variable() {
-                // Ignore variation offset when `hvar` is not set.
-                if let Some(hvar) = self.tables.hvar {
-                    if let Some(offset) = hvar.advance_offset(glyph_id, self.coords()) {
-                        // We can't use `round()` in `no_std`, so this is the next best thing.
-                        advance += offset + 0.5;
-                    }
-                }
-            }
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            self.tables.hmtx?.advance(glyph_id)
+        }
+    }
 
-            u16::try_num_from(advance)
+    /// Returns glyph's horizontal advance without rounding.
+    ///
+    /// Variation offsets are fractional, therefore the advance of a variable font
+    /// may be fractional as well. Useful when the caller has its own rounding rules.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_hor_advance_unrounded(&self, glyph_id: GlyphId) -> Option<f32> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            self.glyph_hor_advance_at(glyph_id, self.coords())
         }
 
         #[cfg(not(feature = "variable-fonts"))]
         {
-            self.tables.hmtx?.advance(glyph_id)
+            self.tables.hmtx?.advance(glyph_id).map(f32::from)
+        }
+    }
+
+    /// Returns glyph's horizontal advance using the specified variations.
+    ///
+    /// Works the same way as [`glyph_hor_advance`](Self::glyph_hor_advance),
+    /// but face's own variation coordinates are ignored.
+    /// See [`Variations`] for details.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn glyph_hor_advance_with(
+        &self,
+        glyph_id: GlyphId,
+        variations: &Variations,
+    ) -> Option<u16> {
+        let advance = self.glyph_hor_advance_at(glyph_id, variations.as_slice())?;
+        u16::try_num_from(self.rounding_mode.apply(advance))
+    }
+
+    #[cfg(feature = "variable-fonts")]
+    fn glyph_hor_advance_at(
+        &self,
+        glyph_id: GlyphId,
+        coordinates: &[NormalizedCoordinate],
+    ) -> Option<f32> {
+        let advance = f32::from(self.tables.hmtx?.advance(glyph_id)?);
+
+        if self.is_variable() {
+            // Ignore variation offset when `hvar` is not set.
+            if let Some(hvar) = self.tables.hvar {
+                if let Some(offset) = hvar.advance_offset(glyph_id, coordinates) {
+                    return Some(advance + offset);
+                }
+            }
         }
+
+        Some(advance)
+    }
+
+    /// Returns glyph's horizontal advance in pixels for the specified size.
+    ///
+    /// Uses the precomputed widths from the `hdmx` table.
+    /// Returns `None` when there is no `hdmx` table or it has no record for `ppem`.
+    /// In which case the caller should fallback to scaling [`glyph_hor_advance`](Self::glyph_hor_advance).
+    ///
+    /// This method is not affected by variation axes.
+    #[inline]
+    pub fn glyph_pixel_advance(&self, glyph_id: GlyphId, ppem: u16) -> Option<u8> {
+        let ppem = core::convert::TryFrom::try_from(ppem).ok()?;
+        self.tables.hdmx?.records.find(ppem)?.width(glyph_id)
+    }
+
+    /// Returns face's vertical extents in pixels for the specified size as `(y_max, y_min)`.
+    ///
+    /// Uses the `VDMX` table and assumes square pixels, i.e. the 1:1 aspect ratio.
+    /// Returns `None` when there is no `VDMX` table or it has no record for `ppem`.
+    ///
+    /// This method is not affected by variation axes.
+    #[inline]
+    pub fn vertical_device_metrics(&self, ppem: u16) -> Option<(i16, i16)> {
+        let record = self.tables.vdmx?.find_group(1, 1)?.get(ppem)?;
+        Some((record.y_max, record.y_min))
+    }
+
+    /// Returns a grid-fitting and scan-conversion behavior for the specified size.
+    ///
+    /// Returns `None` when there is no `gasp` table.
+    #[inline]
+    pub fn grid_fitting_behavior(&self, ppem: u16) -> Option<gasp::GaspBehavior> {
+        self.tables.gasp.map(|gasp| gasp.behavior(ppem))
     }
 
     /// Returns glyph's vertical advance.
@@ -1606,25 +2876,39 @@ impl<'a> Face<'a> {
     pub fn glyph_ver_advance(&self, glyph_id: GlyphId) -> Option<u16> {
         #[cfg(feature = "variable-fonts")]
         {
-            let mut advance = self.tables.vmtx?.advance(glyph_id)? as f32;
+            let advance = self.glyph_ver_advance_unrounded(glyph_id)?;
+            u16::try_num_from(self.rounding_mode.apply(advance))
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            self.tables.vmtx?.advance(glyph_id)
+        }
+    }
+
+    /// Returns glyph's vertical advance without rounding.
+    ///
+    /// Variation offsets are fractional, therefore the advance of a variable font
+    /// may be fractional as well. Useful when the caller has its own rounding rules.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_ver_advance_unrounded(&self, glyph_id: GlyphId) -> Option<f32> {
+        let advance = f32::from(self.tables.vmtx?.advance(glyph_id)?);
 
+        #[cfg(feature = "variable-fonts")]
+        {
             if self.is_variable() {
                 // Ignore variation offset when `vvar` is not set.
                 if let Some(vvar) = self.tables.vvar {
                     if let Some(offset) = vvar.advance_offset(glyph_id, self.coords()) {
-                        // We can't use `round()` in `no_std`, so this is the next best thing.
-                        advance += offset + 0.5;
+                        return Some(advance + offset);
                     }
                 }
             }
-
-            u16::try_num_from(advance)
         }
 
-        #[cfg(not(feature = "variable-fonts"))]
-        {
-            self.tables.vmtx?.advance(glyph_id)
-        }
+        Some(advance)
     }
 
     /// Returns glyph's horizontal side bearing.
@@ -1640,13 +2924,12 @@ impl<'a> Face<'a> {
                 // Ignore variation offset when `hvar` is not set.
                 if let Some(hvar) = self.tables.hvar {
                     if let Some(offset) = hvar.side_bearing_offset(glyph_id, self.coords()) {
-                        // We can't use `round()` in `no_std`, so this is the next best thing.
-                        bearing += offset + 0.5;
+                        bearing += offset;
                     }
                 }
             }
 
-            i16::try_num_from(bearing)
+            i16::try_num_from(self.rounding_mode.apply(bearing))
         }
 
         #[cfg(not(feature = "variable-fonts"))]
@@ -1668,13 +2951,12 @@ impl<'a> Face<'a> {
                 // Ignore variation offset when `vvar` is not set.
                 if let Some(vvar) = self.tables.vvar {
                     if let Some(offset) = vvar.side_bearing_offset(glyph_id, self.coords()) {
-                        // We can't use `round()` in `no_std`, so this is the next best thing.
-                        bearing += offset + 0.5;
+                        bearing += offset;
                     }
                 }
             }
 
-            i16::try_num_from(bearing)
+            i16::try_num_from(self.rounding_mode.apply(bearing))
         }
 
         #[cfg(not(feature = "variable-fonts"))]
@@ -1723,6 +3005,8 @@ impl<'a> Face<'a> {
     /// `gvar`, `glyf`, `CFF` and `CFF2` tables are supported.
     /// And they will be accesses in this specific order.
     ///
+    /// `glyf` is ignored when `head` declares an unknown glyph data format.
+    ///
     /// This method is affected by variation axes.
     ///
     /// Returns `None` when glyph has no outline or on error.
@@ -1744,56 +3028,269 @@ impl<'a> Face<'a> {
     ///         write!(&mut self.0, "L {} {} ", x, y).unwrap();
     ///     }
     ///
-    ///     fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
-    ///         write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
-    ///     }
+    ///     fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+    ///         write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+    ///     }
+    ///
+    ///     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+    ///         write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+    ///     }
+    ///
+    ///     fn close(&mut self) {
+    ///         write!(&mut self.0, "Z ").unwrap();
+    ///     }
+    /// }
+    ///
+    /// let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    /// let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    /// let mut builder = Builder(String::new());
+    /// let bbox = face.outline_glyph(ttf_parser::GlyphId(1), &mut builder).unwrap();
+    /// assert_eq!(builder.0, "M 173 267 L 369 267 L 270 587 L 173 267 Z M 6 0 L 224 656 \
+    ///                        L 320 656 L 541 0 L 452 0 L 390 200 L 151 200 L 85 0 L 6 0 Z ");
+    /// assert_eq!(bbox, ttf_parser::Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 });
+    /// ```
+    #[inline]
+    pub fn outline_glyph(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        self.outline_glyph_from(glyph_id, OutlineSource::Auto, builder)
+    }
+
+    /// Outlines a glyph using the specified source and returns its tight bounding box.
+    ///
+    /// Unlike [`outline_glyph`](Self::outline_glyph), doesn't fallback to other sources.
+    /// Useful for faces with multiple outline tables.
+    ///
+    /// [`OutlineSource::Glyf`] will use `gvar` as well, when present.
+    ///
+    /// Returns `None` when the requested source is not present, glyph has no outline or on error.
+    pub fn outline_glyph_from(
+        &self,
+        glyph_id: GlyphId,
+        source: OutlineSource,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        self.outline_glyph_from_impl(glyph_id, source, &mut NeverStop(builder))
+    }
+
+    fn outline_glyph_from_impl(
+        &self,
+        glyph_id: GlyphId,
+        source: OutlineSource,
+        builder: &mut dyn StoppableOutlineBuilder,
+    ) -> Option<Rect> {
+        match source {
+            OutlineSource::Auto => {
+                let sources = self.outline_sources();
+                if sources.glyf() {
+                    self.outline_glyph_from_impl(glyph_id, OutlineSource::Glyf, builder)
+                } else if sources.cff() {
+                    self.outline_glyph_from_impl(glyph_id, OutlineSource::Cff, builder)
+                } else {
+                    self.outline_glyph_from_impl(glyph_id, OutlineSource::Cff2, builder)
+                }
+            }
+            OutlineSource::Glyf => {
+                #[cfg(feature = "variable-fonts")]
+                {
+                    if let Some(ref gvar) = self.tables.gvar {
+                        let glyf = self.tables.glyf?;
+                        return gvar.outline_stoppable(glyf, self.coords(), glyph_id, builder);
+                    }
+                }
+
+                self.tables.glyf?.outline_stoppable(glyph_id, builder)
+            }
+            OutlineSource::Cff => self
+                .tables
+                .cff
+                .as_ref()?
+                .outline_stoppable(glyph_id, builder)
+                .ok(),
+            OutlineSource::Cff2 => {
+                #[cfg(feature = "variable-fonts")]
+                {
+                    if let Some(ref cff2) = self.tables.cff2 {
+                        return cff2
+                            .outline_stoppable(self.coords(), glyph_id, builder)
+                            .ok();
+                    }
+                }
+
+                None
+            }
+        }
+    }
+
+    /// Checks that a glyph can be outlined and returns its tight bounding box.
+    ///
+    /// Works the same way as [`outline_glyph`](Self::outline_glyph),
+    /// but without a builder and returns the reason of failure instead of `None`.
+    pub fn validate_glyph_outline(&self, glyph_id: GlyphId) -> Result<Rect, OutlineError> {
+        // Must use the same source as `outline_glyph`.
+        let sources = self.outline_sources();
+        if sources.glyf() {
+            let glyf = self.tables.glyf.ok_or(OutlineError::MissingTable)?;
+            self.outline_glyph_from(glyph_id, OutlineSource::Glyf, &mut DummyOutline)
+                .ok_or_else(|| glyf.outline_error(glyph_id))
+        } else if sources.cff() {
+            let cff = self.tables.cff.as_ref().ok_or(OutlineError::MissingTable)?;
+            Ok(cff.outline(glyph_id, &mut DummyOutline)?)
+        } else {
+            #[cfg(feature = "variable-fonts")]
+            {
+                if sources.cff2() {
+                    let cff2 = self
+                        .tables
+                        .cff2
+                        .as_ref()
+                        .ok_or(OutlineError::MissingTable)?;
+                    return Ok(cff2.outline(self.coords(), glyph_id, &mut DummyOutline)?);
+                }
+            }
+
+            Err(OutlineError::MissingTable)
+        }
+    }
+
+    /// Outlines a glyph using the specified variation coordinates
+    /// and returns its tight bounding box.
+    ///
+    /// Unlike [`set_variation`](Self::set_variation), doesn't modify the face,
+    /// so multiple instances can be outlined from the same face.
+    ///
+    /// `coordinates` are expected to be already normalized and mapped via `avar`,
+    /// just like the ones returned by [`variation_coordinates`](Self::variation_coordinates).
+    /// Missing coordinates are set to zero
+    /// and coordinates past the number of the face's axes are ignored.
+    ///
+    /// Face's own variation coordinates are ignored.
+    #[cfg(feature = "variable-fonts")]
+    pub fn outline_glyph_instance(
+        &self,
+        glyph_id: GlyphId,
+        coordinates: &[NormalizedCoordinate],
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        let mut coords = Variations {
+            len: self.coordinates.len,
+            ..Variations::default()
+        };
+
+        for (to, from) in coords.as_mut_slice().iter_mut().zip(coordinates) {
+            *to = *from;
+        }
+
+        self.outline_glyph_with(glyph_id, &coords, builder)
+    }
+
+    /// Outlines a glyph using the specified variations and returns its tight bounding box.
+    ///
+    /// Works the same way as [`outline_glyph`](Self::outline_glyph),
+    /// but face's own variation coordinates are ignored.
+    /// See [`Variations`] for details.
+    #[cfg(feature = "variable-fonts")]
+    pub fn outline_glyph_with(
+        &self,
+        glyph_id: GlyphId,
+        variations: &Variations,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        // Must use the same source as `outline_glyph`.
+        let sources = self.outline_sources();
+        if sources.glyf() {
+            if let Some(ref gvar) = self.tables.gvar {
+                return gvar.outline(self.tables.glyf?, variations.as_slice(), glyph_id, builder);
+            }
+        } else if !sources.cff() {
+            if let Some(ref cff2) = self.tables.cff2 {
+                return cff2.outline(variations.as_slice(), glyph_id, builder).ok();
+            }
+        }
+
+        // Not a variable outline.
+        self.outline_glyph(glyph_id, builder)
+    }
+
+    /// Outlines a glyph using integer coordinates and returns its tight bounding box.
+    ///
+    /// Works the same way as [`outline_glyph`](Self::outline_glyph),
+    /// but coordinates are rounded to the nearest integer.
     ///
-    ///     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
-    ///         write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
-    ///     }
+    /// Coordinates are exact for `glyf` based, non-variable faces,
+    /// except the implied on-curve points, which are placed in the middle
+    /// between two off-curve points and may have a fractional part.
+    /// `CFF`, `CFF2` and variable faces coordinates are rounded.
+    #[inline]
+    pub fn outline_glyph_i32(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn IntegerOutlineBuilder,
+    ) -> Option<Rect> {
+        self.outline_glyph(glyph_id, &mut IntegerOutline(builder))
+    }
+
+    /// Outlines a glyph and returns its tight bounding box.
     ///
-    ///     fn close(&mut self) {
-    ///         write!(&mut self.0, "Z ").unwrap();
-    ///     }
-    /// }
+    /// Works the same way as [`outline_glyph`](Self::outline_glyph),
+    /// but outlining stops as soon as the builder returns
+    /// [`ControlFlow::Break`].
+    /// Useful when only a part of an outline is needed, like the first contour.
     ///
-    /// let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
-    /// let face = ttf_parser::Face::parse(&data, 0).unwrap();
-    /// let mut builder = Builder(String::new());
-    /// let bbox = face.outline_glyph(ttf_parser::GlyphId(1), &mut builder).unwrap();
-    /// assert_eq!(builder.0, "M 173 267 L 369 267 L 270 587 L 173 267 Z M 6 0 L 224 656 \
-    ///                        L 320 656 L 541 0 L 452 0 L 390 200 L 151 200 L 85 0 L 6 0 Z ");
-    /// assert_eq!(bbox, ttf_parser::Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 });
-    /// ```
+    /// When stopped, the returned bounding box covers only the emitted segments.
     #[inline]
-    pub fn outline_glyph(
+    pub fn outline_glyph_stoppable(
         &self,
         glyph_id: GlyphId,
-        builder: &mut dyn OutlineBuilder,
+        builder: &mut dyn StoppableOutlineBuilder,
     ) -> Option<Rect> {
-        #[cfg(feature = "variable-fonts")]
-        {
-            if let Some(ref gvar) = self.tables.gvar {
-                return gvar.outline(self.tables.glyf?, self.coords(), glyph_id, builder);
-            }
+        self.outline_glyph_from_impl(glyph_id, OutlineSource::Auto, builder)
+    }
+
+    /// Sets the composite glyph nesting depth limit.
+    ///
+    /// Affects only `glyf` based faces, including variable ones.
+    /// The default is [`glyf::MAX_COMPONENT_DEPTH`].
+    /// The value is clamped to [`glyf::MAX_ALLOWED_COMPONENT_DEPTH`].
+    #[inline]
+    pub fn set_outline_depth_limit(&mut self, depth: u8) {
+        if let Some(ref mut glyf) = self.tables.glyf {
+            glyf.set_depth_limit(depth);
         }
+    }
 
-        if let Some(table) = self.tables.glyf {
-            return table.outline(glyph_id, builder);
+    /// Returns a list of outline tables present in the face.
+    ///
+    /// [`outline_glyph`](Self::outline_glyph) will use the first available source
+    /// in the following order: `gvar` (with `glyf`), `glyf`, `CFF `, `CFF2`.
+    ///
+    /// Only successfully parsed tables are reported.
+    /// `gvar` and `CFF2` are never reported without the `variable-fonts` feature.
+    #[inline]
+    pub fn outline_sources(&self) -> OutlineSources {
+        let mut flags = 0;
+        if self.tables.glyf.is_some() {
+            flags |= OutlineSources::GLYF;
         }
 
-        if let Some(ref cff) = self.tables.cff {
-            return cff.outline(glyph_id, builder).ok();
+        if self.tables.cff.is_some() {
+            flags |= OutlineSources::CFF;
         }
 
         #[cfg(feature = "variable-fonts")]
         {
-            if let Some(ref cff2) = self.tables.cff2 {
-                return cff2.outline(self.coords(), glyph_id, builder).ok();
+            if self.tables.gvar.is_some() {
+                flags |= OutlineSources::GVAR;
+            }
+
+            if self.tables.cff2.is_some() {
+                flags |= OutlineSources::CFF2;
             }
         }
 
-        None
+        OutlineSources(flags)
     }
 
     /// Returns a tight glyph bounding box.
@@ -1817,6 +3314,153 @@ impl<'a> Face<'a> {
         self.outline_glyph(glyph_id, &mut DummyOutline)
     }
 
+    /// Returns a glyph bounding box with 32-bit coordinates.
+    ///
+    /// Unlike [`glyph_bounding_box`](Self::glyph_bounding_box), doesn't fail when
+    /// the bounding box doesn't fit into `i16`, which can happen for composite glyphs.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyph_bounding_box_i32(&self, glyph_id: GlyphId) -> Option<RectI32> {
+        let mut builder = BBoxOutline(BBox::new());
+        if let Some(rect) = self.outline_glyph(glyph_id, &mut builder) {
+            return Some(RectI32::from(rect));
+        }
+
+        // Outlining fails either on error or on the `i16` bbox overflow.
+        // Only the last one is acceptable.
+        let bbox = builder.0;
+        if bbox.is_default() || bbox.to_rect().is_some() {
+            return None;
+        }
+
+        bbox.to_rect_i32()
+    }
+
+    /// Returns a glyph bounding box without outlining it, when possible.
+    ///
+    /// For `glyf` based faces, the bounding box stored in the glyph header is returned as is.
+    /// Which is much faster, but the stored bounding box can be malformed
+    /// and isn't affected by variation axes.
+    /// See [`glyf::Table::bbox`] for details.
+    ///
+    /// Other faces fallback to [`glyph_bounding_box`](Self::glyph_bounding_box).
+    #[inline]
+    pub fn glyph_bounding_box_fast(&self, glyph_id: GlyphId) -> Option<Rect> {
+        match self.tables.glyf {
+            Some(glyf) => glyf.bbox(glyph_id),
+            None => self.glyph_bounding_box(glyph_id),
+        }
+    }
+
+    /// Returns glyph's horizontal advance scaled to the specified size.
+    ///
+    /// `ppem` is the size in pixels per EM. The advance is not rounded.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_hor_advance_scaled(&self, glyph_id: GlyphId, ppem: f32) -> Option<f32> {
+        let scale = ppem / f32::from(self.units_per_em());
+        Some(self.glyph_hor_advance_unrounded(glyph_id)? * scale)
+    }
+
+    /// Returns a glyph bounding box scaled to the specified size.
+    ///
+    /// `ppem` is the size in pixels per EM.
+    /// See [`glyph_bounding_box`](Self::glyph_bounding_box) for details.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_bounding_box_scaled(&self, glyph_id: GlyphId, ppem: f32) -> Option<RectF> {
+        let scale = ppem / f32::from(self.units_per_em());
+        let bbox = self.glyph_bounding_box(glyph_id)?;
+        Some(RectF {
+            x_min: f32::from(bbox.x_min) * scale,
+            y_min: f32::from(bbox.y_min) * scale,
+            x_max: f32::from(bbox.x_max) * scale,
+            y_max: f32::from(bbox.y_max) * scale,
+        })
+    }
+
+    /// Returns glyph's advance and ink metrics.
+    ///
+    /// The vertical origin is resolved via `vmtx`, then `VORG`,
+    /// and then the face's ascender is used.
+    ///
+    /// Returns `None` when glyph has no outline or horizontal advance.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyph_metrics(&self, glyph_id: GlyphId) -> Option<GlyphMetrics> {
+        let hor_advance = self.glyph_hor_advance(glyph_id)?;
+        let bbox = self.glyph_bounding_box(glyph_id)?;
+
+        let ascender = i32::from(self.ascender());
+        let ver_advance = match self.glyph_ver_advance(glyph_id) {
+            Some(advance) => advance,
+            None => {
+                let height = ascender - i32::from(self.descender());
+                core::convert::TryFrom::try_from(height).unwrap_or(0)
+            }
+        };
+
+        let top_side_bearing = match self.glyph_ver_side_bearing(glyph_id) {
+            Some(bearing) => i32::from(bearing),
+            None => {
+                let y_origin = self.glyph_y_origin(glyph_id).map(i32::from);
+                y_origin.unwrap_or(ascender) - i32::from(bbox.y_max)
+            }
+        };
+
+        Some(GlyphMetrics {
+            hor_advance,
+            ver_advance,
+            bbox,
+            left_side_bearing: i32::from(bbox.x_min),
+            right_side_bearing: i32::from(hor_advance) - i32::from(bbox.x_max),
+            top_side_bearing,
+            bottom_side_bearing: i32::from(ver_advance) - top_side_bearing + i32::from(bbox.y_min)
+                - i32::from(bbox.y_max),
+        })
+    }
+
+    /// Checks that a point is inside a glyph.
+    ///
+    /// The point is in font units. Uses the nonzero winding rule,
+    /// therefore overlapping contours are handled as well.
+    ///
+    /// Curves are flattened, so points closer than a hundredth of a font unit
+    /// to the outline edge may be misclassified.
+    ///
+    /// Returns `None` when glyph has no outline or on error.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyph_contains_point(&self, glyph_id: GlyphId, x: f32, y: f32) -> Option<bool> {
+        let mut counter = WindingCounter {
+            x,
+            y,
+            winding: 0,
+            start: (0.0, 0.0),
+            last: (0.0, 0.0),
+        };
+
+        self.outline_glyph(glyph_id, &mut outline::Flattener::new(&mut counter, 0.01))?;
+        // Close the last contour.
+        counter.add_edge(counter.start);
+        Some(counter.winding != 0)
+    }
+
+    /// Checks that a glyph is a composite one, i.e. built from other glyphs.
+    ///
+    /// Only `glyf` glyphs can be composite.
+    /// Returns `false` when `glyf` table is not present or glyph ID is out of bounds.
+    #[inline]
+    pub fn is_composite_glyph(&self, glyph_id: GlyphId) -> bool {
+        self.tables
+            .glyf
+            .and_then(|glyf| glyf.number_of_contours(glyph_id))
+            .map(|n| n < 0)
+            .unwrap_or(false)
+    }
+
     /// Returns a bounding box that large enough to enclose any glyph from the face.
     #[inline]
     pub fn global_bounding_box(&self) -> Rect {
@@ -1836,13 +3480,13 @@ impl<'a> Face<'a> {
     /// Note that this method will return an encoded image. It should be decoded
     /// by the caller. We don't validate or preprocess it in any way.
     ///
-    /// Currently, only PNG images are supported.
+    /// PNG, JPEG and TIFF images and monochrome bitmaps are supported.
     ///
     /// Also, a font can contain both: images and outlines. So when this method returns `None`
     /// you should also try `outline_glyph()` afterwards.
     ///
     /// There are multiple ways an image can be stored in a TrueType font
-    /// and this method supports only `sbix`, `CBLC`+`CBDT`.
+    /// and this method supports only `sbix`, `CBLC`+`CBDT` and `EBLC`+`EBDT`.
     /// Font's tables be accesses in this specific order.
     #[inline]
     pub fn glyph_raster_image(
@@ -1860,9 +3504,72 @@ impl<'a> Face<'a> {
             return cbdt.get(glyph_id, pixels_per_em);
         }
 
+        if let Some(ebdt) = self.tables.ebdt {
+            return ebdt.get(glyph_id, pixels_per_em);
+        }
+
+        None
+    }
+
+    /// Checks that the face has any color glyphs.
+    ///
+    /// Checks for `SVG `, `sbix`, `CBLC`+`CBDT` and `COLR` tables presence.
+    /// The `COLR` table is detected only for faces created using
+    /// [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn has_color_glyphs(&self) -> bool {
+        self.tables.svg.is_some()
+            || self.tables.sbix.is_some()
+            || self.tables.cbdt.is_some()
+            || self.has_table(Tag::from_bytes(b"COLR"))
+    }
+
+    /// Returns a color format that covers the specified glyph.
+    ///
+    /// Sources are checked in the `SVG `, `sbix`, `CBLC`+`CBDT` order.
+    /// Raster glyphs are checked in all sizes.
+    ///
+    /// `COLR` glyphs are not supported.
+    pub fn color_glyph_format(&self, glyph_id: GlyphId) -> Option<ColorGlyphFormat> {
+        if self.glyph_svg_image(glyph_id).is_some() {
+            return Some(ColorGlyphFormat::Svg);
+        }
+
+        if let Some(sbix) = self.tables.sbix {
+            let mut strikes = sbix.strikes.into_iter();
+            if strikes.any(|strike| strike.glyph_data(glyph_id).is_some()) {
+                return Some(ColorGlyphFormat::Sbix);
+            }
+        }
+
+        if let Some(cbdt) = self.tables.cbdt {
+            if cbdt.contains(glyph_id) {
+                return Some(ColorGlyphFormat::Cbdt);
+            }
+        }
+
         None
     }
 
+    /// Returns a reference to a glyph's raster image using the specified sources order.
+    ///
+    /// Sources are tried in the specified order and the first found image is returned.
+    /// Missing sources are skipped.
+    ///
+    /// See [`glyph_raster_image`](Self::glyph_raster_image) for details.
+    pub fn glyph_raster_image_with(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: u16,
+        order: &[RasterSource],
+    ) -> Option<RasterGlyphImage<'a>> {
+        order.iter().find_map(|source| match source {
+            RasterSource::Sbix => self.tables.sbix?.best_strike(pixels_per_em)?.get(glyph_id),
+            RasterSource::Cbdt => self.tables.cbdt?.get(glyph_id, pixels_per_em),
+            RasterSource::Ebdt => self.tables.ebdt?.get(glyph_id, pixels_per_em),
+        })
+    }
+
     /// Returns a reference to a glyph's SVG image.
     ///
     /// A font can define a glyph using a raster or a vector image instead of a simple outline.
@@ -1879,6 +3586,123 @@ impl<'a> Face<'a> {
         self.tables.svg.and_then(|svg| svg.documents.find(glyph_id))
     }
 
+    /// Returns a glyph's SVG document, including the range of glyphs it covers.
+    ///
+    /// Same as [`glyph_svg_image`](Self::glyph_svg_image), but since a single SVG document
+    /// can define multiple glyphs, the caller can render it once
+    /// and reuse it for all the glyphs in the range.
+    #[inline]
+    pub fn glyph_svg_document_range(&self, glyph_id: GlyphId) -> Option<svg::SvgDocument<'a>> {
+        self.tables
+            .svg
+            .and_then(|svg| svg.documents.find_document(glyph_id))
+    }
+
+    /// Returns a glyph's SVG image, decompressing it when needed.
+    ///
+    /// Same as [`glyph_svg_image`](Self::glyph_svg_image), but gzip-compressed documents (SVGZ)
+    /// are inflated into `out`. Plain documents are copied into `out` as is.
+    /// `out` is cleared first, but its allocation is reused.
+    /// When decompression fails, `out` is left empty.
+    ///
+    /// Returns `None` when there is no SVG image for the glyph, decompression had failed
+    /// or the decompressed document is larger than [`svg::MAX_DECOMPRESSED_SIZE`].
+    #[cfg(feature = "svgz")]
+    pub fn glyph_svg_image_decompressed(
+        &self,
+        glyph_id: GlyphId,
+        out: &mut std::vec::Vec<u8>,
+    ) -> Option<ImageKind> {
+        let data = self.glyph_svg_image(glyph_id)?;
+        out.clear();
+
+        if !svg::is_gzip(data) {
+            out.extend_from_slice(data);
+            return Some(ImageKind::Svg);
+        }
+
+        let stream = svg::gzip_deflate_stream(data)?;
+        if svg::inflate(stream, out).is_none() {
+            out.clear();
+            return None;
+        }
+
+        Some(ImageKind::Svgz)
+    }
+
+    /// Returns a color palette that should be used by a glyph's SVG image.
+    ///
+    /// SVG documents can reference `CPAL` palette entries via `var(--color0)`,
+    /// `var(--color1)`, etc. custom properties, while `context-fill`/`currentColor`
+    /// should be resolved to the text color by the caller.
+    /// Per the OpenType-SVG spec, the default palette (with index 0) is used
+    /// when an application doesn't select one explicitly.
+    /// Other palettes can be accessed via [`cpal::Table::palette`].
+    ///
+    /// Returns `None` when the glyph doesn't have an SVG image
+    /// or when the `CPAL` table is not present.
+    #[inline]
+    pub fn svg_glyph_palette(&self, glyph_id: GlyphId) -> Option<LazyArray16<'a, cpal::BgraColor>> {
+        self.glyph_svg_image(glyph_id)?;
+        self.tables.cpal?.palette(0)
+    }
+
+    /// Returns an iterator over all feature tags from `GSUB` and `GPOS` tables.
+    ///
+    /// Unlike [`opentype_layout::LayoutTable::features`], isn't limited
+    /// to a specific script and language system and includes unregistered features as well.
+    ///
+    /// Each tag is returned only once. `GSUB` tags are listed first, in the file order.
+    /// Since no allocations are performed, deduplication is done via linear lookups.
+    #[cfg(feature = "opentype-layout")]
+    pub fn all_feature_tags(&self) -> impl Iterator<Item = Tag> + 'a {
+        let gsub = self.tables.gsub.map(|table| table.features);
+        let gpos = self.tables.gpos.map(|table| table.features);
+
+        let gsub_tags = gsub.into_iter().flat_map(|list| {
+            list.into_iter()
+                .enumerate()
+                .filter(move |(i, feature)| !has_feature_tag(list, feature.tag, *i))
+                .map(|(_, feature)| feature.tag)
+        });
+
+        let gpos_tags = gpos.into_iter().flat_map(move |list| {
+            list.into_iter()
+                .enumerate()
+                .filter(move |(i, feature)| {
+                    !has_feature_tag(list, feature.tag, *i)
+                        && !gsub
+                            .into_iter()
+                            .any(|gsub| has_feature_tag(gsub, feature.tag, usize::from(gsub.len())))
+                })
+                .map(|(_, feature)| feature.tag)
+        });
+
+        gsub_tags.chain(gpos_tags)
+    }
+
+    /// Shapes a simple, left-to-right text.
+    ///
+    /// This is a very limited shaper, suitable only for simple Latin, Greek or Cyrillic
+    /// user interface text. It maps characters to glyphs, applies `GSUB` single and ligature
+    /// substitutions and `GPOS` pair positioning for the specified features.
+    /// When `GPOS` is not present, the `kern` feature fallbacks to the `kern` table.
+    /// Usually, `ccmp`, `liga` and `kern` features are used.
+    ///
+    /// Complex scripts, bidirectional text, contextual lookups, lookup flags,
+    /// mark positioning and reordering are not supported. Use a proper shaper for them.
+    ///
+    /// `out` is cleared first. Glyph positions are in font units.
+    #[cfg(all(feature = "std", feature = "opentype-layout"))]
+    pub fn shape_simple(
+        &self,
+        text: &str,
+        features: &[Tag],
+        out: &mut std::vec::Vec<GlyphPosition>,
+    ) {
+        shaping::shape(self, text, features, out)
+    }
+
     /// Returns an iterator over variation axes.
     #[cfg(feature = "variable-fonts")]
     #[inline]
@@ -1886,9 +3710,57 @@ impl<'a> Face<'a> {
         self.tables.fvar.map(|fvar| fvar.axes).unwrap_or_default()
     }
 
+    /// Returns an iterator over variation axes with resolved names.
+    ///
+    /// A more high-level alternative to [`Face::variation_axes`].
+    #[cfg(feature = "variable-fonts")]
+    pub fn axis_info(&self) -> impl Iterator<Item = AxisInfo> + 'a {
+        #[cfg(feature = "std")]
+        let names = self.names();
+        self.variation_axes().into_iter().map(move |axis| AxisInfo {
+            tag: axis.tag,
+            name_id: axis.name_id,
+            #[cfg(feature = "std")]
+            name: names
+                .get_localized(axis.name_id, Language::English)
+                .and_then(|name| name.to_string()),
+            min: axis.min_value,
+            default: axis.def_value,
+            max: axis.max_value,
+            hidden: axis.hidden,
+        })
+    }
+
+    /// Returns face's style attributes.
+    ///
+    /// Describes design axes and named positions on them,
+    /// which can be used to compose family and face names.
+    ///
+    /// Returns `None` when `STAT` table is not present.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn style_attributes(&self) -> Option<stat::Table<'a>> {
+        self.tables.stat
+    }
+
+    /// Returns a name of a value on the specified variation axis, like *Bold* for `wght` 700.
+    ///
+    /// The name is resolved via a matching `STAT` axis value table.
+    /// See [`stat::Table::axis_value`] for the lookup rules.
+    ///
+    /// Returns `None` when `STAT` table is not present, no axis value table matches
+    /// or the name is not present in the `name` table or cannot be decoded.
+    #[cfg(all(feature = "std", feature = "variable-fonts"))]
+    pub fn axis_value_name(&self, tag: Tag, value: f32) -> Option<std::string::String> {
+        let axis_value = self.tables.stat?.axis_value(tag, value)?;
+        self.names()
+            .get_localized(axis_value.value_name_id(), Language::English)?
+            .to_string()
+    }
+
     /// Sets a variation axis coordinate.
     ///
-    /// This is the only mutable method in the library.
+    /// This is one of the few mutable methods in the library.
     /// We can simplify the API a lot by storing the variable coordinates
     /// in the face object itself.
     ///
@@ -1901,30 +3773,113 @@ impl<'a> Face<'a> {
             return None;
         }
 
-        let v = self
-            .variation_axes()
-            .into_iter()
-            .enumerate()
-            .find(|(_, a)| a.tag == axis);
-        if let Some((idx, a)) = v {
-            if idx >= MAX_VAR_COORDS {
-                return None;
-            }
+        if !self.set_unmapped_variation(axis, value) {
+            return None;
+        }
 
-            self.coordinates.data[idx] = a.normalized_value(value);
-        } else {
+        self.apply_avar();
+        Some(())
+    }
+
+    /// Sets multiple variation axes coordinates at once.
+    ///
+    /// Works the same way as calling [`set_variation`](Self::set_variation)
+    /// for each variation, but applies the `avar` mapping only once.
+    ///
+    /// Unknown axes are ignored.
+    ///
+    /// Returns `None` when face is not variable or none of the variations were applied.
+    #[cfg(feature = "variable-fonts")]
+    pub fn set_variations(&mut self, variations: &[Variation]) -> Option<()> {
+        if !self.is_variable() {
             return None;
         }
 
-        // TODO: optimize
-        if let Some(avar) = self.tables.avar {
-            // Ignore error.
-            let _ = avar.map_coordinates(self.coordinates.as_mut_slice());
+        let mut applied = false;
+        for variation in variations {
+            applied |= self.set_unmapped_variation(variation.axis, variation.value);
+        }
+
+        if !applied {
+            return None;
+        }
+
+        self.apply_avar();
+        Some(())
+    }
+
+    /// Sets all variation axes coordinates to the ones of a named instance.
+    ///
+    /// Named instances can be obtained via [`fvar::Table::instances`].
+    ///
+    /// Returns `None` when face is not variable or when the number of instance coordinates
+    /// doesn't match the number of axes.
+    #[cfg(feature = "variable-fonts")]
+    pub fn set_named_variation(&mut self, instance: fvar::NamedInstance) -> Option<()> {
+        let axes = self.tables.fvar?.axes;
+        if instance.coordinates.len() != axes.len() {
+            return None;
+        }
+
+        for (idx, (axis, value)) in axes.into_iter().zip(instance.coordinates).enumerate() {
+            if idx >= MAX_VAR_COORDS {
+                break;
+            }
+
+            self.coordinates.data[idx] = axis.normalized_value(value.0);
         }
 
+        self.apply_avar();
         Some(())
     }
 
+    /// Resets all variation axes coordinates to the default ones.
+    ///
+    /// Does nothing when face is not variable.
+    #[cfg(feature = "variable-fonts")]
+    pub fn reset_variations(&mut self) {
+        if !self.is_variable() {
+            return;
+        }
+
+        for c in self.coordinates.as_mut_slice() {
+            *c = NormalizedCoordinate::default();
+        }
+
+        self.apply_avar();
+    }
+
+    // Sets a normalized coordinate without the `avar` mapping.
+    #[cfg(feature = "variable-fonts")]
+    fn set_unmapped_variation(&mut self, axis: Tag, value: f32) -> bool {
+        let axes = self.variation_axes();
+        self.coordinates.set_unmapped(axes, axis, value)
+    }
+
+    #[cfg(feature = "variable-fonts")]
+    fn apply_avar(&mut self) {
+        self.coordinates.apply_avar(self.tables.avar);
+    }
+
+    /// Sets a rounding mode for variation-adjusted metrics.
+    ///
+    /// Affects glyph advances and side bearings, as well as `MVAR`-adjusted face metrics.
+    /// Non-variable fonts are not affected, since their metrics are integers already.
+    ///
+    /// Uses [`RoundingMode::Round`] by default.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn set_rounding_mode(&mut self, mode: RoundingMode) {
+        self.rounding_mode = mode;
+    }
+
+    /// Returns the current rounding mode for variation-adjusted metrics.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
     /// Returns the current normalized variation coordinates.
     #[cfg(feature = "variable-fonts")]
     #[inline]
@@ -1932,6 +3887,40 @@ impl<'a> Face<'a> {
         self.coordinates.as_slice()
     }
 
+    /// Checks that two sets of normalized variation coordinates are equivalent.
+    ///
+    /// Both sets are mapped via `avar` first, so coordinates that are different
+    /// after the default normalization, but end up the same after the `avar` mapping,
+    /// are treated as equal. Such coordinates will produce identical outlines and metrics.
+    ///
+    /// Missing coordinates are treated as default ones (zero)
+    /// and coordinates past the number of the face's axes are ignored.
+    ///
+    /// Always returns `true` for non-variable faces.
+    #[cfg(feature = "variable-fonts")]
+    pub fn variations_equivalent(
+        &self,
+        a: &[NormalizedCoordinate],
+        b: &[NormalizedCoordinate],
+    ) -> bool {
+        self.map_variation_coordinates(a).as_slice() == self.map_variation_coordinates(b).as_slice()
+    }
+
+    #[cfg(feature = "variable-fonts")]
+    fn map_variation_coordinates(&self, coordinates: &[NormalizedCoordinate]) -> Variations {
+        let mut mapped = Variations {
+            len: self.coordinates.len,
+            ..Variations::default()
+        };
+
+        for (to, from) in mapped.as_mut_slice().iter_mut().zip(coordinates) {
+            *to = *from;
+        }
+
+        mapped.apply_avar(self.tables.avar);
+        mapped
+    }
+
     /// Checks that face has non-default variation coordinates.
     #[cfg(feature = "variable-fonts")]
     #[inline]
@@ -1959,8 +3948,7 @@ impl<'a> Face<'a> {
     fn apply_metrics_variation_to(&self, tag: Tag, value: &mut i16) {
         if self.is_variable() {
             let v = f32::from(*value) + self.metrics_var_offset(tag);
-            // TODO: Should probably round it, but f32::round is not available in core.
-            if let Some(v) = i16::try_num_from(v) {
+            if let Some(v) = i16::try_num_from(self.rounding_mode.apply(v)) {
                 *value = v;
             }
         }
@@ -1977,6 +3965,12 @@ impl<'a> Face<'a> {
     }
 }
 
+/// Checks that the first `len` features in the list contain a tag.
+#[cfg(feature = "opentype-layout")]
+fn has_feature_tag(list: opentype_layout::FeatureList, tag: Tag, len: usize) -> bool {
+    list.into_iter().take(len).any(|feature| feature.tag == tag)
+}
+
 struct DefaultTableProvider<'a> {
     data: &'a [u8],
     tables: LazyArrayIter16<'a, TableRecord>,
@@ -0,0 +1,741 @@
+//! [`OutlineBuilder`] adapters.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::OutlineBuilder;
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+enum Segment {
+    Line(f32, f32),
+    Quad(f32, f32, f32, f32),
+    Curve(f32, f32, f32, f32, f32, f32),
+}
+
+#[cfg(feature = "std")]
+impl Segment {
+    #[inline]
+    fn end(&self) -> (f32, f32) {
+        match *self {
+            Segment::Line(x, y) => (x, y),
+            Segment::Quad(_, _, x, y) => (x, y),
+            Segment::Curve(_, _, _, _, x, y) => (x, y),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+struct Contour {
+    start: (f32, f32),
+    segments: Vec<Segment>,
+}
+
+#[cfg(feature = "std")]
+impl Contour {
+    // Iterates over all the contour points, including control ones.
+    fn points(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        core::iter::once(self.start).chain(self.segments.iter().flat_map(|segment| {
+            let (a, b, c) = match *segment {
+                Segment::Line(x, y) => (None, None, (x, y)),
+                Segment::Quad(x1, y1, x, y) => (Some((x1, y1)), None, (x, y)),
+                Segment::Curve(x1, y1, x2, y2, x, y) => (Some((x1, y1)), Some((x2, y2)), (x, y)),
+            };
+
+            a.into_iter().chain(b).chain(core::iter::once(c))
+        }))
+    }
+
+    // Positive when counter-clockwise in a y-up coordinate system.
+    fn signed_area(&self) -> f32 {
+        let mut area = 0.0;
+        let mut prev = self.start;
+        for p in self.points().skip(1).chain(core::iter::once(self.start)) {
+            area += prev.0 * p.1 - p.0 * prev.1;
+            prev = p;
+        }
+
+        area * 0.5
+    }
+
+    // Uses the even-odd rule on the control polygon.
+    fn contains(&self, x: f32, y: f32) -> bool {
+        let mut inside = false;
+        let mut prev = self.start;
+        for p in self.points().skip(1).chain(core::iter::once(self.start)) {
+            if (p.1 > y) != (prev.1 > y) && x < (prev.0 - p.0) * (y - p.1) / (prev.1 - p.1) + p.0 {
+                inside = !inside;
+            }
+
+            prev = p;
+        }
+
+        inside
+    }
+
+    fn emit(&self, clockwise: bool, builder: &mut dyn OutlineBuilder) {
+        builder.move_to(self.start.0, self.start.1);
+        for segment in &self.segments {
+            match *segment {
+                Segment::Line(x, y) => builder.line_to(x, y),
+                Segment::Quad(x1, y1, x, y) => builder.quad_to(x1, y1, x, y),
+                Segment::Curve(x1, y1, x2, y2, x, y) => builder.curve_to(x1, y1, x2, y2, x, y),
+            }
+        }
+        builder.contour_winding(clockwise);
+        builder.close();
+    }
+
+    fn emit_reversed(&self, clockwise: bool, builder: &mut dyn OutlineBuilder) {
+        builder.move_to(self.start.0, self.start.1);
+
+        // The closing segment is implicit, therefore we have to emit it explicitly.
+        if let Some(last) = self.segments.last() {
+            let (x, y) = last.end();
+            if (x, y) != self.start {
+                builder.line_to(x, y);
+            }
+        }
+
+        for (i, segment) in self.segments.iter().enumerate().rev() {
+            let (x, y) = match i.checked_sub(1) {
+                Some(prev) => self.segments[prev].end(),
+                None => self.start,
+            };
+
+            match *segment {
+                Segment::Line(..) => builder.line_to(x, y),
+                Segment::Quad(x1, y1, ..) => builder.quad_to(x1, y1, x, y),
+                Segment::Curve(x1, y1, x2, y2, ..) => builder.curve_to(x2, y2, x1, y1, x, y),
+            }
+        }
+
+        builder.contour_winding(clockwise);
+        builder.close();
+    }
+}
+
+/// An [`OutlineBuilder`] adapter that normalizes contours winding.
+///
+/// Outer contours are emitted counter-clockwise and inner ones (holes) clockwise,
+/// assuming a y-up coordinate system, as used by fonts.
+/// This is useful for renderers that support only the non-zero fill rule,
+/// since some fonts have inconsistent winding.
+///
+/// Contours nesting is determined by a heuristic: a contour is considered to be inside
+/// of another one when its first point is inside of the other contour's control polygon.
+/// Contours with an even nesting depth are outer ones and with an odd depth are holes.
+/// The current winding is determined by the sign of the control polygon area.
+/// Contours with a zero area are emitted as is.
+///
+/// Since nesting can be resolved only when all contours are known,
+/// the whole outline is buffered and will be passed to the underlying builder
+/// only after calling [`finish`](Self::finish).
+///
+/// # Example
+///
+/// ```
+/// use ttf_parser::outline::NormalizeWinding;
+/// # struct Builder;
+/// # impl ttf_parser::OutlineBuilder for Builder {
+/// #     fn move_to(&mut self, _: f32, _: f32) {}
+/// #     fn line_to(&mut self, _: f32, _: f32) {}
+/// #     fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn close(&mut self) {}
+/// # }
+/// fn example(face: &ttf_parser::Face) {
+///     let mut builder = Builder;
+///     let mut normalizer = NormalizeWinding::new(&mut builder);
+///     face.outline_glyph(ttf_parser::GlyphId(1), &mut normalizer);
+///     normalizer.finish();
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub struct NormalizeWinding<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    contours: Vec<Contour>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> NormalizeWinding<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder) -> Self {
+        NormalizeWinding {
+            builder,
+            contours: Vec::new(),
+        }
+    }
+
+    /// Passes all the buffered contours to the underlying builder.
+    pub fn finish(self) {
+        for contour in &self.contours {
+            let area = contour.signed_area();
+            if area == 0.0 {
+                contour.emit(false, self.builder);
+                continue;
+            }
+
+            let (x, y) = contour.start;
+            let depth = self
+                .contours
+                .iter()
+                .filter(|other| !core::ptr::eq(*other, contour) && other.contains(x, y))
+                .count();
+
+            let is_outer = depth % 2 == 0;
+            let is_ccw = area > 0.0;
+            if is_outer == is_ccw {
+                contour.emit(!is_outer, self.builder);
+            } else {
+                contour.emit_reversed(!is_outer, self.builder);
+            }
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, segment: Segment) {
+        if let Some(contour) = self.contours.last_mut() {
+            contour.segments.push(segment);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for NormalizeWinding<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "NormalizeWinding {{ ... }}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl OutlineBuilder for NormalizeWinding<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.contours.push(Contour {
+            start: (x, y),
+            segments: Vec::new(),
+        });
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(Segment::Line(x, y));
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.push(Segment::Quad(x1, y1, x, y));
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.push(Segment::Curve(x1, y1, x2, y2, x, y));
+    }
+
+    #[inline]
+    fn close(&mut self) {}
+}
+
+/// A 2x3 affine transformation matrix.
+///
+/// Maps a point as `x' = sx * x + kx * y + tx` and `y' = ky * x + sy * y + ty`.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform {
+    pub sx: f32,
+    pub ky: f32,
+    pub kx: f32,
+    pub sy: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Transform {
+    /// Creates a new transform.
+    #[inline]
+    pub fn new(sx: f32, ky: f32, kx: f32, sy: f32, tx: f32, ty: f32) -> Self {
+        Transform {
+            sx,
+            ky,
+            kx,
+            sy,
+            tx,
+            ty,
+        }
+    }
+
+    /// Creates a new scaling transform.
+    #[inline]
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Transform::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Creates a new translating transform.
+    #[inline]
+    pub fn translate(tx: f32, ty: f32) -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Returns a transform that applies `self` first and `other` afterwards.
+    #[inline]
+    pub fn then(&self, other: &Transform) -> Self {
+        Transform {
+            sx: other.sx * self.sx + other.kx * self.ky,
+            ky: other.ky * self.sx + other.sy * self.ky,
+            kx: other.sx * self.kx + other.kx * self.sy,
+            sy: other.ky * self.kx + other.sy * self.sy,
+            tx: other.sx * self.tx + other.kx * self.ty + other.tx,
+            ty: other.ky * self.tx + other.sy * self.ty + other.ty,
+        }
+    }
+
+    /// Applies the transform to a point.
+    #[inline]
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.sx * x + self.kx * y + self.tx,
+            self.ky * x + self.sy * y + self.ty,
+        )
+    }
+}
+
+impl Default for Transform {
+    /// Returns an identity transform.
+    #[inline]
+    fn default() -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+}
+
+/// An [`OutlineBuilder`] adapter that applies a [`Transform`] to all points,
+/// including control ones.
+///
+/// Usually used to convert font units into pixels.
+///
+/// # Example
+///
+/// ```
+/// use ttf_parser::outline::{Transform, TransformedOutline};
+/// # struct Builder;
+/// # impl ttf_parser::OutlineBuilder for Builder {
+/// #     fn move_to(&mut self, _: f32, _: f32) {}
+/// #     fn line_to(&mut self, _: f32, _: f32) {}
+/// #     fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn close(&mut self) {}
+/// # }
+/// fn example(face: &ttf_parser::Face) {
+///     // Scale to 16px and flip the y axis, since fonts are y-up.
+///     let scale = 16.0 / f32::from(face.units_per_em());
+///     let ts = Transform::scale(scale, -scale).then(&Transform::translate(0.0, 16.0));
+///
+///     let mut builder = Builder;
+///     let mut transformed = TransformedOutline::new(&mut builder, ts);
+///     face.outline_glyph(ttf_parser::GlyphId(1), &mut transformed);
+/// }
+/// ```
+pub struct TransformedOutline<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    transform: Transform,
+}
+
+impl<'a> TransformedOutline<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, transform: Transform) -> Self {
+        TransformedOutline { builder, transform }
+    }
+}
+
+impl core::fmt::Debug for TransformedOutline<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "TransformedOutline {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for TransformedOutline<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.transform.apply(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.transform.apply(x, y);
+        self.builder.line_to(x, y);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.transform.apply(x1, y1);
+        let (x, y) = self.transform.apply(x, y);
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.transform.apply(x1, y1);
+        let (x2, y2) = self.transform.apply(x2, y2);
+        let (x, y) = self.transform.apply(x, y);
+        self.builder.curve_to(x1, y1, x2, y2, x, y);
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        // A transform with a negative determinant mirrors the outline.
+        let t = &self.transform;
+        let is_mirrored = t.sx * t.sy - t.kx * t.ky < 0.0;
+        self.builder.contour_winding(clockwise != is_mirrored);
+    }
+}
+
+// Limits the subdivision of malformed or NaN segments.
+const MAX_FLATTENING_DEPTH: u8 = 16;
+
+/// An [`OutlineBuilder`] adapter that converts curves into lines.
+///
+/// Curves are subdivided adaptively, until each line deviates from the curve
+/// by no more than `tolerance`, which is in the same units as the outline.
+/// Therefore, larger curves produce more lines.
+///
+/// `move_to` and `close` are passed through as is.
+///
+/// # Example
+///
+/// ```
+/// use ttf_parser::outline::Flattener;
+/// # struct Builder;
+/// # impl ttf_parser::OutlineBuilder for Builder {
+/// #     fn move_to(&mut self, _: f32, _: f32) {}
+/// #     fn line_to(&mut self, _: f32, _: f32) {}
+/// #     fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn close(&mut self) {}
+/// # }
+/// fn example(face: &ttf_parser::Face) {
+///     let mut builder = Builder;
+///     // A quarter of a font unit.
+///     let mut flattener = Flattener::new(&mut builder, 0.25);
+///     face.outline_glyph(ttf_parser::GlyphId(1), &mut flattener);
+/// }
+/// ```
+pub struct Flattener<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    tolerance: f32,
+    start: (f32, f32),
+    last: (f32, f32),
+}
+
+impl<'a> Flattener<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, tolerance: f32) -> Self {
+        Flattener {
+            builder,
+            tolerance,
+            start: (0.0, 0.0),
+            last: (0.0, 0.0),
+        }
+    }
+
+    fn flatten_quad(&mut self, p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), depth: u8) {
+        // The maximum distance between a quadratic curve and its chord
+        // is a quarter of `p0 - 2 * p1 + p2`.
+        let dx = p0.0 - 2.0 * p1.0 + p2.0;
+        let dy = p0.1 - 2.0 * p1.1 + p2.1;
+        let limit = 4.0 * self.tolerance;
+        if depth == MAX_FLATTENING_DEPTH || dx * dx + dy * dy <= limit * limit {
+            self.builder.line_to(p2.0, p2.1);
+            return;
+        }
+
+        let p01 = mid(p0, p1);
+        let p12 = mid(p1, p2);
+        let p012 = mid(p01, p12);
+        self.flatten_quad(p0, p01, p012, depth + 1);
+        self.flatten_quad(p012, p12, p2, depth + 1);
+    }
+
+    fn flatten_cubic(
+        &mut self,
+        p0: (f32, f32),
+        p1: (f32, f32),
+        p2: (f32, f32),
+        p3: (f32, f32),
+        depth: u8,
+    ) {
+        // The maximum distance between a cubic curve and its chord
+        // is bound by a sixteenth of this value.
+        let ux = 3.0 * p1.0 - 2.0 * p0.0 - p3.0;
+        let uy = 3.0 * p1.1 - 2.0 * p0.1 - p3.1;
+        let vx = 3.0 * p2.0 - 2.0 * p3.0 - p0.0;
+        let vy = 3.0 * p2.1 - 2.0 * p3.1 - p0.1;
+        let flatness = (ux * ux).max(vx * vx) + (uy * uy).max(vy * vy);
+        let limit = 4.0 * self.tolerance;
+        if depth == MAX_FLATTENING_DEPTH || flatness <= limit * limit {
+            self.builder.line_to(p3.0, p3.1);
+            return;
+        }
+
+        let p01 = mid(p0, p1);
+        let p12 = mid(p1, p2);
+        let p23 = mid(p2, p3);
+        let p012 = mid(p01, p12);
+        let p123 = mid(p12, p23);
+        let p0123 = mid(p012, p123);
+        self.flatten_cubic(p0, p01, p012, p0123, depth + 1);
+        self.flatten_cubic(p0123, p123, p23, p3, depth + 1);
+    }
+}
+
+#[inline]
+fn mid(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    ((a.0 + b.0) * 0.5, (a.1 + b.1) * 0.5)
+}
+
+impl core::fmt::Debug for Flattener<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Flattener {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for Flattener<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = (x, y);
+        self.last = (x, y);
+        self.builder.move_to(x, y);
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.last = (x, y);
+        self.builder.line_to(x, y);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.flatten_quad(self.last, (x1, y1), (x, y), 0);
+        self.last = (x, y);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.flatten_cubic(self.last, (x1, y1), (x2, y2), (x, y), 0);
+        self.last = (x, y);
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.last = self.start;
+        self.builder.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.builder.contour_winding(clockwise);
+    }
+}
+
+/// An [`OutlineBuilder`] adapter that flips the x axis.
+///
+/// Each `x` coordinate is replaced with `width - x`,
+/// i.e. the outline is mirrored around the `width / 2` vertical line.
+/// Use zero `width` to simply negate coordinates.
+pub struct FlipX<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    width: f32,
+}
+
+impl<'a> FlipX<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, width: f32) -> Self {
+        FlipX { builder, width }
+    }
+}
+
+impl core::fmt::Debug for FlipX<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "FlipX {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for FlipX<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.builder.move_to(self.width - x, y);
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(self.width - x, y);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.builder.quad_to(self.width - x1, y1, self.width - x, y);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let w = self.width;
+        self.builder.curve_to(w - x1, y1, w - x2, y2, w - x, y);
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.builder.contour_winding(!clockwise);
+    }
+}
+
+/// An [`OutlineBuilder`] adapter that flips the y axis.
+///
+/// Each `y` coordinate is replaced with `height - y`,
+/// i.e. the outline is mirrored around the `height / 2` horizontal line.
+/// Use zero `height` to simply negate coordinates.
+///
+/// Font outlines are y-up, therefore this adapter is usually used to render
+/// into a y-down coordinate system, like a screen. In which case, `height` is
+/// the baseline position, e.g. the [ascender](crate::Face::ascender).
+///
+/// # Example
+///
+/// ```
+/// use ttf_parser::outline::FlipY;
+/// # struct Builder;
+/// # impl ttf_parser::OutlineBuilder for Builder {
+/// #     fn move_to(&mut self, _: f32, _: f32) {}
+/// #     fn line_to(&mut self, _: f32, _: f32) {}
+/// #     fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+/// #     fn close(&mut self) {}
+/// # }
+/// fn example(face: &ttf_parser::Face) {
+///     let mut builder = Builder;
+///     let mut flipped = FlipY::new(&mut builder, f32::from(face.ascender()));
+///     face.outline_glyph(ttf_parser::GlyphId(1), &mut flipped);
+/// }
+/// ```
+pub struct FlipY<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    height: f32,
+}
+
+impl<'a> FlipY<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, height: f32) -> Self {
+        FlipY { builder, height }
+    }
+}
+
+impl core::fmt::Debug for FlipY<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "FlipY {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for FlipY<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.builder.move_to(x, self.height - y);
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(x, self.height - y);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.builder
+            .quad_to(x1, self.height - y1, x, self.height - y);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let h = self.height;
+        self.builder.curve_to(x1, h - y1, x2, h - y2, x, h - y);
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.builder.contour_winding(!clockwise);
+    }
+}
+
+/// An [`OutlineBuilder`] adapter that offsets all points.
+pub struct Translate<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    tx: f32,
+    ty: f32,
+}
+
+impl<'a> Translate<'a> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, tx: f32, ty: f32) -> Self {
+        Translate { builder, tx, ty }
+    }
+}
+
+impl core::fmt::Debug for Translate<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Translate {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for Translate<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.builder.move_to(x + self.tx, y + self.ty);
+    }
+
+    #[inline]
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(x + self.tx, y + self.ty);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (tx, ty) = (self.tx, self.ty);
+        self.builder.quad_to(x1 + tx, y1 + ty, x + tx, y + ty);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (tx, ty) = (self.tx, self.ty);
+        self.builder
+            .curve_to(x1 + tx, y1 + ty, x2 + tx, y2 + ty, x + tx, y + ty);
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    #[inline]
+    fn contour_winding(&mut self, clockwise: bool) {
+        self.builder.contour_winding(clockwise);
+    }
+}
@@ -0,0 +1,176 @@
+//! A minimal shaper for simple scripts.
+
+use std::vec::Vec;
+
+use crate::gpos::PositioningSubtable;
+use crate::gsub::SubstitutionSubtable;
+use crate::opentype_layout::{LayoutTable, LookupIndex};
+use crate::{Face, GlyphId, Tag};
+
+/// A positioned glyph.
+///
+/// Produced by [`Face::shape_simple`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlyphPosition {
+    /// A glyph ID.
+    pub glyph_id: GlyphId,
+    /// A byte offset of the first character in the source text that produced this glyph.
+    pub cluster: usize,
+    /// A horizontal advance, in font units.
+    pub x_advance: i32,
+    /// A horizontal offset, in font units.
+    ///
+    /// Doesn't affect the pen position.
+    pub x_offset: i32,
+    /// A vertical offset, in font units.
+    ///
+    /// Doesn't affect the pen position.
+    pub y_offset: i32,
+}
+
+const KERN: Tag = Tag::from_bytes(b"kern");
+const DEFAULT_LANGUAGE: Tag = Tag::from_bytes(b"dflt");
+
+pub(crate) fn shape(face: &Face, text: &str, features: &[Tag], out: &mut Vec<GlyphPosition>) {
+    out.clear();
+    out.extend(text.char_indices().map(|(cluster, c)| GlyphPosition {
+        glyph_id: face.glyph_index(c).unwrap_or(GlyphId(0)),
+        cluster,
+        x_advance: 0,
+        x_offset: 0,
+        y_offset: 0,
+    }));
+
+    let script = detect_script(text);
+
+    if let Some(gsub) = face.tables().gsub {
+        for index in lookup_indices(&gsub, script, features) {
+            substitute(&gsub, index, out);
+        }
+    }
+
+    for pos in out.iter_mut() {
+        pos.x_advance = i32::from(face.glyph_hor_advance(pos.glyph_id).unwrap_or(0));
+    }
+
+    if let Some(gpos) = face.tables().gpos {
+        for index in lookup_indices(&gpos, script, features) {
+            position(&gpos, index, out);
+        }
+    } else if features.contains(&KERN) {
+        kern(face, out);
+    }
+}
+
+// Only Latin, Greek and Cyrillic are supported,
+// therefore the first letter from those scripts defines the whole run.
+fn detect_script(text: &str) -> Tag {
+    for c in text.chars() {
+        match u32::from(c) {
+            0x0370..=0x03FF | 0x1F00..=0x1FFF => return Tag::from_bytes(b"grek"),
+            0x0400..=0x052F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => {
+                return Tag::from_bytes(b"cyrl")
+            }
+            _ if c.is_alphabetic() => return Tag::from_bytes(b"latn"),
+            _ => {}
+        }
+    }
+
+    Tag::from_bytes(b"latn")
+}
+
+// Lookups must be applied in the lookup list order and not in the features order.
+fn lookup_indices(table: &LayoutTable, script: Tag, features: &[Tag]) -> Vec<LookupIndex> {
+    let mut indices: Vec<LookupIndex> = table
+        .features(script, DEFAULT_LANGUAGE)
+        .filter(|feature| features.contains(&feature.tag))
+        .flat_map(|feature| feature.lookup_indices)
+        .collect();
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+fn substitute(table: &LayoutTable, lookup_index: LookupIndex, buffer: &mut Vec<GlyphPosition>) {
+    let lookup = match table.lookups.get(lookup_index) {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut glyphs: Vec<GlyphId> = buffer.iter().map(|pos| pos.glyph_id).collect();
+    let mut i = 0;
+    while i < buffer.len() {
+        for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+            match subtable {
+                SubstitutionSubtable::Single(ref single) => {
+                    if let Some(glyph_id) = single.substitute(glyphs[i]) {
+                        buffer[i].glyph_id = glyph_id;
+                        glyphs[i] = glyph_id;
+                        break;
+                    }
+                }
+                SubstitutionSubtable::Ligature(ref ligature) => {
+                    if let Some((glyph_id, len)) = ligature.ligature(&glyphs[i..]) {
+                        // A ligature inherits the cluster of its first component.
+                        buffer[i].glyph_id = glyph_id;
+                        buffer.drain(i + 1..i + len);
+                        glyphs[i] = glyph_id;
+                        glyphs.drain(i + 1..i + len);
+                        break;
+                    }
+                }
+                // Other substitution types are not supported.
+                _ => {}
+            }
+        }
+
+        i += 1;
+    }
+}
+
+fn position(table: &LayoutTable, lookup_index: LookupIndex, buffer: &mut [GlyphPosition]) {
+    let lookup = match table.lookups.get(lookup_index) {
+        Some(v) => v,
+        None => return,
+    };
+
+    for i in 1..buffer.len() {
+        let (first, second) = (buffer[i - 1].glyph_id, buffer[i].glyph_id);
+        for subtable in lookup.subtables.into_iter::<PositioningSubtable>() {
+            // Only pair positioning is supported.
+            if let PositioningSubtable::Pair(ref pair) = subtable {
+                if let Some((record1, record2)) = pair.get(first, second) {
+                    let pos = &mut buffer[i - 1];
+                    pos.x_advance += i32::from(record1.x_advance);
+                    pos.x_offset += i32::from(record1.x_placement);
+                    pos.y_offset += i32::from(record1.y_placement);
+
+                    let pos = &mut buffer[i];
+                    pos.x_advance += i32::from(record2.x_advance);
+                    pos.x_offset += i32::from(record2.x_placement);
+                    pos.y_offset += i32::from(record2.y_placement);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+// A fallback for fonts without `GPOS`.
+fn kern(face: &Face, buffer: &mut [GlyphPosition]) {
+    let table = match face.tables().kern {
+        Some(v) => v,
+        None => return,
+    };
+
+    for i in 1..buffer.len() {
+        let (first, second) = (buffer[i - 1].glyph_id, buffer[i].glyph_id);
+        for subtable in table.subtables {
+            if subtable.horizontal && !subtable.has_cross_stream && !subtable.variable {
+                if let Some(value) = subtable.glyphs_kerning(first, second) {
+                    buffer[i - 1].x_advance += i32::from(value);
+                }
+            }
+        }
+    }
+}
@@ -70,6 +70,8 @@ impl<'a> Table<'a> {
     }
 
     /// Returns a list of anchor points for the specified glyph.
+    ///
+    /// Returns `None` when glyph has no anchor points entry.
     pub fn points(&self, glyph_id: GlyphId) -> Option<LazyArray32<'a, Point>> {
         let offset = self.lookup.value(glyph_id)?;
 
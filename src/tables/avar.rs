@@ -3,7 +3,8 @@
 
 use core::convert::TryFrom;
 
-use crate::parser::{FromData, LazyArray16, Stream};
+use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream, TryNumFrom};
+use crate::var_store::{DeltaSetIndexMap, ItemVariationStore};
 use crate::NormalizedCoordinate;
 
 /// An axis value map.
@@ -30,6 +31,7 @@ impl FromData for AxisValueMap {
 
 /// A list of segment maps.
 ///
+/// Each item contains `(from, to)` coordinate pairs for a single axis.
 /// Can be empty.
 ///
 /// The internal data layout is not designed for random access,
@@ -87,11 +89,16 @@ impl<'a> Iterator for SegmentMapsIter<'a> {
 
 /// An [Axis Variations Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/avar).
-#[derive(Clone, Copy, Debug)]
+///
+/// Both version 1 and 2 are supported.
+#[derive(Clone, Copy)]
 pub struct Table<'a> {
     /// The segment maps array — one segment map for each axis
     /// in the order of axes specified in the `fvar` table.
     pub segment_maps: SegmentMaps<'a>,
+    data: &'a [u8],
+    axis_index_map_offset: Option<Offset32>,
+    variation_store: Option<ItemVariationStore<'a>>,
 }
 
 impl<'a> Table<'a> {
@@ -100,34 +107,95 @@ impl<'a> Table<'a> {
         let mut s = Stream::new(data);
 
         let version = s.read::<u32>()?;
-        if version != 0x00010000 {
+        if version != 0x00010000 && version != 0x00020000 {
             return None;
         }
 
         s.skip::<u16>(); // reserved
+        let segment_maps = SegmentMaps {
+            // TODO: check that `axisCount` is the same as in `fvar`?
+            count: s.read::<u16>()?,
+            data: s.tail()?,
+        };
+
+        let mut axis_index_map_offset = None;
+        let mut variation_store = None;
+        if version == 0x00020000 {
+            // Skip segment maps.
+            for _ in 0..segment_maps.count {
+                let count = s.read::<u16>()?;
+                s.advance_checked(usize::from(count) * AxisValueMap::SIZE)?;
+            }
+
+            axis_index_map_offset = s.read::<Option<Offset32>>()?;
+            if let Some(offset) = s.read::<Option<Offset32>>()? {
+                let s = Stream::new_at(data, offset.to_usize())?;
+                variation_store = Some(ItemVariationStore::parse(s)?);
+            }
+        }
+
         Some(Self {
-            segment_maps: SegmentMaps {
-                // TODO: check that `axisCount` is the same as in `fvar`?
-                count: s.read::<u16>()?,
-                data: s.tail()?,
-            },
+            segment_maps,
+            data,
+            axis_index_map_offset,
+            variation_store,
         })
     }
 
     /// Maps coordinates.
+    ///
+    /// Returns `None` when the number of coordinates doesn't match the number of segment maps.
     pub fn map_coordinates(&self, coordinates: &mut [NormalizedCoordinate]) -> Option<()> {
         if usize::from(self.segment_maps.count) != coordinates.len() {
             return None;
         }
 
-        for (map, coord) in self.segment_maps.into_iter().zip(coordinates) {
+        for (map, coord) in self.segment_maps.into_iter().zip(coordinates.iter_mut()) {
             *coord = NormalizedCoordinate::from(map_value(&map, coord.0)?);
         }
 
+        if let Some(variation_store) = self.variation_store {
+            // Deltas must be calculated using coordinates mapped by segment maps,
+            // therefore we have to make a copy first.
+            let mut mapped = [NormalizedCoordinate::default(); crate::MAX_VAR_COORDS];
+            let mapped = mapped.get_mut(..coordinates.len())?;
+            mapped.copy_from_slice(coordinates);
+
+            for (i, coord) in coordinates.iter_mut().enumerate() {
+                let index = u32::try_from(i).ok()?;
+                let (outer, inner) = match self.axis_index_map_offset {
+                    Some(offset) => {
+                        DeltaSetIndexMap::new(self.data.get(offset.to_usize()..)?).map(index)?
+                    }
+                    // Axis indices are used as inner indices by default.
+                    None => (0, u16::try_from(index).ok()?),
+                };
+
+                let delta = variation_store
+                    .parse_delta(outer, inner, mapped)
+                    .unwrap_or(0.0);
+                // We can't use `round()` in `no_std`, so this is the next best thing.
+                let delta = if delta < 0.0 {
+                    delta - 0.5
+                } else {
+                    delta + 0.5
+                };
+                let delta = i16::try_num_from(delta)?;
+                // `NormalizedCoordinate` will clamp the value.
+                *coord = NormalizedCoordinate::from(coord.get().saturating_add(delta));
+            }
+        }
+
         Some(())
     }
 }
 
+impl core::fmt::Debug for Table<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Table {{ ... }}")
+    }
+}
+
 fn map_value(map: &LazyArray16<AxisValueMap>, value: i16) -> Option<i16> {
     // This code is based on harfbuzz implementation.
 
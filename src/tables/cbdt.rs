@@ -1,7 +1,11 @@
 //! A [Color Bitmap Data Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/cbdt) implementation.
+//!
+//! Also used for the [Embedded Bitmap Data Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/ebdt),
+//! since `CBDT` is a superset of it.
 
-use super::cblc::{self, BitmapFormat};
+use super::cblc::{self, parse_big_metrics, parse_small_metrics, BitmapFormat, Metrics};
 use crate::parser::{NumFrom, Stream};
 use crate::{GlyphId, RasterGlyphImage, RasterImageFormat};
 
@@ -15,15 +19,36 @@ pub struct Table<'a> {
 
 impl<'a> Table<'a> {
     /// Parses a table from raw data.
+    ///
+    /// Can be used for both `CBLC`+`CBDT` and `EBLC`+`EBDT` pairs.
     pub fn parse(locations: cblc::Table<'a>, data: &'a [u8]) -> Option<Self> {
         Some(Self { locations, data })
     }
 
+    // Checks that any strike contains the glyph.
+    #[inline]
+    pub(crate) fn contains(&self, glyph_id: GlyphId) -> bool {
+        self.locations.contains(glyph_id)
+    }
+
     /// Returns a raster image for the glyph.
+    ///
+    /// Only PNG and monochrome bitmaps are supported.
     pub fn get(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<RasterGlyphImage<'a>> {
         let location = self.locations.get(glyph_id, pixels_per_em)?;
         let mut s = Stream::new_at(self.data, location.offset)?;
         match location.format {
+            BitmapFormat::Format1 | BitmapFormat::Format2 => {
+                let metrics = parse_small_metrics(&mut s)?;
+                let is_packed = location.format == BitmapFormat::Format2;
+                parse_mono_bitmap(&mut s, metrics, is_packed, location)
+            }
+            BitmapFormat::Format5 => parse_mono_bitmap(&mut s, location.metrics, true, location),
+            BitmapFormat::Format6 | BitmapFormat::Format7 => {
+                let metrics = parse_big_metrics(&mut s)?;
+                let is_packed = location.format == BitmapFormat::Format7;
+                parse_mono_bitmap(&mut s, metrics, is_packed, location)
+            }
             BitmapFormat::Format17 => {
                 let height = s.read::<u8>()?;
                 let width = s.read::<u8>()?;
@@ -88,3 +113,44 @@ impl core::fmt::Debug for Table<'_> {
         write!(f, "Table {{ ... }}")
     }
 }
+
+fn parse_mono_bitmap<'a>(
+    s: &mut Stream<'a>,
+    metrics: Metrics,
+    is_packed: bool,
+    location: cblc::Location,
+) -> Option<RasterGlyphImage<'a>> {
+    // Grayscale bitmaps are not supported.
+    if location.bit_depth != 1 {
+        return None;
+    }
+
+    let width = usize::from(metrics.width);
+    let height = usize::from(metrics.height);
+    let data_len = if is_packed {
+        bits_to_bytes(width * height)
+    } else {
+        bits_to_bytes(width) * height
+    };
+
+    let data = s.read_bytes(data_len)?;
+    Some(RasterGlyphImage {
+        x: i16::from(metrics.x),
+        // `y` in EBDT is a bottom bound, not top one.
+        y: i16::from(metrics.y) - i16::from(metrics.height),
+        width: u16::from(metrics.width),
+        height: u16::from(metrics.height),
+        pixels_per_em: location.ppem,
+        format: if is_packed {
+            RasterImageFormat::BitmapMonoPacked
+        } else {
+            RasterImageFormat::BitmapMono
+        },
+        data,
+    })
+}
+
+#[inline]
+fn bits_to_bytes(bits: usize) -> usize {
+    bits / 8 + usize::from(bits & 7 != 0)
+}
@@ -1,11 +1,20 @@
 //! A [Color Bitmap Location Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/cblc) implementation.
+//!
+//! Also used for the [Embedded Bitmap Location Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/eblc),
+//! since their structure is the same.
 
 use crate::parser::{FromData, NumFrom, Offset, Offset16, Offset32, Stream};
 use crate::GlyphId;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub(crate) enum BitmapFormat {
+    Format1,
+    Format2,
+    Format5,
+    Format6,
+    Format7,
     Format17,
     Format18,
     Format19,
@@ -25,6 +34,7 @@ pub(crate) struct Location {
     pub offset: usize,
     pub metrics: Metrics,
     pub ppem: u16,
+    pub bit_depth: u8,
 }
 
 #[derive(Clone, Copy)]
@@ -32,52 +42,68 @@ struct BitmapSizeTable {
     subtable_array_offset: Offset32,
     number_of_subtables: u32,
     ppem: u16,
+    bit_depth: u8,
     // Many fields are omitted.
 }
 
+// Parses a `BitmapSize` record at index along with the glyph range it covers.
+fn parse_bitmap_size_table(
+    data: &[u8],
+    index: u32,
+) -> Option<(BitmapSizeTable, core::ops::RangeInclusive<GlyphId>)> {
+    const SIZE: usize = 48;
+
+    // Records start right after the version and the number of records.
+    let offset = usize::num_from(index).checked_mul(SIZE)?.checked_add(8)?;
+    let mut s = Stream::new_at(data, offset)?;
+    let subtable_array_offset = s.read::<Offset32>()?;
+    s.skip::<u32>(); // index_tables_size
+    let number_of_subtables = s.read::<u32>()?;
+    s.advance(28); // Jump to `start_glyph_index`.
+    let start_glyph_id = s.read::<GlyphId>()?;
+    let end_glyph_id = s.read::<GlyphId>()?;
+    let ppem = u16::from(s.read::<u8>()?);
+    s.skip::<u8>(); // ppem_y
+    let bit_depth = s.read::<u8>()?;
+
+    let table = BitmapSizeTable {
+        subtable_array_offset,
+        number_of_subtables,
+        ppem,
+        bit_depth,
+    };
+
+    Some((table, start_glyph_id..=end_glyph_id))
+}
+
 fn select_bitmap_size_table(
+    data: &[u8],
     glyph_id: GlyphId,
     pixels_per_em: u16,
-    mut s: Stream,
 ) -> Option<BitmapSizeTable> {
-    let subtable_count = s.read::<u32>()?;
-    let orig_s = s.clone();
+    let subtable_count = Stream::read_at::<u32>(data, 4)?;
 
-    let mut idx = None;
+    let mut best = None;
     let mut max_ppem = 0;
     for i in 0..subtable_count {
-        // Check that the current subtable contains a provided glyph id.
-        s.advance(40); // Jump to `start_glyph_index`.
-        let start_glyph_id = s.read::<GlyphId>()?;
-        let end_glyph_id = s.read::<GlyphId>()?;
-        let ppem = u16::from(s.read::<u8>()?);
+        let (table, range) = parse_bitmap_size_table(data, i)?;
 
-        if !(start_glyph_id..=end_glyph_id).contains(&glyph_id) {
-            s.advance(4); // Jump to the end of the subtable.
+        // Check that the current subtable contains a provided glyph id.
+        if !range.contains(&glyph_id) {
             continue;
         }
 
         // Select a best matching subtable based on `pixels_per_em`.
+        let ppem = table.ppem;
         if (pixels_per_em <= ppem && ppem < max_ppem)
             || (pixels_per_em > max_ppem && ppem > max_ppem)
         {
-            idx = Some(usize::num_from(i));
+            best = Some(table);
             max_ppem = ppem;
         }
     }
 
-    let mut s = orig_s;
-    s.advance(idx? * 48); // 48 is BitmapSize Table size
-
-    let subtable_array_offset = s.read::<Offset32>()?;
-    s.skip::<u32>(); // index_tables_size
-    let number_of_subtables = s.read::<u32>()?;
-
-    Some(BitmapSizeTable {
-        subtable_array_offset,
-        number_of_subtables,
-        ppem: max_ppem,
-    })
+    best
 }
 
 #[derive(Clone, Copy)]
@@ -144,16 +170,30 @@ impl<'a> Table<'a> {
     }
 
     pub(crate) fn get(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<Location> {
-        let mut s = Stream::new(self.data);
-
         // The CBLC table version is a bit tricky, so we are ignoring it for now.
         // The CBLC table is based on EBLC table, which was based on the `bloc` table.
         // And before the CBLC table specification was finished, some fonts,
         // notably Noto Emoji, have used version 2.0, but the final spec allows only 3.0.
         // So there are perfectly valid fonts in the wild, which have an invalid version.
-        s.skip::<u32>(); // version
+        let size_table = select_bitmap_size_table(self.data, glyph_id, pixels_per_em)?;
+        self.locate(glyph_id, size_table)
+    }
 
-        let size_table = select_bitmap_size_table(glyph_id, pixels_per_em, s)?;
+    // Checks that any strike has an image for the glyph.
+    //
+    // Unlike `get`, doesn't stop at the best strike, since its index subtables
+    // may not cover the glyph even when its glyph range does.
+    pub(crate) fn contains(&self, glyph_id: GlyphId) -> bool {
+        let subtable_count = Stream::read_at::<u32>(self.data, 4).unwrap_or(0);
+        (0..subtable_count).any(|i| {
+            parse_bitmap_size_table(self.data, i)
+                .filter(|(_, range)| range.contains(&glyph_id))
+                .and_then(|(table, _)| self.locate(glyph_id, table))
+                .is_some()
+        })
+    }
+
+    fn locate(&self, glyph_id: GlyphId, size_table: BitmapSizeTable) -> Option<Location> {
         let info = select_index_subtable(self.data, size_table, glyph_id)?;
 
         let mut s = Stream::new_at(self.data, info.offset)?;
@@ -162,6 +202,11 @@ impl<'a> Table<'a> {
         let mut image_offset = s.read::<Offset32>()?.to_usize();
 
         let image_format = match image_format {
+            1 => BitmapFormat::Format1,
+            2 => BitmapFormat::Format2,
+            5 => BitmapFormat::Format5,
+            6 => BitmapFormat::Format6,
+            7 => BitmapFormat::Format7,
             17 => BitmapFormat::Format17,
             18 => BitmapFormat::Format18,
             19 => BitmapFormat::Format19,
@@ -171,7 +216,7 @@ impl<'a> Table<'a> {
         // TODO: I wasn't able to find fonts with index 4 and 5, so they are untested.
 
         let glyph_diff = glyph_id.0.checked_sub(info.start_glyph_id.0)?;
-        let metrics = Metrics::default();
+        let mut metrics = Metrics::default();
         match index_format {
             1 => {
                 s.advance(usize::from(glyph_diff) * Offset32::SIZE);
@@ -180,6 +225,7 @@ impl<'a> Table<'a> {
             }
             2 => {
                 let image_size = s.read::<u32>()?;
+                metrics = parse_big_metrics(&mut s)?;
                 image_offset += usize::from(glyph_diff).checked_mul(usize::num_from(image_size))?;
             }
             3 => {
@@ -196,7 +242,7 @@ impl<'a> Table<'a> {
             }
             5 => {
                 let image_size = s.read::<u32>()?;
-                s.advance(8); // big metrics
+                metrics = parse_big_metrics(&mut s)?;
                 let num_glyphs = s.read::<u32>()?;
                 let glyphs = s.read_array32::<GlyphId>(num_glyphs)?;
                 let (index, _) = glyphs.binary_search(&glyph_id)?;
@@ -212,10 +258,34 @@ impl<'a> Table<'a> {
             offset: image_offset,
             metrics,
             ppem: size_table.ppem,
+            bit_depth: size_table.bit_depth,
         })
     }
 }
 
+pub(crate) fn parse_small_metrics(s: &mut Stream) -> Option<Metrics> {
+    let height = s.read::<u8>()?;
+    let width = s.read::<u8>()?;
+    let x = s.read::<i8>()?;
+    let y = s.read::<i8>()?;
+    s.skip::<u8>(); // advance
+    Some(Metrics {
+        x,
+        y,
+        width,
+        height,
+    })
+}
+
+// Only the horizontal part of `BigGlyphMetrics` is used.
+pub(crate) fn parse_big_metrics(s: &mut Stream) -> Option<Metrics> {
+    let metrics = parse_small_metrics(s)?;
+    s.skip::<i8>(); // ver_bearing_x
+    s.skip::<i8>(); // ver_bearing_y
+    s.skip::<u8>(); // ver_advance
+    Some(metrics)
+}
+
 impl core::fmt::Debug for Table<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "Table {{ ... }}")
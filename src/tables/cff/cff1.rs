@@ -11,6 +11,8 @@ use core::num::NonZeroU16;
 use core::ops::Range;
 
 use super::argstack::ArgumentsStack;
+#[cfg(feature = "glyph-names")]
+use super::charset::CharsetIter;
 use super::charset::{parse_charset, Charset};
 use super::charstring::CharStringParser;
 use super::dict::DictionaryParser;
@@ -20,7 +22,7 @@ use super::index::{parse_index, skip_index, Index};
 use super::std_names::STANDARD_NAMES;
 use super::{calc_subroutine_bias, conv_subroutine_index, Builder, CFFError, IsEven, StringId};
 use crate::parser::{LazyArray16, NumFrom, Stream, TryNumFrom};
-use crate::{BBox, Fixed, GlyphId, OutlineBuilder, Rect};
+use crate::{Fixed, GlyphId, NeverStop, OutlineBuilder, Rect, StoppableOutlineBuilder};
 
 // Limits according to the Adobe Technical Note #5176, chapter 4 DICT Data.
 const MAX_OPERANDS_LEN: usize = 48;
@@ -71,6 +73,7 @@ mod top_dict_operator {
     pub const ENCODING_OFFSET: u16 = 16;
     pub const CHAR_STRINGS_OFFSET: u16 = 17;
     pub const PRIVATE_DICT_SIZE_AND_OFFSET: u16 = 18;
+    pub const CHARSTRING_TYPE: u16 = 1206;
     pub const FONT_MATRIX: u16 = 1207;
     pub const ROS: u16 = 1230;
     pub const FD_ARRAY: u16 = 1236;
@@ -150,6 +153,7 @@ struct TopDict {
     charset_offset: Option<usize>,
     encoding_offset: Option<usize>,
     char_strings_offset: usize,
+    charstring_type: Option<u8>,
     private_dict_range: Option<Range<usize>>,
     matrix: Matrix,
     has_ros: bool,
@@ -181,6 +185,11 @@ fn parse_top_dict(s: &mut Stream) -> Option<TopDict> {
             top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET => {
                 top_dict.private_dict_range = dict_parser.parse_range();
             }
+            top_dict_operator::CHARSTRING_TYPE => {
+                top_dict.charstring_type = dict_parser
+                    .parse_number()
+                    .and_then(|n| u8::try_from(n as i32).ok());
+            }
             top_dict_operator::FONT_MATRIX => {
                 dict_parser.parse_operands()?;
                 let operands = dict_parser.operands();
@@ -356,7 +365,7 @@ fn parse_char_string(
     data: &[u8],
     metadata: &Table,
     glyph_id: GlyphId,
-    builder: &mut dyn OutlineBuilder,
+    builder: &mut dyn StoppableOutlineBuilder,
 ) -> Result<Rect, CFFError> {
     let local_subrs = match metadata.kind {
         FontKind::SID(ref sid) => Some(sid.local_subrs),
@@ -373,10 +382,7 @@ fn parse_char_string(
         local_subrs,
     };
 
-    let mut inner_builder = Builder {
-        builder,
-        bbox: BBox::new(),
-    };
+    let mut inner_builder = Builder::new(builder);
 
     let stack = ArgumentsStack {
         data: &mut [0.0; MAX_ARGUMENTS_STACK_LEN], // 192B
@@ -393,7 +399,8 @@ fn parse_char_string(
     };
     _parse_char_string(&mut ctx, data, 0, &mut parser)?;
 
-    if !ctx.has_endchar {
+    // A stopped outline is incomplete by design.
+    if !ctx.has_endchar && !parser.builder.stopped {
         return Err(CFFError::MissingEndChar);
     }
 
@@ -415,6 +422,10 @@ fn _parse_char_string(
 ) -> Result<(), CFFError> {
     let mut s = Stream::new(char_string);
     while !s.at_end() {
+        if p.builder.stopped {
+            return Ok(());
+        }
+
         let op = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
         match op {
             0 | 2 | 9 | 13 | 15 | 16 | 17 => {
@@ -903,6 +914,7 @@ pub struct Table<'a> {
     charset: Charset<'a>,
     number_of_glyphs: NonZeroU16,
     matrix: Matrix,
+    charstring_type: u8,
     char_strings: Index<'a>,
     kind: FontKind<'a>,
 }
@@ -965,6 +977,8 @@ impl<'a> Table<'a> {
         };
 
         let matrix = top_dict.matrix;
+        // Type 2 is the default.
+        let charstring_type = top_dict.charstring_type.unwrap_or(2);
 
         let kind = if top_dict.has_ros {
             parse_cid_metadata(data, top_dict, number_of_glyphs.get())?
@@ -987,6 +1001,7 @@ impl<'a> Table<'a> {
             charset,
             number_of_glyphs,
             matrix,
+            charstring_type,
             char_strings,
             kind,
         })
@@ -1006,12 +1021,60 @@ impl<'a> Table<'a> {
         self.matrix
     }
 
+    /// Returns the charstring type.
+    ///
+    /// 2 by default. Only Type 2 charstrings are supported.
+    #[inline]
+    pub fn charstring_type(&self) -> u8 {
+        self.charstring_type
+    }
+
+    /// Checks that the font is CID-keyed.
+    #[inline]
+    pub fn is_cid_keyed(&self) -> bool {
+        matches!(self.kind, FontKind::CID(_))
+    }
+
+    /// Returns a Font DICT index for a glyph.
+    ///
+    /// Resolved via FDSelect. Returns `None` when the font is not CID-keyed.
+    pub fn glyph_fd_index(&self, glyph_id: GlyphId) -> Option<u8> {
+        match self.kind {
+            FontKind::SID(_) => None,
+            FontKind::CID(ref cid) => cid.fd_select.font_dict_index(glyph_id),
+        }
+    }
+
+    /// Returns a CID for a glyph.
+    ///
+    /// Resolved via charset. Returns `None` when the font is not CID-keyed.
+    pub fn glyph_cid(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self.kind {
+            FontKind::SID(_) => None,
+            FontKind::CID(_) => self.charset.gid_to_sid(glyph_id).map(|cid| cid.0),
+        }
+    }
+
     /// Outlines a glyph.
+    ///
+    /// Returns [`CFFError::UnsupportedCharstringType`] when charstrings are not Type 2.
     pub fn outline(
         &self,
         glyph_id: GlyphId,
         builder: &mut dyn OutlineBuilder,
     ) -> Result<Rect, CFFError> {
+        self.outline_stoppable(glyph_id, &mut NeverStop(builder))
+    }
+
+    pub(crate) fn outline_stoppable(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn StoppableOutlineBuilder,
+    ) -> Result<Rect, CFFError> {
+        if self.charstring_type != 2 {
+            return Err(CFFError::UnsupportedCharstringType);
+        }
+
         let data = self
             .char_strings
             .get(u32::from(glyph_id.0))
@@ -1084,19 +1147,43 @@ impl<'a> Table<'a> {
         match self.kind {
             FontKind::SID(_) => {
                 let sid = self.charset.gid_to_sid(glyph_id)?;
-                let sid = usize::from(sid.0);
-                match STANDARD_NAMES.get(sid) {
-                    Some(name) => Some(name),
-                    None => {
-                        let idx = u32::try_from(sid - STANDARD_NAMES.len()).ok()?;
-                        let name = self.strings.get(idx)?;
-                        core::str::from_utf8(name).ok()
-                    }
-                }
+                sid_to_name(self.strings, sid)
             }
             FontKind::CID(_) => None,
         }
     }
+
+    /// Returns an iterator over glyph names in glyph ID order.
+    ///
+    /// Faster than calling [`glyph_name`](Self::glyph_name) for each glyph.
+    ///
+    /// CID-keyed fonts have no glyph names, therefore `None` is yielded for each glyph.
+    /// Use [`glyph_cid`](Self::glyph_cid) instead.
+    #[cfg(feature = "glyph-names")]
+    pub fn glyph_names(&self) -> impl Iterator<Item = Option<&'a str>> + 'a {
+        let is_cid = self.is_cid_keyed();
+        let strings = self.strings;
+        CharsetIter::new(self.charset, self.number_of_glyphs.get()).map(move |sid| {
+            if is_cid {
+                None
+            } else {
+                sid_to_name(strings, sid?)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "glyph-names")]
+fn sid_to_name<'a>(strings: Index<'a>, sid: StringId) -> Option<&'a str> {
+    let sid = usize::from(sid.0);
+    match STANDARD_NAMES.get(sid) {
+        Some(name) => Some(name),
+        None => {
+            let idx = u32::try_from(sid - STANDARD_NAMES.len()).ok()?;
+            let name = strings.get(idx)?;
+            core::str::from_utf8(name).ok()
+        }
+    }
 }
 
 impl core::fmt::Debug for Table<'_> {
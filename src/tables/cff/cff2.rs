@@ -13,7 +13,9 @@ use super::index::{parse_index, Index};
 use super::{calc_subroutine_bias, conv_subroutine_index, Builder, CFFError};
 use crate::parser::{NumFrom, Stream, TryNumFrom};
 use crate::var_store::*;
-use crate::{BBox, GlyphId, NormalizedCoordinate, OutlineBuilder, Rect};
+use crate::{
+    GlyphId, NeverStop, NormalizedCoordinate, OutlineBuilder, Rect, StoppableOutlineBuilder,
+};
 
 // https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#7-top-dict-data
 // 'Operators in DICT may be preceded by up to a maximum of 513 operands.'
@@ -233,7 +235,7 @@ fn parse_char_string(
     data: &[u8],
     metadata: &Table,
     coordinates: &[NormalizedCoordinate],
-    builder: &mut dyn OutlineBuilder,
+    builder: &mut dyn StoppableOutlineBuilder,
 ) -> Result<Rect, CFFError> {
     let mut ctx = CharStringParserContext {
         metadata,
@@ -247,10 +249,7 @@ fn parse_char_string(
     // Load scalars at default index.
     ctx.update_scalars(0)?;
 
-    let mut inner_builder = Builder {
-        builder,
-        bbox: BBox::new(),
-    };
+    let mut inner_builder = Builder::new(builder);
 
     let stack = ArgumentsStack {
         data: &mut [0.0; MAX_ARGUMENTS_STACK_LEN], // 2052B
@@ -286,6 +285,10 @@ fn _parse_char_string(
 ) -> Result<(), CFFError> {
     let mut s = Stream::new(char_string);
     while !s.at_end() {
+        if p.builder.stopped {
+            return Ok(());
+        }
+
         let op = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
         match op {
             0 | 2 | 9 | 11 | 13 | 14 | 17 => {
@@ -547,6 +550,15 @@ impl<'a> Table<'a> {
         coordinates: &[NormalizedCoordinate],
         glyph_id: GlyphId,
         builder: &mut dyn OutlineBuilder,
+    ) -> Result<Rect, CFFError> {
+        self.outline_stoppable(coordinates, glyph_id, &mut NeverStop(builder))
+    }
+
+    pub(crate) fn outline_stoppable(
+        &self,
+        coordinates: &[NormalizedCoordinate],
+        glyph_id: GlyphId,
+        builder: &mut dyn StoppableOutlineBuilder,
     ) -> Result<Rect, CFFError> {
         let data = self
             .char_strings
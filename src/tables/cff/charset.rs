@@ -4,7 +4,6 @@ use crate::GlyphId;
 
 /// The Expert Encoding conversion as defined in the Adobe Technical Note #5176 Appendix C.
 #[rustfmt::skip]
-#[cfg(feature = "glyph-names")]
 const EXPERT_ENCODING: &[u16] = &[
       0,    1,  229,  230,  231,  232,  233,  234,  235,  236,  237,  238,   13,   14,   15,   99,
     239,  240,  241,  242,  243,  244,  245,  246,  247,  248,   27,   28,  249,  250,  251,  252,
@@ -21,7 +20,6 @@ const EXPERT_ENCODING: &[u16] = &[
 
 /// The Expert Subset Encoding conversion as defined in the Adobe Technical Note #5176 Appendix C.
 #[rustfmt::skip]
-#[cfg(feature = "glyph-names")]
 const EXPERT_SUBSET_ENCODING: &[u16] = &[
       0,    1,  231,  232,  235,  236,  237,  238,   13,   14,   15,   99,  239,  240,  241,  242,
     243,  244,  245,  246,  247,  248,   27,   28,  249,  250,  251,  253,  254,  255,  256,  257,
@@ -126,7 +124,6 @@ impl Charset<'_> {
         }
     }
 
-    #[cfg(feature = "glyph-names")]
     pub fn gid_to_sid(&self, gid: GlyphId) -> Option<StringId> {
         match self {
             Charset::ISOAdobe => {
@@ -189,6 +186,78 @@ impl Charset<'_> {
     }
 }
 
+/// An iterator over string IDs in glyph ID order.
+///
+/// Unlike [`Charset::gid_to_sid`], ranges are walked only once.
+#[cfg(feature = "glyph-names")]
+#[derive(Clone, Copy)]
+pub(crate) struct CharsetIter<'a> {
+    charset: Charset<'a>,
+    number_of_glyphs: u16,
+    glyph_id: u16,
+    range_index: u16,
+    offset: u16,
+}
+
+#[cfg(feature = "glyph-names")]
+impl<'a> CharsetIter<'a> {
+    pub fn new(charset: Charset<'a>, number_of_glyphs: u16) -> Self {
+        CharsetIter {
+            charset,
+            number_of_glyphs,
+            glyph_id: 0,
+            range_index: 0,
+            offset: 0,
+        }
+    }
+
+    fn range(&self, index: u16) -> Option<(StringId, u16)> {
+        match self.charset {
+            Charset::Format1(array) => array.get(index).map(|r| (r.first, u16::from(r.left))),
+            Charset::Format2(array) => array.get(index).map(|r| (r.first, r.left)),
+            _ => None,
+        }
+    }
+
+    fn next_in_ranges(&mut self) -> Option<StringId> {
+        loop {
+            let (first, left) = self.range(self.range_index)?;
+            if self.offset <= left {
+                let sid = first.0.checked_add(self.offset)?;
+                self.offset += 1;
+                return Some(StringId(sid));
+            }
+
+            self.range_index += 1;
+            self.offset = 0;
+        }
+    }
+}
+
+#[cfg(feature = "glyph-names")]
+impl Iterator for CharsetIter<'_> {
+    type Item = Option<StringId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.glyph_id >= self.number_of_glyphs {
+            return None;
+        }
+
+        let glyph_id = self.glyph_id;
+        self.glyph_id += 1;
+
+        if glyph_id == 0 {
+            // `.notdef` is omitted in all formats.
+            return Some(Some(StringId(0)));
+        }
+
+        match self.charset {
+            Charset::Format1(_) | Charset::Format2(_) => Some(self.next_in_ranges()),
+            _ => Some(self.charset.gid_to_sid(GlyphId(glyph_id))),
+        }
+    }
+}
+
 pub(crate) fn parse_charset<'a>(number_of_glyphs: u16, s: &mut Stream<'a>) -> Option<Charset<'a>> {
     if number_of_glyphs < 2 {
         return None;
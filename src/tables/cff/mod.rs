@@ -13,7 +13,7 @@ mod std_names;
 use core::convert::TryFrom;
 
 use crate::parser::{FromData, TryNumFrom};
-use crate::{BBox, OutlineBuilder};
+use crate::{BBox, ContourArea, ControlFlow, StoppableOutlineBuilder};
 
 /// A list of errors that can occur during a CFF glyph outlining.
 #[allow(missing_docs)]
@@ -34,6 +34,7 @@ pub enum CFFError {
     InvalidSubroutineIndex,
     NoLocalSubroutines,
     InvalidSeacCode,
+    UnsupportedCharstringType,
     #[cfg(feature = "variable-fonts")]
     InvalidItemVariationDataIndex,
     #[cfg(feature = "variable-fonts")]
@@ -42,35 +43,82 @@ pub enum CFFError {
     BlendRegionsLimitReached,
 }
 
+impl From<CFFError> for crate::OutlineError {
+    #[inline]
+    fn from(e: CFFError) -> Self {
+        match e {
+            CFFError::NoGlyph | CFFError::ZeroBBox => crate::OutlineError::Empty,
+            CFFError::NestingLimitReached => crate::OutlineError::DepthLimitExceeded,
+            _ => crate::OutlineError::Malformed,
+        }
+    }
+}
+
 pub(crate) struct Builder<'a> {
-    builder: &'a mut dyn OutlineBuilder,
+    builder: &'a mut dyn StoppableOutlineBuilder,
     bbox: BBox,
+    area: ContourArea,
+    // Set when the builder has asked to stop. No more segments will be emitted.
+    stopped: bool,
 }
 
 impl<'a> Builder<'a> {
+    #[inline]
+    fn new(builder: &'a mut dyn StoppableOutlineBuilder) -> Self {
+        Builder {
+            builder,
+            bbox: BBox::new(),
+            area: ContourArea::default(),
+            stopped: false,
+        }
+    }
+
     #[inline]
     fn move_to(&mut self, x: f32, y: f32) {
+        if self.stopped {
+            return;
+        }
+
         self.bbox.extend_by(x, y);
-        self.builder.move_to(x, y);
+        self.area.move_to(x, y);
+        self.stopped = self.builder.move_to(x, y) == ControlFlow::Break;
     }
 
     #[inline]
     fn line_to(&mut self, x: f32, y: f32) {
+        if self.stopped {
+            return;
+        }
+
         self.bbox.extend_by(x, y);
-        self.builder.line_to(x, y);
+        self.area.line_to(x, y);
+        self.stopped = self.builder.line_to(x, y) == ControlFlow::Break;
     }
 
     #[inline]
     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        if self.stopped {
+            return;
+        }
+
         self.bbox.extend_by(x1, y1);
         self.bbox.extend_by(x2, y2);
         self.bbox.extend_by(x, y);
-        self.builder.curve_to(x1, y1, x2, y2, x, y);
+        self.area.line_to(x1, y1);
+        self.area.line_to(x2, y2);
+        self.area.line_to(x, y);
+        self.stopped = self.builder.curve_to(x1, y1, x2, y2, x, y) == ControlFlow::Break;
     }
 
     #[inline]
     fn close(&mut self) {
-        self.builder.close();
+        if self.stopped {
+            return;
+        }
+
+        let is_clockwise = self.area.finish();
+        self.builder.contour_winding(is_clockwise);
+        self.stopped = self.builder.close() == ControlFlow::Break;
     }
 }
 
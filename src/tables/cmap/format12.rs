@@ -65,6 +65,12 @@ impl<'a> Subtable12<'a> {
         u16::try_from(id).ok().map(GlyphId)
     }
 
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn groups(&self) -> LazyArray32<'a, SequentialMapGroup> {
+        self.groups
+    }
+
     /// Calls `f` for each codepoint defined in this table.
     pub fn codepoints(&self, mut f: impl FnMut(u32)) {
         for group in self.groups {
@@ -22,10 +22,13 @@ impl FromData for VariationSelectorRecord {
     }
 }
 
-#[derive(Clone, Copy)]
-struct UVSMappingRecord {
-    unicode_value: u32,
-    glyph_id: GlyphId,
+/// A non-default UVS mapping.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UVSMappingRecord {
+    /// A base Unicode value.
+    pub unicode_value: u32,
+    /// A glyph ID of the variation sequence.
+    pub glyph_id: GlyphId,
 }
 
 impl FromData for UVSMappingRecord {
@@ -41,14 +44,20 @@ impl FromData for UVSMappingRecord {
     }
 }
 
-#[derive(Clone, Copy)]
-struct UnicodeRangeRecord {
-    start_unicode_value: u32,
-    additional_count: u8,
+/// A default UVS range.
+///
+/// Variation sequences with these base values use the default glyph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnicodeRangeRecord {
+    /// The first Unicode value in the range.
+    pub start_unicode_value: u32,
+    /// The number of additional values in the range.
+    pub additional_count: u8,
 }
 
 impl UnicodeRangeRecord {
-    fn contains(&self, c: u32) -> bool {
+    /// Checks that the range contains a code point.
+    pub fn contains(&self, c: u32) -> bool {
         // Never overflows, since `start_unicode_value` is actually u24.
         let end = self.start_unicode_value + u32::from(self.additional_count);
         (self.start_unicode_value..=end).contains(&c)
@@ -102,15 +111,7 @@ impl<'a> Subtable14<'a> {
 
     /// Returns a glyph index for a code point.
     pub fn glyph_index(&self, code_point: u32, variation: u32) -> Option<GlyphVariationResult> {
-        let (_, record) = self
-            .records
-            .binary_search_by(|v| v.var_selector.cmp(&variation))?;
-
-        if let Some(offset) = record.default_uvs_offset {
-            let data = self.data.get(offset.to_usize()..)?;
-            let mut s = Stream::new(data);
-            let count = s.read::<u32>()?;
-            let ranges = s.read_array32::<UnicodeRangeRecord>(count)?;
+        if let Some(ranges) = self.default_uvs(variation) {
             for range in ranges {
                 if range.contains(code_point) {
                     return Some(GlyphVariationResult::UseDefault);
@@ -118,17 +119,42 @@ impl<'a> Subtable14<'a> {
             }
         }
 
-        if let Some(offset) = record.non_default_uvs_offset {
-            let data = self.data.get(offset.to_usize()..)?;
-            let mut s = Stream::new(data);
-            let count = s.read::<u32>()?;
-            let uvs_mappings = s.read_array32::<UVSMappingRecord>(count)?;
-            let (_, mapping) =
-                uvs_mappings.binary_search_by(|v| v.unicode_value.cmp(&code_point))?;
-            return Some(GlyphVariationResult::Found(mapping.glyph_id));
-        }
+        let uvs_mappings = self.non_default_uvs(variation)?;
+        let (_, mapping) = uvs_mappings.binary_search_by(|v| v.unicode_value.cmp(&code_point))?;
+        Some(GlyphVariationResult::Found(mapping.glyph_id))
+    }
+
+    /// Returns an iterator over all variation selectors defined by the subtable.
+    ///
+    /// Selectors are in ascending order.
+    pub fn variation_selectors(&self) -> impl Iterator<Item = u32> + 'a {
+        self.records.into_iter().map(|record| record.var_selector)
+    }
+
+    /// Returns default UVS ranges for a variation selector.
+    ///
+    /// Returns `None` when the selector is not defined or has no default UVS table.
+    pub fn default_uvs(&self, variation: u32) -> Option<LazyArray32<'a, UnicodeRangeRecord>> {
+        let offset = self.record(variation)?.default_uvs_offset?;
+        let mut s = Stream::new_at(self.data, offset.to_usize())?;
+        let count = s.read::<u32>()?;
+        s.read_array32::<UnicodeRangeRecord>(count)
+    }
+
+    /// Returns non-default UVS mappings for a variation selector.
+    ///
+    /// Returns `None` when the selector is not defined or has no non-default UVS table.
+    pub fn non_default_uvs(&self, variation: u32) -> Option<LazyArray32<'a, UVSMappingRecord>> {
+        let offset = self.record(variation)?.non_default_uvs_offset?;
+        let mut s = Stream::new_at(self.data, offset.to_usize())?;
+        let count = s.read::<u32>()?;
+        s.read_array32::<UVSMappingRecord>(count)
+    }
 
-        None
+    fn record(&self, variation: u32) -> Option<VariationSelectorRecord> {
+        self.records
+            .binary_search_by(|v| v.var_selector.cmp(&variation))
+            .map(|(_, record)| record)
     }
 }
 
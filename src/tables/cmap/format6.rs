@@ -36,7 +36,8 @@ impl<'a> Subtable6<'a> {
         // This subtable supports code points only in a u16 range.
         let code_point = u16::try_from(code_point).ok()?;
         let idx = code_point.checked_sub(self.first_code_point)?;
-        self.glyphs.get(idx)
+        // Unmapped code points in range are set to zero.
+        self.glyphs.get(idx).filter(|id| id.0 != 0)
     }
 
     /// Calls `f` for each codepoint defined in this table.
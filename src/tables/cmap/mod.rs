@@ -8,6 +8,11 @@ This module provides a low-level alternative to
 methods.
 */
 
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "std")]
+use crate::parser::NumFrom;
 use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream};
 use crate::{name::PlatformId, GlyphId};
 
@@ -24,7 +29,7 @@ pub use format0::Subtable0;
 pub use format10::Subtable10;
 pub use format12::Subtable12;
 pub use format13::Subtable13;
-pub use format14::{GlyphVariationResult, Subtable14};
+pub use format14::{GlyphVariationResult, Subtable14, UVSMappingRecord, UnicodeRangeRecord};
 pub use format2::Subtable2;
 pub use format4::Subtable4;
 pub use format6::Subtable6;
@@ -84,6 +89,68 @@ impl<'a> Subtable<'a> {
         }
     }
 
+    /// Checks that the current encoding is Macintosh Roman.
+    ///
+    /// Such subtables are usually in the byte encoding (0) or the trimmed table mapping (6)
+    /// format and can be found in old Mac fonts.
+    #[inline]
+    pub fn is_mac_roman(&self) -> bool {
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-encoding-ids-script-manager-codes
+        const MACINTOSH_ROMAN_ENCODING_ID: u16 = 0;
+
+        self.platform_id == PlatformId::Macintosh && self.encoding_id == MACINTOSH_ROMAN_ENCODING_ID
+    }
+
+    /// Checks that the current encoding is Windows Symbol.
+    ///
+    /// Such subtables are used by symbol fonts and usually map characters
+    /// in the `U+F000..U+F0FF` private use range.
+    #[inline]
+    pub fn is_symbol(&self) -> bool {
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-encoding-ids
+        const WINDOWS_SYMBOL_ENCODING_ID: u16 = 0;
+
+        self.platform_id == PlatformId::Windows && self.encoding_id == WINDOWS_SYMBOL_ENCODING_ID
+    }
+
+    /// Returns the subtable format number.
+    #[inline]
+    pub fn format_number(&self) -> u16 {
+        match self.format {
+            Format::ByteEncodingTable(..) => 0,
+            Format::HighByteMappingThroughTable(..) => 2,
+            Format::SegmentMappingToDeltaValues(..) => 4,
+            Format::TrimmedTableMapping(..) => 6,
+            Format::MixedCoverage => 8,
+            Format::TrimmedArray(..) => 10,
+            Format::SegmentedCoverage(..) => 12,
+            Format::ManyToOneRangeMappings(..) => 13,
+            Format::UnicodeVariationSequences(..) => 14,
+        }
+    }
+
+    // Returns a subtable rank for `Table::best_unicode_subtable`. Lower is better.
+    fn unicode_rank(&self) -> Option<u8> {
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-encoding-ids
+        const WINDOWS_UNICODE_BMP_ENCODING_ID: u16 = 1;
+
+        if matches!(
+            self.format,
+            Format::MixedCoverage | Format::UnicodeVariationSequences(..)
+        ) {
+            return None;
+        }
+
+        match self.platform_id {
+            PlatformId::Windows if self.encoding_id == WINDOWS_UNICODE_BMP_ENCODING_ID => Some(1),
+            // Full repertoire subtables.
+            PlatformId::Windows if self.is_unicode() => Some(0),
+            PlatformId::Unicode => Some(2),
+            PlatformId::Windows if self.is_symbol() => Some(3),
+            _ => None,
+        }
+    }
+
     /// Maps a character to a glyph ID.
     ///
     /// This is a low-level method and unlike `Face::glyph_index` it doesn't
@@ -155,6 +222,70 @@ impl<'a> Subtable<'a> {
             Format::UnicodeVariationSequences(_) => {} // unsupported
         };
     }
+
+    /// Calls `f` for all codepoint ranges contained in this subtable.
+    ///
+    /// Unlike [`codepoints`](Self::codepoints), segmented subtables (format 12 and 13)
+    /// are reported per group, so malformed groups cannot produce billions of calls.
+    /// Ranges are inclusive, limited to valid Unicode code points and may overlap.
+    #[cfg(all(feature = "std", feature = "unicode-scripts"))]
+    pub(crate) fn codepoint_ranges<F: FnMut(u32, u32)>(&self, mut f: F) {
+        let groups = match self.format {
+            Format::SegmentedCoverage(ref subtable) => subtable.groups(),
+            Format::ManyToOneRangeMappings(ref subtable) => subtable.groups(),
+            _ => {
+                self.codepoints(|c| {
+                    if c <= MAX_CODE_POINT {
+                        f(c, c)
+                    }
+                });
+                return;
+            }
+        };
+
+        for group in groups {
+            let end = core::cmp::min(group.end_char_code, MAX_CODE_POINT);
+            if group.start_char_code <= end {
+                f(group.start_char_code, end);
+            }
+        }
+    }
+}
+
+// Mac OS Roman to Unicode mapping for the upper half of the encoding.
+// The lower half is the same as ASCII.
+#[rustfmt::skip]
+const MAC_ROMAN: [u16; 128] = [
+    0x00C4, 0x00C5, 0x00C7, 0x00C9, 0x00D1, 0x00D6, 0x00DC, 0x00E1,
+    0x00E0, 0x00E2, 0x00E4, 0x00E3, 0x00E5, 0x00E7, 0x00E9, 0x00E8,
+    0x00EA, 0x00EB, 0x00ED, 0x00EC, 0x00EE, 0x00EF, 0x00F1, 0x00F3,
+    0x00F2, 0x00F4, 0x00F6, 0x00F5, 0x00FA, 0x00F9, 0x00FB, 0x00FC,
+    0x2020, 0x00B0, 0x00A2, 0x00A3, 0x00A7, 0x2022, 0x00B6, 0x00DF,
+    0x00AE, 0x00A9, 0x2122, 0x00B4, 0x00A8, 0x2260, 0x00C6, 0x00D8,
+    0x221E, 0x00B1, 0x2264, 0x2265, 0x00A5, 0x00B5, 0x2202, 0x2211,
+    0x220F, 0x03C0, 0x222B, 0x00AA, 0x00BA, 0x03A9, 0x00E6, 0x00F8,
+    0x00BF, 0x00A1, 0x00AC, 0x221A, 0x0192, 0x2248, 0x2206, 0x00AB,
+    0x00BB, 0x2026, 0x00A0, 0x00C0, 0x00C3, 0x00D5, 0x0152, 0x0153,
+    0x2013, 0x2014, 0x201C, 0x201D, 0x2018, 0x2019, 0x00F7, 0x25CA,
+    0x00FF, 0x0178, 0x2044, 0x20AC, 0x2039, 0x203A, 0xFB01, 0xFB02,
+    0x2021, 0x00B7, 0x201A, 0x201E, 0x2030, 0x00C2, 0x00CA, 0x00C1,
+    0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF, 0x00CC, 0x00D3, 0x00D4,
+    0xF8FF, 0x00D2, 0x00DA, 0x00DB, 0x00D9, 0x0131, 0x02C6, 0x02DC,
+    0x00AF, 0x02D8, 0x02D9, 0x02DA, 0x00B8, 0x02DD, 0x02DB, 0x02C7,
+];
+
+/// Converts a Unicode code point into a Macintosh Roman character code.
+// The last valid Unicode code point.
+#[cfg(feature = "std")]
+const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+pub(crate) fn mac_roman_code(code_point: u32) -> Option<u8> {
+    if code_point < 0x80 {
+        return Some(code_point as u8);
+    }
+
+    let index = MAC_ROMAN.iter().position(|c| u32::from(*c) == code_point)?;
+    Some(0x80 + index as u8)
 }
 
 #[derive(Clone, Copy)]
@@ -281,4 +412,178 @@ impl<'a> Table<'a> {
             subtables: Subtables { data, records },
         })
     }
+
+    /// Returns the most suitable subtable for Unicode code points.
+    ///
+    /// Subtables are selected in the following order:
+    /// Windows Unicode full repertoire (3, 10), Windows Unicode BMP (3, 1),
+    /// Unicode (0, *) and Windows Symbol (3, 0).
+    /// The first subtable is used when multiple subtables have the same priority.
+    /// Variation sequences (format 14) and unsupported subtables are ignored.
+    ///
+    /// Note that Windows Symbol subtables usually map characters in the `U+F000..U+F0FF` range.
+    pub fn best_unicode_subtable(&self) -> Option<Subtable<'a>> {
+        let mut best: Option<(u8, Subtable)> = None;
+        for subtable in self.subtables {
+            if let Some(rank) = subtable.unicode_rank() {
+                if best.map(|(best_rank, _)| rank < best_rank).unwrap_or(true) {
+                    best = Some((rank, subtable));
+                }
+            }
+        }
+
+        best.map(|(_, subtable)| subtable)
+    }
+}
+
+/// A precomputed code point to glyph mapping.
+///
+/// Built via [`Face::build_cmap_cache`](../struct.Face.html#method.build_cmap_cache)
+/// from a single Unicode subtable, so lookups don't involve any parsing.
+///
+/// Maps code points to glyphs for the default variation only.
+/// Unicode Variation Sequences (format 14) are not cached.
+#[cfg(feature = "std")]
+#[derive(Clone, Default, Debug)]
+pub struct CmapCache {
+    // Sorted by code point and non-overlapping.
+    ranges: std::vec::Vec<CachedRange>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+struct CachedRange {
+    start: u32,
+    end: u32,
+    start_glyph_id: u32,
+    // Whether glyph IDs are incremented along with code points, like in format 12.
+    // Otherwise all code points are mapped to the same glyph, like in format 13.
+    sequential: bool,
+}
+
+#[cfg(feature = "std")]
+impl CmapCache {
+    /// Builds a cache from a subtable.
+    ///
+    /// Doesn't check that the subtable encoding is Unicode.
+    /// Code points above `U+10FFFF` are ignored.
+    pub fn from_subtable(subtable: &Subtable) -> Self {
+        let mut ranges = std::vec::Vec::new();
+        match subtable.format {
+            // Segmented subtables are cached per group, since a single group
+            // can cover the whole code point range.
+            Format::SegmentedCoverage(ref subtable) => {
+                for group in subtable.groups() {
+                    // Stop where glyph IDs would overflow.
+                    let max_offset = match 0xFFFFu32.checked_sub(group.start_glyph_id) {
+                        Some(n) => n,
+                        None => continue,
+                    };
+
+                    let last = group.start_char_code.saturating_add(max_offset);
+                    ranges.push(CachedRange {
+                        start: group.start_char_code,
+                        end: core::cmp::min(group.end_char_code, last),
+                        start_glyph_id: group.start_glyph_id,
+                        sequential: true,
+                    });
+                }
+            }
+            Format::ManyToOneRangeMappings(ref subtable) => {
+                for group in subtable.groups() {
+                    if group.start_glyph_id <= 0xFFFF {
+                        ranges.push(CachedRange {
+                            start: group.start_char_code,
+                            end: group.end_char_code,
+                            start_glyph_id: group.start_glyph_id,
+                            sequential: false,
+                        });
+                    }
+                }
+            }
+            _ => {
+                subtable.codepoints(|code_point| {
+                    if let Some(id) = subtable.glyph_index(code_point) {
+                        ranges.push(CachedRange {
+                            start: code_point,
+                            end: code_point,
+                            start_glyph_id: u32::from(id.0),
+                            sequential: true,
+                        });
+                    }
+                });
+            }
+        }
+
+        for range in &mut ranges {
+            range.end = core::cmp::min(range.end, MAX_CODE_POINT);
+        }
+        ranges.retain(|range| range.start <= range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        // Trim overlapping ranges, so each code point is cached only once.
+        let mut mapping: std::vec::Vec<CachedRange> = std::vec::Vec::with_capacity(ranges.len());
+        for mut range in ranges {
+            if let Some(last) = mapping.last() {
+                if range.start <= last.end {
+                    let start = last.end + 1;
+                    if start > range.end {
+                        continue;
+                    }
+
+                    if range.sequential {
+                        range.start_glyph_id += start - range.start;
+                    }
+
+                    range.start = start;
+                }
+            }
+
+            mapping.push(range);
+        }
+
+        CmapCache { ranges: mapping }
+    }
+
+    /// Maps a code point to a glyph ID.
+    ///
+    /// Returns `None` instead of `0` when glyph is not found.
+    #[inline]
+    pub fn glyph_index(&self, code_point: u32) -> Option<GlyphId> {
+        let index = match self
+            .ranges
+            .binary_search_by_key(&code_point, |range| range.start)
+        {
+            Ok(index) => index,
+            Err(index) => index.checked_sub(1)?,
+        };
+
+        let range = self.ranges.get(index)?;
+        if code_point > range.end {
+            return None;
+        }
+
+        let id = if range.sequential {
+            range.start_glyph_id.checked_add(code_point - range.start)?
+        } else {
+            range.start_glyph_id
+        };
+
+        u16::try_from(id).ok().map(GlyphId)
+    }
+
+    /// Returns the number of cached code points.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|range| usize::num_from(range.end - range.start) + 1)
+            .sum()
+    }
+
+    /// Checks if there are any cached code points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
 }
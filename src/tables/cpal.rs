@@ -0,0 +1,180 @@
+//! A [Color Palette Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/cpal) implementation.
+
+use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream};
+
+/// A [BGRA color record](https://docs.microsoft.com/en-us/typography/opentype/spec/cpal#color-records-array).
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BgraColor {
+    pub blue: u8,
+    pub green: u8,
+    pub red: u8,
+    pub alpha: u8,
+}
+
+impl FromData for BgraColor {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(BgraColor {
+            blue: s.read::<u8>()?,
+            green: s.read::<u8>()?,
+            red: s.read::<u8>()?,
+            alpha: s.read::<u8>()?,
+        })
+    }
+}
+
+/// A [palette type](https://docs.microsoft.com/en-us/typography/opentype/spec/cpal#palette-type-array).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct PaletteFlags(pub u32);
+
+impl PaletteFlags {
+    /// The palette is appropriate to use when displaying the font on a light background.
+    pub const USABLE_WITH_LIGHT_BACKGROUND: u32 = 0x0001;
+    /// The palette is appropriate to use when displaying the font on a dark background.
+    pub const USABLE_WITH_DARK_BACKGROUND: u32 = 0x0002;
+
+    /// Checks that `USABLE_WITH_LIGHT_BACKGROUND` flag is set.
+    #[inline]
+    pub fn light_background(self) -> bool {
+        self.0 & Self::USABLE_WITH_LIGHT_BACKGROUND != 0
+    }
+
+    /// Checks that `USABLE_WITH_DARK_BACKGROUND` flag is set.
+    #[inline]
+    pub fn dark_background(self) -> bool {
+        self.0 & Self::USABLE_WITH_DARK_BACKGROUND != 0
+    }
+}
+
+/// A [Color Palette Table](https://docs.microsoft.com/en-us/typography/opentype/spec/cpal).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    color_records: LazyArray16<'a, BgraColor>,
+    color_record_indices: LazyArray16<'a, u16>,
+    palette_entries: u16,
+    // Version 1 only.
+    palette_types: Option<LazyArray16<'a, u32>>,
+    palette_labels: Option<LazyArray16<'a, u16>>,
+    palette_entry_labels: Option<LazyArray16<'a, u16>>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        let palette_entries = s.read::<u16>()?;
+        let palettes = s.read::<u16>()?;
+        let color_records_count = s.read::<u16>()?;
+        let color_records_offset = s.read::<Offset32>()?;
+        let color_record_indices = s.read_array16::<u16>(palettes)?;
+
+        let color_records = Stream::new_at(data, color_records_offset.to_usize())?
+            .read_array16::<BgraColor>(color_records_count)?;
+
+        let mut table = Table {
+            color_records,
+            color_record_indices,
+            palette_entries,
+            palette_types: None,
+            palette_labels: None,
+            palette_entry_labels: None,
+        };
+
+        if version == 1 {
+            let palette_types_offset = s.read::<Option<Offset32>>()?;
+            let palette_labels_offset = s.read::<Option<Offset32>>()?;
+            let palette_entry_labels_offset = s.read::<Option<Offset32>>()?;
+            // Malformed arrays are ignored, since they are optional.
+            table.palette_types = read_array(data, palette_types_offset, palettes);
+            table.palette_labels = read_array(data, palette_labels_offset, palettes);
+            table.palette_entry_labels =
+                read_array(data, palette_entry_labels_offset, palette_entries);
+        }
+
+        Some(table)
+    }
+
+    /// Returns the number of palettes.
+    #[inline]
+    pub fn palettes(&self) -> u16 {
+        self.color_record_indices.len()
+    }
+
+    /// Returns the number of entries in each palette.
+    #[inline]
+    pub fn palette_entries(&self) -> u16 {
+        self.palette_entries
+    }
+
+    /// Returns all colors of a palette.
+    ///
+    /// The first palette, at index 0, is the default one.
+    ///
+    /// Returns `None` when the palette index is out of bounds or the palette is malformed.
+    #[inline]
+    pub fn palette(&self, palette_index: u16) -> Option<LazyArray16<'a, BgraColor>> {
+        let start = self.color_record_indices.get(palette_index)?;
+        let end = start.checked_add(self.palette_entries)?;
+        self.color_records.slice(start..end)
+    }
+
+    /// Returns a color at the specified palette and entry.
+    #[inline]
+    pub fn get(&self, palette_index: u16, palette_entry: u16) -> Option<BgraColor> {
+        self.palette(palette_index)?.get(palette_entry)
+    }
+
+    /// Returns palette's type flags.
+    ///
+    /// Returns no flags when the table has no palette types or the index is out of bounds.
+    #[inline]
+    pub fn palette_flags(&self, palette_index: u16) -> PaletteFlags {
+        self.palette_types
+            .and_then(|types| types.get(palette_index))
+            .map(PaletteFlags)
+            .unwrap_or_default()
+    }
+
+    /// Returns palette's name ID.
+    ///
+    /// Can be resolved via [`Face::names`](crate::Face::names).
+    ///
+    /// Returns `None` when the table has no palette labels or the palette has no label.
+    #[inline]
+    pub fn palette_label(&self, palette_index: u16) -> Option<u16> {
+        self.palette_labels?
+            .get(palette_index)
+            .filter(|id| *id != 0xFFFF)
+    }
+
+    /// Returns palette entry's name ID.
+    ///
+    /// The same label is used by the entry in all palettes.
+    /// Can be resolved via [`Face::names`](crate::Face::names).
+    ///
+    /// Returns `None` when the table has no palette entry labels or the entry has no label.
+    #[inline]
+    pub fn entry_label(&self, palette_entry: u16) -> Option<u16> {
+        self.palette_entry_labels?
+            .get(palette_entry)
+            .filter(|id| *id != 0xFFFF)
+    }
+}
+
+fn read_array<T: FromData>(
+    data: &[u8],
+    offset: Option<Offset32>,
+    count: u16,
+) -> Option<LazyArray16<'_, T>> {
+    Stream::new_at(data, offset?.to_usize())?.read_array16(count)
+}
@@ -65,6 +65,8 @@ pub struct FeatureName<'a> {
     /// The feature's exclusive settings. If set, the feature settings are mutually exclusive.
     pub exclusive: bool,
     /// The `name` table index for the feature's name in a 256..32768 range.
+    ///
+    /// Can be resolved via [`Face::names`](crate::Face::names).
     pub name_index: u16,
 }
 
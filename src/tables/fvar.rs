@@ -62,12 +62,112 @@ impl VariationAxis {
     }
 }
 
+/// A [named instance](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#instancerecord).
+#[derive(Clone, Copy, Debug)]
+pub struct NamedInstance<'a> {
+    /// A subfamily name in the `name` table.
+    pub subfamily_name_id: u16,
+    /// A PostScript name in the `name` table.
+    pub post_script_name_id: Option<u16>,
+    /// Axes coordinates in user units, in the same order as axes.
+    pub coordinates: LazyArray16<'a, Fixed>,
+}
+
+/// A list of named instances.
+#[derive(Clone, Copy, Default)]
+pub struct NamedInstances<'a> {
+    data: &'a [u8],
+    count: u16,
+    instance_size: usize,
+    axis_count: u16,
+}
+
+impl<'a> NamedInstances<'a> {
+    /// Returns a named instance at index.
+    pub fn get(&self, index: u16) -> Option<NamedInstance<'a>> {
+        if index >= self.count {
+            return None;
+        }
+
+        let start = usize::from(index) * self.instance_size;
+        let data = self.data.get(start..start + self.instance_size)?;
+        let mut s = Stream::new(data);
+        let subfamily_name_id = s.read::<u16>()?;
+        s.skip::<u16>(); // flags
+        let coordinates = s.read_array16::<Fixed>(self.axis_count)?;
+        // The PostScript name ID is optional and is present only when the instance size allows.
+        let post_script_name_id = s.read::<u16>();
+        Some(NamedInstance {
+            subfamily_name_id,
+            post_script_name_id,
+            coordinates,
+        })
+    }
+
+    /// Returns the number of named instances.
+    pub fn len(&self) -> u16 {
+        self.count
+    }
+
+    /// Checks if there are any named instances.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl core::fmt::Debug for NamedInstances<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "NamedInstances {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for NamedInstances<'a> {
+    type Item = NamedInstance<'a>;
+    type IntoIter = NamedInstancesIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        NamedInstancesIter {
+            instances: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over named instances.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct NamedInstancesIter<'a> {
+    instances: NamedInstances<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for NamedInstancesIter<'a> {
+    type Item = NamedInstance<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.instances.len() {
+            self.index += 1;
+            self.instances.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.instances.len().saturating_sub(self.index))
+    }
+}
+
 /// A [Font Variations Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/fvar).
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
     /// A list of variation axes.
     pub axes: LazyArray16<'a, VariationAxis>,
+    /// A list of named instances.
+    pub instances: NamedInstances<'a>,
 }
 
 impl<'a> Table<'a> {
@@ -82,6 +182,9 @@ impl<'a> Table<'a> {
         let axes_array_offset = s.read::<Offset16>()?;
         s.skip::<u16>(); // reserved
         let axis_count = s.read::<u16>()?;
+        let axis_size = s.read::<u16>()?;
+        let instance_count = s.read::<u16>()?;
+        let instance_size = s.read::<u16>()?;
 
         // 'If axisCount is zero, then the font is not functional as a variable font,
         // and must be treated as a non-variable font;
@@ -91,6 +194,59 @@ impl<'a> Table<'a> {
         let mut s = Stream::new_at(data, axes_array_offset.to_usize())?;
         let axes = s.read_array16::<VariationAxis>(axis_count.get())?;
 
-        Some(Table { axes })
+        // Named instances are optional, so malformed ones are ignored.
+        let instances_offset =
+            axes_array_offset.to_usize() + usize::from(axis_count.get()) * usize::from(axis_size);
+        let instances = parse_instances(
+            data,
+            instances_offset,
+            instance_count,
+            instance_size,
+            axis_count.get(),
+        )
+        .unwrap_or_default();
+
+        Some(Table { axes, instances })
+    }
+
+    /// Fills `coordinates` with the default normalized coordinates.
+    ///
+    /// Default normalized coordinates are always zero.
+    /// Only the first `axes.len()` items are set. The rest are left untouched.
+    pub fn default_coords(&self, coordinates: &mut [NormalizedCoordinate]) {
+        for c in coordinates.iter_mut().take(usize::from(self.axes.len())) {
+            *c = NormalizedCoordinate::default();
+        }
     }
+
+    /// Fills `coordinates` with the default user coordinates, i.e. axes default values.
+    ///
+    /// Only the first `axes.len()` items are set. The rest are left untouched.
+    pub fn default_user_coords(&self, coordinates: &mut [f32]) {
+        for (c, axis) in coordinates.iter_mut().zip(self.axes) {
+            *c = axis.def_value;
+        }
+    }
+}
+
+fn parse_instances(
+    data: &[u8],
+    offset: usize,
+    count: u16,
+    instance_size: u16,
+    axis_count: u16,
+) -> Option<NamedInstances<'_>> {
+    // Each instance has a subfamily name ID, flags and a coordinate per axis.
+    let instance_size = usize::from(instance_size);
+    if instance_size < 4 + usize::from(axis_count) * Fixed::SIZE {
+        return None;
+    }
+
+    let len = usize::from(count) * instance_size;
+    Some(NamedInstances {
+        data: data.get(offset..offset + len)?,
+        count,
+        instance_size,
+        axis_count,
+    })
 }
@@ -0,0 +1,110 @@
+//! A [Grid-fitting and Scan-conversion Procedure Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/gasp) implementation.
+
+use crate::parser::{FromData, LazyArray16, Stream};
+
+/// A [grid-fitting and scan-conversion behavior](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp#gasprange-record).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct GaspBehavior(pub u16);
+
+impl GaspBehavior {
+    /// Use gridfitting.
+    pub const GRIDFIT: u16 = 0x0001;
+    /// Use grayscale rendering.
+    pub const DO_GRAY: u16 = 0x0002;
+    /// Use gridfitting with ClearType symmetric smoothing.
+    pub const SYMMETRIC_GRIDFIT: u16 = 0x0004;
+    /// Use smoothing along multiple axes with ClearType.
+    pub const SYMMETRIC_SMOOTHING: u16 = 0x0008;
+
+    /// Checks that `GRIDFIT` flag is set.
+    #[inline]
+    pub fn gridfit(self) -> bool {
+        self.0 & Self::GRIDFIT != 0
+    }
+
+    /// Checks that `DO_GRAY` flag is set.
+    #[inline]
+    pub fn do_gray(self) -> bool {
+        self.0 & Self::DO_GRAY != 0
+    }
+
+    /// Checks that `SYMMETRIC_GRIDFIT` flag is set.
+    #[inline]
+    pub fn symmetric_gridfit(self) -> bool {
+        self.0 & Self::SYMMETRIC_GRIDFIT != 0
+    }
+
+    /// Checks that `SYMMETRIC_SMOOTHING` flag is set.
+    #[inline]
+    pub fn symmetric_smoothing(self) -> bool {
+        self.0 & Self::SYMMETRIC_SMOOTHING != 0
+    }
+}
+
+/// A range record.
+#[derive(Clone, Copy, Debug)]
+pub struct Range {
+    /// Upper limit of the range, in PPEM.
+    pub max_ppem: u16,
+    /// Range behavior.
+    pub behavior: GaspBehavior,
+}
+
+impl FromData for Range {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(Range {
+            max_ppem: s.read::<u16>()?,
+            behavior: GaspBehavior(s.read::<u16>()?),
+        })
+    }
+}
+
+/// A [Grid-fitting and Scan-conversion Procedure Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of ranges sorted by `max_ppem`.
+    pub ranges: LazyArray16<'a, Range>,
+    version: u16,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        let count = s.read::<u16>()?;
+        let ranges = s.read_array16::<Range>(count)?;
+        Some(Table { ranges, version })
+    }
+
+    /// Returns a behavior for the specified size.
+    ///
+    /// Returns no flags when `ppem` is not covered by any range,
+    /// which is possible only when the last range is not `0xFFFF`.
+    pub fn behavior(&self, ppem: u16) -> GaspBehavior {
+        let flags = self
+            .ranges
+            .into_iter()
+            .find(|range| range.max_ppem >= ppem)
+            .map(|range| range.behavior.0)
+            .unwrap_or(0);
+
+        // Symmetric flags are defined only in version 1.
+        if self.version == 0 {
+            GaspBehavior(flags & (GaspBehavior::GRIDFIT | GaspBehavior::DO_GRAY))
+        } else {
+            GaspBehavior(flags)
+        }
+    }
+}
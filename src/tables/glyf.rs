@@ -4,108 +4,185 @@
 use core::num::NonZeroU16;
 
 use crate::parser::{LazyArray16, NumFrom, Stream, F2DOT14};
-use crate::{loca, BBox, GlyphId, OutlineBuilder, Rect};
+use crate::{
+    loca, BBox, ContourArea, ControlFlow, DummyOutline, GlyphId, NeverStop, OutlineBuilder,
+    OutlineError, Rect, StoppableOutlineBuilder,
+};
 
 pub(crate) struct Builder<'a> {
-    pub builder: &'a mut dyn OutlineBuilder,
+    pub builder: &'a mut dyn StoppableOutlineBuilder,
     pub transform: Transform,
     is_default_ts: bool, // `bool` is faster than `Option` or `is_default`.
     // We have to always calculate the bbox, because `gvar` doesn't store one
     // and in case of a malformed bbox in `glyf`.
     pub bbox: BBox,
+    area: ContourArea,
     first_on_curve: Option<Point>,
-    first_off_curve: Option<Point>,
-    last_off_curve: Option<Point>,
+    // Off-curve points that precede the first on-curve point.
+    // They will be processed on contour closing.
+    first_off_curve: OffCurvePoints,
+    last_off_curve: OffCurvePoints,
+    // Set when the builder has asked to stop. No more segments will be emitted.
+    pub stopped: bool,
 }
 
 impl<'a> Builder<'a> {
     #[inline]
-    pub fn new(transform: Transform, bbox: BBox, builder: &'a mut dyn OutlineBuilder) -> Self {
+    pub fn new(
+        transform: Transform,
+        bbox: BBox,
+        builder: &'a mut dyn StoppableOutlineBuilder,
+    ) -> Self {
         Builder {
             builder,
             transform,
             is_default_ts: transform.is_default(),
             bbox,
+            area: ContourArea::default(),
             first_on_curve: None,
-            first_off_curve: None,
-            last_off_curve: None,
+            first_off_curve: OffCurvePoints::default(),
+            last_off_curve: OffCurvePoints::default(),
+            stopped: false,
         }
     }
 
     #[inline]
     fn move_to(&mut self, mut x: f32, mut y: f32) {
+        if self.stopped {
+            return;
+        }
+
         if !self.is_default_ts {
             self.transform.apply_to(&mut x, &mut y);
         }
 
         self.bbox.extend_by(x, y);
+        self.area.move_to(x, y);
 
-        self.builder.move_to(x, y);
+        self.stopped = self.builder.move_to(x, y) == ControlFlow::Break;
     }
 
     #[inline]
     fn line_to(&mut self, mut x: f32, mut y: f32) {
+        if self.stopped {
+            return;
+        }
+
         if !self.is_default_ts {
             self.transform.apply_to(&mut x, &mut y);
         }
 
         self.bbox.extend_by(x, y);
+        self.area.line_to(x, y);
 
-        self.builder.line_to(x, y);
+        self.stopped = self.builder.line_to(x, y) == ControlFlow::Break;
     }
 
     #[inline]
     fn quad_to(&mut self, mut x1: f32, mut y1: f32, mut x: f32, mut y: f32) {
+        if self.stopped {
+            return;
+        }
+
+        if !self.is_default_ts {
+            self.transform.apply_to(&mut x1, &mut y1);
+            self.transform.apply_to(&mut x, &mut y);
+        }
+
+        self.bbox.extend_by(x1, y1);
+        self.bbox.extend_by(x, y);
+        self.area.line_to(x1, y1);
+        self.area.line_to(x, y);
+
+        self.stopped = self.builder.quad_to(x1, y1, x, y) == ControlFlow::Break;
+    }
+
+    #[inline]
+    fn curve_to(
+        &mut self,
+        mut x1: f32,
+        mut y1: f32,
+        mut x2: f32,
+        mut y2: f32,
+        mut x: f32,
+        mut y: f32,
+    ) {
+        if self.stopped {
+            return;
+        }
+
         if !self.is_default_ts {
             self.transform.apply_to(&mut x1, &mut y1);
+            self.transform.apply_to(&mut x2, &mut y2);
             self.transform.apply_to(&mut x, &mut y);
         }
 
         self.bbox.extend_by(x1, y1);
+        self.bbox.extend_by(x2, y2);
         self.bbox.extend_by(x, y);
+        self.area.line_to(x1, y1);
+        self.area.line_to(x2, y2);
+        self.area.line_to(x, y);
 
-        self.builder.quad_to(x1, y1, x, y);
+        self.stopped = self.builder.curve_to(x1, y1, x2, y2, x, y) == ControlFlow::Break;
+    }
+
+    // Emits a segment from the current point to `p` using the specified off-curve points.
+    #[inline]
+    fn segment_to(&mut self, off_curve: OffCurvePoints, p: Point) {
+        match off_curve.len {
+            0 => self.line_to(p.x, p.y),
+            1 => self.quad_to(off_curve.p1.x, off_curve.p1.y, p.x, p.y),
+            _ => self.curve_to(
+                off_curve.p1.x,
+                off_curve.p1.y,
+                off_curve.p2.x,
+                off_curve.p2.y,
+                p.x,
+                p.y,
+            ),
+        }
     }
 
     // Useful links:
     //
     // - https://developer.apple.com/fonts/TrueType-Reference-Manual/RM01/Chap1.html
     // - https://stackoverflow.com/a/20772557
+    // - https://github.com/harfbuzz/boring-expansion-spec/blob/main/glyf1-cubicOutlines.md
     #[inline]
-    pub fn push_point(&mut self, x: f32, y: f32, on_curve_point: bool, last_point: bool) {
+    pub fn push_point(
+        &mut self,
+        x: f32,
+        y: f32,
+        on_curve_point: bool,
+        cubic_point: bool,
+        last_point: bool,
+    ) {
         let p = Point { x, y };
         if self.first_on_curve.is_none() {
             if on_curve_point {
                 self.first_on_curve = Some(p);
                 self.move_to(p.x, p.y);
+            } else if self.first_off_curve.accepts(cubic_point) {
+                self.first_off_curve.push(p, cubic_point);
             } else {
-                if let Some(offcurve) = self.first_off_curve {
-                    let mid = offcurve.lerp(p, 0.5);
-                    self.first_on_curve = Some(mid);
-                    self.last_off_curve = Some(p);
-                    self.move_to(mid.x, mid.y);
-                } else {
-                    self.first_off_curve = Some(p);
-                }
+                // Two off-curve points in a row imply an on-curve point between them.
+                let mid = self.first_off_curve.last().lerp(p, 0.5);
+                self.first_on_curve = Some(mid);
+                self.last_off_curve = OffCurvePoints::new(p, cubic_point);
+                self.move_to(mid.x, mid.y);
             }
+        } else if on_curve_point {
+            let off_curve = self.last_off_curve;
+            self.last_off_curve = OffCurvePoints::default();
+            self.segment_to(off_curve, p);
+        } else if self.last_off_curve.accepts(cubic_point) {
+            self.last_off_curve.push(p, cubic_point);
         } else {
-            match (self.last_off_curve, on_curve_point) {
-                (Some(offcurve), true) => {
-                    self.last_off_curve = None;
-                    self.quad_to(offcurve.x, offcurve.y, p.x, p.y);
-                }
-                (Some(offcurve), false) => {
-                    self.last_off_curve = Some(p);
-                    let mid = offcurve.lerp(p, 0.5);
-                    self.quad_to(offcurve.x, offcurve.y, mid.x, mid.y);
-                }
-                (None, true) => {
-                    self.line_to(p.x, p.y);
-                }
-                (None, false) => {
-                    self.last_off_curve = Some(p);
-                }
-            }
+            let off_curve = self.last_off_curve;
+            self.last_off_curve = OffCurvePoints::new(p, cubic_point);
+            let mid = off_curve.last().lerp(p, 0.5);
+            self.segment_to(off_curve, mid);
         }
 
         if last_point {
@@ -115,25 +192,79 @@ impl<'a> Builder<'a> {
 
     #[inline]
     fn finish_contour(&mut self) {
-        if let (Some(offcurve1), Some(offcurve2)) = (self.first_off_curve, self.last_off_curve) {
-            self.last_off_curve = None;
-            let mid = offcurve2.lerp(offcurve1, 0.5);
-            self.quad_to(offcurve2.x, offcurve2.y, mid.x, mid.y);
-        }
+        if let Some(p) = self.first_on_curve {
+            let first_off_curve = self.first_off_curve;
+            let last_off_curve = self.last_off_curve;
+            if first_off_curve.len != 0 {
+                if last_off_curve.len != 0 {
+                    let mid = last_off_curve.last().lerp(first_off_curve.p1, 0.5);
+                    self.segment_to(last_off_curve, mid);
+                }
 
-        if let (Some(p), Some(offcurve1)) = (self.first_on_curve, self.first_off_curve) {
-            self.quad_to(offcurve1.x, offcurve1.y, p.x, p.y);
-        } else if let (Some(p), Some(offcurve2)) = (self.first_on_curve, self.last_off_curve) {
-            self.quad_to(offcurve2.x, offcurve2.y, p.x, p.y);
-        } else if let Some(p) = self.first_on_curve {
-            self.line_to(p.x, p.y);
+                self.segment_to(first_off_curve, p);
+            } else {
+                self.segment_to(last_off_curve, p);
+            }
         }
 
         self.first_on_curve = None;
-        self.first_off_curve = None;
-        self.last_off_curve = None;
+        self.first_off_curve = OffCurvePoints::default();
+        self.last_off_curve = OffCurvePoints::default();
+
+        let is_clockwise = self.area.finish();
+        if !self.stopped {
+            self.builder.contour_winding(is_clockwise);
+            self.stopped = self.builder.close() == ControlFlow::Break;
+        }
+    }
+}
+
+// Consecutive off-curve points of a single segment.
+//
+// A quadratic segment has just one, while a cubic one has two.
+#[derive(Clone, Copy, Default)]
+struct OffCurvePoints {
+    p1: Point,
+    p2: Point,
+    len: u8,
+    is_cubic: bool,
+}
+
+impl OffCurvePoints {
+    #[inline]
+    fn new(p: Point, is_cubic: bool) -> Self {
+        OffCurvePoints {
+            p1: p,
+            p2: p,
+            len: 1,
+            is_cubic,
+        }
+    }
+
+    // Checks that a point can be added to the current segment
+    // without implying an on-curve point.
+    #[inline]
+    fn accepts(&self, is_cubic: bool) -> bool {
+        self.len == 0 || (self.len == 1 && self.is_cubic && is_cubic)
+    }
+
+    #[inline]
+    fn push(&mut self, p: Point, is_cubic: bool) {
+        if self.len == 0 {
+            *self = OffCurvePoints::new(p, is_cubic);
+        } else {
+            self.p2 = p;
+            self.len = 2;
+        }
+    }
 
-        self.builder.close();
+    #[inline]
+    fn last(&self) -> Point {
+        if self.len == 2 {
+            self.p2
+        } else {
+            self.p1
+        }
     }
 }
 
@@ -297,6 +428,8 @@ pub(crate) struct GlyphPoint {
     /// Indicates that a point is a point on curve
     /// and not a control point.
     pub on_curve_point: bool,
+    /// Indicates that an off-curve point belongs to a cubic segment.
+    pub cubic_point: bool,
     pub last_point: bool,
 }
 
@@ -336,6 +469,7 @@ impl<'a> Iterator for GlyphPointsIter<'a> {
                 .y_coords
                 .next(flags.y_short(), flags.y_is_same_or_positive_short()),
             on_curve_point: flags.on_curve_point(),
+            cubic_point: flags.cubic(),
             last_point,
         })
     }
@@ -461,7 +595,7 @@ impl<'a> CoordsIter<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Default, Debug)]
 struct Point {
     x: f32,
     y: f32,
@@ -489,6 +623,8 @@ impl SimpleGlyphFlags {
     #[inline] fn repeat_flag(self) -> bool { self.0 & 0x08 != 0 }
     #[inline] fn x_is_same_or_positive_short(self) -> bool { self.0 & 0x10 != 0 }
     #[inline] fn y_is_same_or_positive_short(self) -> bool { self.0 & 0x20 != 0 }
+    // Not yet in the OpenType spec, but already produced by font tools.
+    #[inline] fn cubic(self) -> bool { self.0 & 0x80 != 0 }
 }
 
 // https://docs.microsoft.com/en-us/typography/opentype/spec/glyf#composite-glyph-description
@@ -505,8 +641,16 @@ impl CompositeGlyphFlags {
     #[inline] pub fn we_have_a_two_by_two(self) -> bool { self.0 & 0x0080 != 0 }
 }
 
-// It's not defined in the spec, so we are using our own value.
-pub(crate) const MAX_COMPONENTS: u8 = 32;
+/// The default composite glyph nesting depth limit.
+///
+/// It's not defined in the spec, so we are using our own value.
+/// See [`Table::set_depth_limit`].
+pub const MAX_COMPONENT_DEPTH: u8 = 32;
+
+/// The highest composite glyph nesting depth limit that can be set.
+///
+/// See [`Table::set_depth_limit`].
+pub const MAX_ALLOWED_COMPONENT_DEPTH: u8 = 64;
 
 #[allow(clippy::comparison_chain)]
 #[inline]
@@ -515,9 +659,10 @@ fn outline_impl(
     glyf_table: &[u8],
     data: &[u8],
     depth: u8,
+    max_depth: u8,
     builder: &mut Builder,
 ) -> Option<Option<Rect>> {
-    if depth >= MAX_COMPONENTS {
+    if depth >= max_depth {
         return None;
     }
 
@@ -531,24 +676,45 @@ fn outline_impl(
         // u16 casting is safe, since we already checked that the value is positive.
         let number_of_contours = NonZeroU16::new(number_of_contours as u16)?;
         for point in parse_simple_outline(s.tail()?, number_of_contours)? {
+            if builder.stopped {
+                break;
+            }
+
             builder.push_point(
                 f32::from(point.x),
                 f32::from(point.y),
                 point.on_curve_point,
+                point.cubic_point,
                 point.last_point,
             );
         }
     } else if number_of_contours < 0 {
         // Composite glyph.
         for comp in CompositeGlyphIter::new(s.tail()?) {
-            if let Some(range) = loca_table.glyph_range(comp.glyph_id) {
+            // Empty components are allowed and simply ignored.
+            if let Some(range) = loca_table
+                .glyph_range(comp.glyph_id)
+                .filter(|range| !range.is_empty())
+            {
                 if let Some(glyph_data) = glyf_table.get(range) {
                     let transform = Transform::combine(builder.transform, comp.transform);
                     let mut b = Builder::new(transform, builder.bbox, builder.builder);
-                    outline_impl(loca_table, glyf_table, glyph_data, depth + 1, &mut b)?;
+                    outline_impl(
+                        loca_table,
+                        glyf_table,
+                        glyph_data,
+                        depth + 1,
+                        max_depth,
+                        &mut b,
+                    )?;
 
                     // Take updated bbox.
                     builder.bbox = b.bbox;
+
+                    if b.stopped {
+                        builder.stopped = true;
+                        break;
+                    }
                 }
             }
         }
@@ -561,6 +727,41 @@ fn outline_impl(
     Some(builder.bbox.to_rect())
 }
 
+// Checks that all components of a composite glyph are present and not nested too deep.
+fn validate_components(
+    loca_table: loca::Table,
+    glyf_table: &[u8],
+    data: &[u8],
+    depth: u8,
+    max_depth: u8,
+) -> Result<(), OutlineError> {
+    if depth >= max_depth {
+        return Err(OutlineError::DepthLimitExceeded);
+    }
+
+    let mut s = Stream::new(data);
+    let number_of_contours = s.read::<i16>().ok_or(OutlineError::Malformed)?;
+    if number_of_contours >= 0 {
+        return Ok(());
+    }
+
+    s.advance(8); // Skip bbox.
+    let components = s.tail().ok_or(OutlineError::BadComposite)?;
+    for comp in CompositeGlyphIter::new(components) {
+        let range = loca_table
+            .glyph_range(comp.glyph_id)
+            .ok_or(OutlineError::BadComposite)?;
+        if range.is_empty() {
+            continue;
+        }
+
+        let glyph_data = glyf_table.get(range).ok_or(OutlineError::BadComposite)?;
+        validate_components(loca_table, glyf_table, glyph_data, depth + 1, max_depth)?;
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub(crate) fn parse_simple_outline(
     glyph_data: &[u8],
@@ -653,10 +854,14 @@ fn resolve_coords_len(s: &mut Stream, points_total: u16) -> Option<(u32, u32)> {
 
 /// A [Glyph Data Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/glyf).
+///
+/// Only the glyph data format 0 is supported.
+/// It's up to the caller to check `head::Table::glyph_data_format` before parsing.
 #[derive(Clone, Copy)]
 pub struct Table<'a> {
     pub(crate) data: &'a [u8],
     loca_table: loca::Table<'a>,
+    pub(crate) depth_limit: u8,
 }
 
 impl core::fmt::Debug for Table<'_> {
@@ -669,20 +874,128 @@ impl<'a> Table<'a> {
     /// Parses a table from raw data.
     #[inline]
     pub fn parse(loca_table: loca::Table<'a>, data: &'a [u8]) -> Option<Self> {
-        Some(Table { loca_table, data })
+        Some(Table {
+            loca_table,
+            data,
+            depth_limit: MAX_COMPONENT_DEPTH,
+        })
+    }
+
+    /// Sets the composite glyph nesting depth limit.
+    ///
+    /// The depth includes the glyph itself, therefore a simple glyph requires a depth of 1.
+    /// Glyphs that are nested deeper will not be outlined.
+    /// Lower values can be used to reduce stack usage on untrusted input,
+    /// while higher ones increase it proportionally.
+    ///
+    /// The default is [`MAX_COMPONENT_DEPTH`].
+    /// The value is clamped to [`MAX_ALLOWED_COMPONENT_DEPTH`].
+    #[inline]
+    pub fn set_depth_limit(&mut self, depth: u8) {
+        self.depth_limit = depth.min(MAX_ALLOWED_COMPONENT_DEPTH);
     }
 
     /// Outlines a glyph.
     #[inline]
     pub fn outline(&self, glyph_id: GlyphId, builder: &mut dyn OutlineBuilder) -> Option<Rect> {
+        self.outline_stoppable(glyph_id, &mut NeverStop(builder))
+    }
+
+    #[inline]
+    pub(crate) fn outline_stoppable(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn StoppableOutlineBuilder,
+    ) -> Option<Rect> {
         let mut b = Builder::new(Transform::default(), BBox::new(), builder);
         let glyph_data = self.get(glyph_id)?;
-        outline_impl(self.loca_table, self.data, glyph_data, 0, &mut b)?
+        outline_impl(
+            self.loca_table,
+            self.data,
+            glyph_data,
+            0,
+            self.depth_limit,
+            &mut b,
+        )?
+    }
+
+    // Explains why the glyph cannot be outlined.
+    pub(crate) fn outline_error(&self, glyph_id: GlyphId) -> OutlineError {
+        let glyph_data = match self.get(glyph_id) {
+            Some(v) => v,
+            None => return OutlineError::Empty,
+        };
+
+        if let Err(e) =
+            validate_components(self.loca_table, self.data, glyph_data, 0, self.depth_limit)
+        {
+            return e;
+        }
+
+        let mut builder = NeverStop(&mut DummyOutline);
+        let mut b = Builder::new(Transform::default(), BBox::new(), &mut builder);
+        match outline_impl(
+            self.loca_table,
+            self.data,
+            glyph_data,
+            0,
+            self.depth_limit,
+            &mut b,
+        ) {
+            Some(None) => OutlineError::Empty,
+            // Can be a malformed `gvar` data as well.
+            _ => OutlineError::Malformed,
+        }
     }
 
+    /// Returns a glyph bounding box stored in the glyph header.
+    ///
+    /// The stored bounding box can be malformed, since it's not validated in any way.
+    /// Composite glyphs return their own stored bounding box, without resolving components.
+    ///
+    /// Returns `None` for empty glyphs, like space.
     #[inline]
-    pub(crate) fn get(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+    pub fn bbox(&self, glyph_id: GlyphId) -> Option<Rect> {
+        let mut s = Stream::new(self.get(glyph_id)?);
+        s.skip::<i16>(); // number of contours
+        Some(Rect {
+            x_min: s.read::<i16>()?,
+            y_min: s.read::<i16>()?,
+            x_max: s.read::<i16>()?,
+            y_max: s.read::<i16>()?,
+        })
+    }
+
+    /// Returns the number of contours in a glyph.
+    ///
+    /// A negative value indicates a composite glyph.
+    /// Empty glyphs, like space, have zero contours.
+    ///
+    /// Returns `None` when glyph ID is out of bounds or `loca` is malformed.
+    #[inline]
+    pub fn number_of_contours(&self, glyph_id: GlyphId) -> Option<i16> {
+        let data = self.glyph_data(glyph_id)?;
+        if data.is_empty() {
+            Some(0)
+        } else {
+            Stream::read_at::<i16>(data, 0)
+        }
+    }
+
+    /// Returns raw glyph data.
+    ///
+    /// Empty glyphs, like space, return an empty slice.
+    ///
+    /// Returns `None` when glyph ID is out of bounds or `loca` is malformed.
+    #[inline]
+    pub fn glyph_data(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
         let range = self.loca_table.glyph_range(glyph_id)?;
         self.data.get(range)
     }
+
+    // Like `glyph_data`, but treats empty glyphs as missing.
+    #[inline]
+    pub(crate) fn get(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+        self.glyph_data(glyph_id).filter(|data| !data.is_empty())
+    }
 }
@@ -573,6 +573,28 @@ impl<'a> PairAdjustment<'a> {
             Self::Format2 { coverage, .. } => *coverage,
         }
     }
+
+    /// Returns a [`ValueRecord`] pair for a glyph pair.
+    ///
+    /// Returns `None` when the first glyph is not covered by the subtable
+    /// or the pair is not present.
+    pub fn get(
+        &self,
+        first: GlyphId,
+        second: GlyphId,
+    ) -> Option<(ValueRecord<'a>, ValueRecord<'a>)> {
+        match self {
+            Self::Format1 { coverage, sets } => sets.get(coverage.get(first)?)?.get(second),
+            Self::Format2 {
+                coverage,
+                classes,
+                matrix,
+            } => {
+                coverage.get(first)?;
+                matrix.get((classes.0.get(first), classes.1.get(second)))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -4,7 +4,9 @@
 // A heavily modified port of https://github.com/RazrFalcon/rustybuzz implementation
 // originally written by https://github.com/laurmaedje
 
-use crate::opentype_layout::{ChainedContextLookup, ContextLookup, Coverage, LookupSubtable};
+use crate::opentype_layout::{
+    ChainedContextLookup, ContextLookup, Coverage, Feature, LayoutTable, LookupSubtable,
+};
 use crate::parser::{FromSlice, LazyArray16, LazyOffsetArray16, Stream};
 use crate::GlyphId;
 
@@ -52,6 +54,23 @@ impl<'a> SingleSubstitution<'a> {
             Self::Format2 { coverage, .. } => *coverage,
         }
     }
+
+    /// Returns a substitute for a glyph.
+    ///
+    /// Returns `None` when the glyph is not covered by the subtable.
+    pub fn substitute(&self, glyph: GlyphId) -> Option<GlyphId> {
+        match self {
+            Self::Format1 { coverage, delta } => {
+                coverage.get(glyph)?;
+                // Addition is modulo 65536.
+                Some(GlyphId(glyph.0.wrapping_add(*delta as u16)))
+            }
+            Self::Format2 {
+                coverage,
+                substitutes,
+            } => substitutes.get(coverage.get(glyph)?),
+        }
+    }
 }
 
 /// A sequence of glyphs for
@@ -144,6 +163,13 @@ impl<'a> AlternateSubstitution<'a> {
             _ => None,
         }
     }
+
+    /// Returns alternates for a glyph.
+    ///
+    /// Returns `None` when the glyph is not covered by the subtable.
+    pub fn alternates(&self, glyph: GlyphId) -> Option<AlternateSet<'a>> {
+        self.alternate_sets.get(self.coverage.get(glyph)?)
+    }
 }
 
 /// Glyph components for one ligature.
@@ -201,6 +227,27 @@ impl<'a> LigatureSubstitution<'a> {
             _ => None,
         }
     }
+
+    /// Finds a ligature that matches the start of a glyph sequence.
+    ///
+    /// Returns the ligature glyph and the number of matched glyphs, including the first one.
+    /// Returns `None` when the first glyph is not covered by the subtable
+    /// or no ligature matches.
+    pub fn ligature(&self, glyphs: &[GlyphId]) -> Option<(GlyphId, usize)> {
+        let (first, rest) = glyphs.split_first()?;
+        self.ligature_for(*first, rest)
+    }
+
+    fn ligature_for(&self, first: GlyphId, rest: &[GlyphId]) -> Option<(GlyphId, usize)> {
+        let set = self.ligature_sets.get(self.coverage.get(first)?)?;
+        // Ligatures are stored in preference order, so the first match wins.
+        set.into_iter()
+            .find(|lig| {
+                usize::from(lig.components.len()) <= rest.len()
+                    && lig.components.into_iter().zip(rest).all(|(a, b)| a == *b)
+            })
+            .map(|lig| (lig.glyph, usize::from(lig.components.len()) + 1))
+    }
 }
 
 /// A [Reverse Chaining Contextual Single Substitution Subtable](
@@ -290,3 +337,76 @@ impl<'a> SubstitutionSubtable<'a> {
         matches!(self, Self::ReverseChainSingle(_))
     }
 }
+
+impl<'a> LayoutTable<'a> {
+    /// Returns a substitute for a glyph using feature's *Single Substitution* lookups.
+    ///
+    /// Must be called on a `GSUB` table.
+    /// Lookups are checked in the feature order and the first substitute is returned.
+    /// Other lookup types are ignored.
+    ///
+    /// Returns `None` when the glyph is not covered by any lookup.
+    pub fn single_substitution(&self, feature: Feature<'a>, glyph: GlyphId) -> Option<GlyphId> {
+        self.substitution_subtables(feature)
+            .find_map(|subtable| match subtable {
+                SubstitutionSubtable::Single(ref t) => t.substitute(glyph),
+                _ => None,
+            })
+    }
+
+    /// Returns alternates for a glyph using feature's *Alternate Substitution* lookups.
+    ///
+    /// Must be called on a `GSUB` table.
+    /// Lookups are checked in the feature order and the first alternate set is returned.
+    /// Other lookup types are ignored.
+    ///
+    /// Returns `None` when the glyph is not covered by any lookup.
+    pub fn alternate_substitutions(
+        &self,
+        feature: Feature<'a>,
+        glyph: GlyphId,
+    ) -> Option<impl Iterator<Item = GlyphId> + 'a> {
+        let set = self
+            .substitution_subtables(feature)
+            .find_map(|subtable| match subtable {
+                SubstitutionSubtable::Alternate(ref t) => t.alternates(glyph),
+                _ => None,
+            })?;
+        Some(set.alternates.into_iter())
+    }
+
+    /// Finds a ligature using feature's *Ligature Substitution* lookups.
+    ///
+    /// Must be called on a `GSUB` table.
+    /// Works the same way as [`LigatureSubstitution::ligature`],
+    /// but the first glyph is passed separately.
+    /// Lookups are checked in the feature order and the first match is returned.
+    /// Other lookup types are ignored.
+    ///
+    /// Returns the ligature glyph and the number of matched glyphs, including the first one.
+    /// Returns `None` when no ligature matches.
+    pub fn ligature_substitution(
+        &self,
+        feature: Feature<'a>,
+        first_glyph: GlyphId,
+        following: &[GlyphId],
+    ) -> Option<(GlyphId, usize)> {
+        self.substitution_subtables(feature)
+            .find_map(|subtable| match subtable {
+                SubstitutionSubtable::Ligature(ref t) => t.ligature_for(first_glyph, following),
+                _ => None,
+            })
+    }
+
+    fn substitution_subtables(
+        &self,
+        feature: Feature<'a>,
+    ) -> impl Iterator<Item = SubstitutionSubtable<'a>> + 'a {
+        let lookups = self.lookups;
+        feature
+            .lookup_indices
+            .into_iter()
+            .filter_map(move |index| lookups.get(index))
+            .flat_map(|lookup| lookup.subtables.into_iter::<SubstitutionSubtable>())
+    }
+}
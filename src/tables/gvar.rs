@@ -13,7 +13,9 @@ use core::num::NonZeroU16;
 
 use crate::glyf::{self, Transform};
 use crate::parser::{LazyArray16, Offset, Offset16, Offset32, Stream, F2DOT14};
-use crate::{BBox, GlyphId, NormalizedCoordinate, OutlineBuilder, Rect};
+use crate::{
+    BBox, GlyphId, NeverStop, NormalizedCoordinate, OutlineBuilder, Rect, StoppableOutlineBuilder,
+};
 
 /// 'The TrueType rasterizer dynamically generates 'phantom' points for each glyph
 /// that represent horizontal and vertical advance widths and side bearings,
@@ -1685,6 +1687,38 @@ impl<'a> Table<'a> {
         })
     }
 
+    #[inline]
+    fn variation_data_range(&self, glyph_id: GlyphId) -> Option<(usize, usize)> {
+        let next_glyph_id = glyph_id.0.checked_add(1)?;
+        match self.offsets {
+            GlyphVariationDataOffsets::Short(ref array) => {
+                // 'If the short format (Offset16) is used for offsets,
+                // the value stored is the offset divided by 2.'
+                Some((
+                    array.get(glyph_id.0)?.to_usize() * 2,
+                    array.get(next_glyph_id)?.to_usize() * 2,
+                ))
+            }
+            GlyphVariationDataOffsets::Long(ref array) => Some((
+                array.get(glyph_id.0)?.to_usize(),
+                array.get(next_glyph_id)?.to_usize(),
+            )),
+        }
+    }
+
+    /// Checks that a glyph has variation data.
+    ///
+    /// Glyphs without variation data are not affected by variation coordinates.
+    /// Though composite glyphs can still be affected via their components.
+    ///
+    /// Returns `false` when glyph ID is out of bounds or the data is malformed.
+    pub fn has_deltas(&self, glyph_id: GlyphId) -> bool {
+        match self.variation_data_range(glyph_id) {
+            Some((start, end)) => start < end && end <= self.glyphs_variation_data.len(),
+            None => false,
+        }
+    }
+
     #[inline]
     fn parse_variation_data(
         &self,
@@ -1699,22 +1733,7 @@ impl<'a> Table<'a> {
             return None;
         }
 
-        let next_glyph_id = glyph_id.0.checked_add(1)?;
-
-        let (start, end) = match self.offsets {
-            GlyphVariationDataOffsets::Short(ref array) => {
-                // 'If the short format (Offset16) is used for offsets,
-                // the value stored is the offset divided by 2.'
-                (
-                    array.get(glyph_id.0)?.to_usize() * 2,
-                    array.get(next_glyph_id)?.to_usize() * 2,
-                )
-            }
-            GlyphVariationDataOffsets::Long(ref array) => (
-                array.get(glyph_id.0)?.to_usize(),
-                array.get(next_glyph_id)?.to_usize(),
-            ),
-        };
+        let (start, end) = self.variation_data_range(glyph_id)?;
 
         // Ignore empty data.
         if start == end {
@@ -1738,6 +1757,16 @@ impl<'a> Table<'a> {
         coordinates: &[NormalizedCoordinate],
         glyph_id: GlyphId,
         builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        self.outline_stoppable(glyf_table, coordinates, glyph_id, &mut NeverStop(builder))
+    }
+
+    pub(crate) fn outline_stoppable(
+        &self,
+        glyf_table: glyf::Table,
+        coordinates: &[NormalizedCoordinate],
+        glyph_id: GlyphId,
+        builder: &mut dyn StoppableOutlineBuilder,
     ) -> Option<Rect> {
         let mut b = glyf::Builder::new(Transform::default(), BBox::new(), builder);
         let glyph_data = glyf_table.get(glyph_id)?;
@@ -1770,7 +1799,7 @@ fn outline_var_impl(
     depth: u8,
     builder: &mut glyf::Builder,
 ) -> Option<()> {
-    if depth >= glyf::MAX_COMPONENTS {
+    if depth >= glyf_table.depth_limit {
         return None;
     }
 
@@ -1798,8 +1827,18 @@ fn outline_var_impl(
         gvar_table.parse_variation_data(glyph_id, coordinates, points_len, &mut tuples)?;
 
         while let Some(point) = glyph_points.next() {
+            if builder.stopped {
+                break;
+            }
+
             let (x, y) = tuples.apply(all_glyph_points.clone(), glyph_points.clone(), point)?;
-            builder.push_point(x, y, point.on_curve_point, point.last_point);
+            builder.push_point(
+                x,
+                y,
+                point.on_curve_point,
+                point.cubic_point,
+                point.last_point,
+            );
         }
 
         Some(())
@@ -1845,6 +1884,11 @@ fn outline_var_impl(
 
             // Take updated bbox.
             builder.bbox = b.bbox;
+
+            if b.stopped {
+                builder.stopped = true;
+                break;
+            }
         }
 
         Some(())
@@ -0,0 +1,158 @@
+//! A [Horizontal Device Metrics Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx) implementation.
+
+use core::convert::TryFrom;
+use core::num::NonZeroU16;
+
+use crate::parser::Stream;
+use crate::GlyphId;
+
+/// A device record.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceRecord<'a> {
+    /// A pixel size for the following widths (as ppem).
+    pub pixel_size: u8,
+    /// Maximum width.
+    pub max_width: u8,
+    /// Glyph widths, in pixels, indexed by glyph ID.
+    pub widths: &'a [u8],
+}
+
+impl<'a> DeviceRecord<'a> {
+    /// Returns glyph's advance width in pixels.
+    #[inline]
+    pub fn width(&self, glyph_id: GlyphId) -> Option<u8> {
+        self.widths.get(usize::from(glyph_id.0)).copied()
+    }
+}
+
+/// A list of device records.
+#[derive(Clone, Copy, Default)]
+pub struct DeviceRecords<'a> {
+    data: &'a [u8],
+    count: u16,
+    record_size: usize,
+    number_of_glyphs: u16,
+}
+
+impl<'a> DeviceRecords<'a> {
+    /// Returns a device record at index.
+    pub fn get(&self, index: u16) -> Option<DeviceRecord<'a>> {
+        if index >= self.count {
+            return None;
+        }
+
+        // Records can be padded, therefore `record_size` is used as a stride.
+        let start = usize::from(index).checked_mul(self.record_size)?;
+        let mut s = Stream::new_at(self.data, start)?;
+        let pixel_size = s.read::<u8>()?;
+        let max_width = s.read::<u8>()?;
+        let widths = s.read_bytes(usize::from(self.number_of_glyphs))?;
+        Some(DeviceRecord {
+            pixel_size,
+            max_width,
+            widths,
+        })
+    }
+
+    /// Returns a device record for the specified pixel size.
+    pub fn find(&self, pixel_size: u8) -> Option<DeviceRecord<'a>> {
+        self.into_iter()
+            .find(|record| record.pixel_size == pixel_size)
+    }
+
+    /// Returns the number of device records.
+    pub fn len(&self) -> u16 {
+        self.count
+    }
+
+    /// Checks if there are any device records.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl core::fmt::Debug for DeviceRecords<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "DeviceRecords {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for DeviceRecords<'a> {
+    type Item = DeviceRecord<'a>;
+    type IntoIter = DeviceRecordsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        DeviceRecordsIter {
+            records: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over device records.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct DeviceRecordsIter<'a> {
+    records: DeviceRecords<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for DeviceRecordsIter<'a> {
+    type Item = DeviceRecord<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.records.len() {
+            self.index += 1;
+            self.records.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.records.len().saturating_sub(self.index))
+    }
+}
+
+/// A [Horizontal Device Metrics Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of device records.
+    pub records: DeviceRecords<'a>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    ///
+    /// `number_of_glyphs` is from the `maxp` table.
+    pub fn parse(number_of_glyphs: NonZeroU16, data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version != 0 {
+            return None;
+        }
+
+        let count = u16::try_from(s.read::<i16>()?).ok()?;
+        let record_size = usize::try_from(s.read::<i32>()?).ok()?;
+
+        // Each record has a pixel size, a max width and a width per glyph.
+        if record_size < usize::from(number_of_glyphs.get()) + 2 {
+            return None;
+        }
+
+        Some(Table {
+            records: DeviceRecords {
+                // The last record's padding is not required, therefore we don't check the length
+                // and each record is validated on access instead.
+                data: s.tail()?,
+                count,
+                record_size,
+                number_of_glyphs: number_of_glyphs.get(),
+            },
+        })
+    }
+}
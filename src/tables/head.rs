@@ -13,18 +13,173 @@ pub enum IndexToLocationFormat {
     Long,
 }
 
+/// A [font style](https://docs.microsoft.com/en-us/typography/opentype/spec/head) used by macOS.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct MacStyle(pub u16);
+
+impl MacStyle {
+    /// Bold.
+    pub const BOLD: u16 = 0x0001;
+    /// Italic.
+    pub const ITALIC: u16 = 0x0002;
+    /// Underline.
+    pub const UNDERLINE: u16 = 0x0004;
+    /// Outline.
+    pub const OUTLINE: u16 = 0x0008;
+    /// Shadow.
+    pub const SHADOW: u16 = 0x0010;
+    /// Condensed (narrow).
+    pub const CONDENSED: u16 = 0x0020;
+    /// Extended.
+    pub const EXTENDED: u16 = 0x0040;
+
+    /// Checks that `BOLD` flag is set.
+    #[inline]
+    pub fn bold(self) -> bool {
+        self.0 & Self::BOLD != 0
+    }
+
+    /// Checks that `ITALIC` flag is set.
+    #[inline]
+    pub fn italic(self) -> bool {
+        self.0 & Self::ITALIC != 0
+    }
+
+    /// Checks that `UNDERLINE` flag is set.
+    #[inline]
+    pub fn underline(self) -> bool {
+        self.0 & Self::UNDERLINE != 0
+    }
+
+    /// Checks that `OUTLINE` flag is set.
+    #[inline]
+    pub fn outline(self) -> bool {
+        self.0 & Self::OUTLINE != 0
+    }
+
+    /// Checks that `SHADOW` flag is set.
+    #[inline]
+    pub fn shadow(self) -> bool {
+        self.0 & Self::SHADOW != 0
+    }
+
+    /// Checks that `CONDENSED` flag is set.
+    #[inline]
+    pub fn condensed(self) -> bool {
+        self.0 & Self::CONDENSED != 0
+    }
+
+    /// Checks that `EXTENDED` flag is set.
+    #[inline]
+    pub fn extended(self) -> bool {
+        self.0 & Self::EXTENDED != 0
+    }
+}
+
+/// A calendar date and time in UTC.
+///
+/// Used by [`Face::created_time`](crate::Face::created_time)
+/// and [`Face::modified_time`](crate::Face::modified_time).
+#[cfg(feature = "std")]
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct DateTime {
+    pub year: i32,
+    /// In a 1..=12 range.
+    pub month: u8,
+    /// In a 1..=31 range.
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+#[cfg(feature = "std")]
+impl DateTime {
+    /// Converts a `LONGDATETIME` value, i.e. seconds since 12:00 midnight, January 1, 1904,
+    /// into a calendar date.
+    ///
+    /// Returns `None` when the year doesn't fit into `i32`.
+    pub fn from_long_date_time(seconds: i64) -> Option<Self> {
+        let days = seconds.div_euclid(86400);
+        let time = seconds.rem_euclid(86400);
+
+        // Based on http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        // Shift the epoch from 1904-01-01 to 0000-03-01.
+        let z = days.checked_add(DAYS_FROM_CIVIL_EPOCH)?;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097); // [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+        let mp = (5 * doy + 2) / 153; // [0, 11]
+        let day = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+        let month = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+        let year = era
+            .checked_mul(400)?
+            .checked_add(yoe + i64::from(month <= 2))?;
+
+        Some(DateTime {
+            year: core::convert::TryFrom::try_from(year).ok()?,
+            month: month as u8,
+            day: day as u8,
+            hour: (time / 3600) as u8,
+            minute: (time % 3600 / 60) as u8,
+            second: (time % 60) as u8,
+        })
+    }
+}
+
+// The number of days between 0000-03-01 and 1904-01-01.
+#[cfg(feature = "std")]
+const DAYS_FROM_CIVIL_EPOCH: i64 = 695361;
+
+// The number of seconds between 1904-01-01 and 1970-01-01.
+#[cfg(feature = "std")]
+const SECONDS_FROM_UNIX_EPOCH: i64 = 2082844800;
+
+#[cfg(feature = "std")]
+fn to_system_time(seconds: i64) -> Option<std::time::SystemTime> {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let seconds = seconds.checked_sub(SECONDS_FROM_UNIX_EPOCH)?;
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.wrapping_neg() as u64))
+    }
+}
+
 /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
 #[derive(Clone, Copy, Debug)]
 pub struct Table {
+    /// A whole font checksum adjustment.
+    ///
+    /// See [`RawFace::validate_checksums`](crate::RawFace::validate_checksums).
+    pub checksum_adjustment: u32,
+    /// Raw [flags](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
+    pub flags: u16,
     /// Units per EM.
     ///
     /// Guarantee to be in a 16..=16384 range.
     pub units_per_em: u16,
+    /// Creation time, in seconds since 12:00 midnight, January 1, 1904, UTC.
+    pub created: i64,
+    /// Modification time, in seconds since 12:00 midnight, January 1, 1904, UTC.
+    pub modified: i64,
     /// A bounding box that large enough to enclose any glyph from the face.
     pub global_bbox: Rect,
+    /// A font style used by macOS.
+    pub mac_style: MacStyle,
+    /// Smallest readable size in pixels.
+    pub lowest_rec_ppem: u16,
     /// An index format used by the [Index to Location Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/loca).
     pub index_to_location_format: IndexToLocationFormat,
+    /// A [Glyph Data Table](https://docs.microsoft.com/en-us/typography/opentype/spec/glyf)
+    /// format.
+    ///
+    /// Only 0 is defined by the spec.
+    pub glyph_data_format: i16,
 }
 
 impl Table {
@@ -39,20 +194,21 @@ impl Table {
         let mut s = Stream::new(data);
         s.skip::<u32>(); // version
         s.skip::<Fixed>(); // font revision
-        s.skip::<u32>(); // checksum adjustment
+        let checksum_adjustment = s.read::<u32>()?;
         s.skip::<u32>(); // magic number
-        s.skip::<u16>(); // flags
+        let flags = s.read::<u16>()?;
         let units_per_em = s.read::<u16>()?;
-        s.skip::<u64>(); // created time
-        s.skip::<u64>(); // modified time
+        let created = s.read::<i64>()?;
+        let modified = s.read::<i64>()?;
         let x_min = s.read::<i16>()?;
         let y_min = s.read::<i16>()?;
         let x_max = s.read::<i16>()?;
         let y_max = s.read::<i16>()?;
-        s.skip::<u16>(); // mac style
-        s.skip::<u16>(); // lowest PPEM
+        let mac_style = s.read::<u16>()?;
+        let lowest_rec_ppem = s.read::<u16>()?;
         s.skip::<i16>(); // font direction hint
         let index_to_location_format = s.read::<u16>()?;
+        let glyph_data_format = s.read::<i16>()?;
 
         if !(16..=16384).contains(&units_per_em) {
             return None;
@@ -65,14 +221,39 @@ impl Table {
         };
 
         Some(Table {
+            checksum_adjustment,
+            flags,
             units_per_em,
+            created,
+            modified,
             global_bbox: Rect {
                 x_min,
                 y_min,
                 x_max,
                 y_max,
             },
+            mac_style: MacStyle(mac_style),
+            lowest_rec_ppem,
             index_to_location_format,
+            glyph_data_format,
         })
     }
+
+    /// Returns the creation time as a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// Returns `None` when the time cannot be represented by the platform.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn created_system_time(&self) -> Option<std::time::SystemTime> {
+        to_system_time(self.created)
+    }
+
+    /// Returns the modification time as a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// Returns `None` when the time cannot be represented by the platform.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn modified_system_time(&self) -> Option<std::time::SystemTime> {
+        to_system_time(self.modified)
+    }
 }
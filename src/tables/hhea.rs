@@ -12,6 +12,12 @@ pub struct Table {
     pub descender: i16,
     /// Face line gap.
     pub line_gap: i16,
+    /// Caret slope rise.
+    pub caret_slope_rise: i16,
+    /// Caret slope run.
+    pub caret_slope_run: i16,
+    /// Caret offset.
+    pub caret_offset: i16,
     /// Number of metrics in the `hmtx` table.
     pub number_of_metrics: u16,
 }
@@ -30,13 +36,20 @@ impl Table {
         let ascender = s.read::<i16>()?;
         let descender = s.read::<i16>()?;
         let line_gap = s.read::<i16>()?;
-        s.advance(24);
+        s.advance(8);
+        let caret_slope_rise = s.read::<i16>()?;
+        let caret_slope_run = s.read::<i16>()?;
+        let caret_offset = s.read::<i16>()?;
+        s.advance(10);
         let number_of_metrics = s.read::<u16>()?;
 
         Some(Table {
             ascender,
             descender,
             line_gap,
+            caret_slope_rise,
+            caret_slope_run,
+            caret_offset,
             number_of_metrics,
         })
     }
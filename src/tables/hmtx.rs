@@ -56,6 +56,10 @@ impl<'a> Table<'a> {
             return None;
         }
 
+        // Malformed fonts can have more metrics than glyphs.
+        // In which case we should ignore the extra ones instead of reading past the table.
+        number_of_metrics = number_of_metrics.min(number_of_glyphs.get());
+
         let mut s = Stream::new(data);
         let metrics = s.read_array16::<Metrics>(number_of_metrics)?;
 
@@ -111,4 +115,20 @@ impl<'a> Table<'a> {
                 .get(glyph_id.0.checked_sub(self.metrics.len())?)
         }
     }
+
+    /// Returns an iterator over metrics of all glyphs.
+    ///
+    /// Unlike the `metrics` array, yields a value per glyph, including the ones
+    /// that share the last advance and have only a side bearing.
+    /// Side bearings missing in malformed fonts are set to zero.
+    pub fn glyph_metrics(&self) -> impl Iterator<Item = Metrics> + 'a {
+        let table = *self;
+        (0..self.number_of_metrics).filter_map(move |id| {
+            let glyph_id = GlyphId(id);
+            Some(Metrics {
+                advance: table.advance(glyph_id)?,
+                side_bearing: table.side_bearing(glyph_id).unwrap_or(0),
+            })
+        })
+    }
 }
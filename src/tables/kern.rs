@@ -25,7 +25,9 @@ struct OTCoverage(u8);
 #[rustfmt::skip]
 impl OTCoverage {
     #[inline] fn is_horizontal(self) -> bool { self.0 & (1 << 0) != 0 }
+    #[inline] fn has_minimum(self) -> bool { self.0 & (1 << 1) != 0 }
     #[inline] fn has_cross_stream(self) -> bool { self.0 & (1 << 2) != 0 }
+    #[inline] fn has_override(self) -> bool { self.0 & (1 << 3) != 0 }
 }
 
 impl FromData for OTCoverage {
@@ -117,6 +119,15 @@ pub struct Subtable<'a> {
     pub variable: bool,
     /// Indicates that subtable has a cross-stream values.
     pub has_cross_stream: bool,
+    /// Indicates that subtable has minimum values instead of kerning ones.
+    ///
+    /// OpenType only.
+    pub has_minimum: bool,
+    /// Indicates that subtable values must replace the accumulated ones
+    /// instead of being added to them.
+    ///
+    /// OpenType only.
+    pub has_override: bool,
     /// Indicates that subtable uses a state machine.
     ///
     /// In this case `glyphs_kerning()` will return `None`.
@@ -245,6 +256,8 @@ impl<'a> Iterator for SubtablesIter<'a> {
                 horizontal: coverage.is_horizontal(),
                 variable: coverage.is_variable(),
                 has_cross_stream: coverage.has_cross_stream(),
+                has_minimum: false,
+                has_override: false,
                 has_state_machine: format_id == 1,
                 format,
             })
@@ -285,6 +298,8 @@ impl<'a> Iterator for SubtablesIter<'a> {
                 horizontal: coverage.is_horizontal(),
                 variable: false, // Only AAT supports it.
                 has_cross_stream: coverage.has_cross_stream(),
+                has_minimum: coverage.has_minimum(),
+                has_override: coverage.has_override(),
                 has_state_machine: format_id == 1,
                 format,
             })
@@ -319,6 +334,49 @@ impl<'a> Subtable0<'a> {
             .binary_search_by(|v| v.pair.cmp(&needle))
             .map(|(_, v)| v.value)
     }
+
+    /// Returns an iterator over all kerning pairs as `(left, right, value)`.
+    ///
+    /// Pairs are returned in the file order.
+    pub fn glyph_pairs(&self) -> impl Iterator<Item = (GlyphId, GlyphId, i16)> + 'a {
+        self.pairs
+            .into_iter()
+            .map(|pair| (pair.left(), pair.right(), pair.value))
+    }
+}
+
+/// A class table used by [`Subtable2`].
+#[derive(Clone, Copy, Debug)]
+pub struct ClassTable<'a> {
+    /// The first glyph in the class range.
+    pub first_glyph: GlyphId,
+    /// Class values.
+    ///
+    /// Values are byte offsets from the start of the subtable,
+    /// already multiplied by the row width for the left-hand classes.
+    pub classes: LazyArray16<'a, u16>,
+}
+
+impl<'a> ClassTable<'a> {
+    pub(crate) fn parse(data: &'a [u8], offset: usize) -> Option<Self> {
+        let mut s = Stream::new_at(data, offset)?;
+        let first_glyph = s.read::<GlyphId>()?;
+        let number_of_classes = s.read::<u16>()?;
+        let classes = s.read_array16::<u16>(number_of_classes)?;
+        Some(ClassTable {
+            first_glyph,
+            classes,
+        })
+    }
+
+    /// Returns a glyph class.
+    ///
+    /// Returns `None` when glyph is not in the class range.
+    #[inline]
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        self.classes
+            .get(glyph_id.0.checked_sub(self.first_glyph.0)?)
+    }
 }
 
 /// A format 2 subtable.
@@ -326,59 +384,75 @@ impl<'a> Subtable0<'a> {
 /// Simple n x m Array of Kerning Values.
 #[derive(Clone, Copy, Debug)]
 pub struct Subtable2<'a> {
-    // TODO: parse actual structure
+    /// The width, in bytes, of a row in the kerning values array.
+    pub row_width: u16,
+    /// Left-hand classes.
+    ///
+    /// `None` when the class table is malformed.
+    pub left_hand_classes: Option<ClassTable<'a>>,
+    /// Right-hand classes.
+    ///
+    /// `None` when the class table is malformed.
+    pub right_hand_classes: Option<ClassTable<'a>>,
     data: &'a [u8],
     header_len: u8,
+    array_offset: u16,
 }
 
 impl<'a> Subtable2<'a> {
     /// Parses a subtable from raw data.
     pub fn parse(header_len: u8, data: &'a [u8]) -> Option<Self> {
-        Some(Self { header_len, data })
-    }
-
-    /// Returns kerning for a pair of glyphs.
-    pub fn glyphs_kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
-        let mut s = Stream::new(self.data);
-        s.skip::<u16>(); // row_width
+        let mut s = Stream::new(data);
+        let row_width = s.read::<u16>()?;
 
         // Offsets are from beginning of the subtable and not from the `data` start,
         // so we have to subtract the header.
-        let header_len = usize::from(self.header_len);
-        let left_hand_table_offset = s.read::<Offset16>()?.to_usize().checked_sub(header_len)?;
-        let right_hand_table_offset = s.read::<Offset16>()?.to_usize().checked_sub(header_len)?;
-        let array_offset = s.read::<Offset16>()?.to_usize().checked_sub(header_len)?;
+        let class_table = |offset: Offset16| {
+            let offset = offset.to_usize().checked_sub(usize::from(header_len))?;
+            ClassTable::parse(data, offset)
+        };
 
+        let left_hand_classes = class_table(s.read::<Offset16>()?);
+        let right_hand_classes = class_table(s.read::<Offset16>()?);
+        let array_offset = s.read::<Offset16>()?;
+        Some(Self {
+            row_width,
+            left_hand_classes,
+            right_hand_classes,
+            data,
+            header_len,
+            array_offset: array_offset.0,
+        })
+    }
+
+    /// Returns kerning for a pair of glyphs.
+    pub fn glyphs_kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
         // 'The array can be indexed by completing the left-hand and right-hand class mappings,
         // adding the class values to the address of the subtable,
         // and fetching the kerning value to which the new address points.'
 
-        let left_class = get_format2_class(left.0, left_hand_table_offset, self.data).unwrap_or(0);
-        let right_class =
-            get_format2_class(right.0, right_hand_table_offset, self.data).unwrap_or(0);
+        // Glyphs outside of the class range and malformed class tables are using the class 0.
+        let left_class = self
+            .left_hand_classes
+            .and_then(|table| table.get(left))
+            .unwrap_or(0);
+        let right_class = self
+            .right_hand_classes
+            .and_then(|table| table.get(right))
+            .unwrap_or(0);
 
         // 'Values within the left-hand offset table should not be less than the kerning array offset.'
-        if usize::from(left_class) < array_offset {
+        if left_class < self.array_offset {
             return None;
         }
 
         // Classes are already premultiplied, so we only need to sum them.
         let index = usize::from(left_class) + usize::from(right_class);
-        let value_offset = index.checked_sub(header_len)?;
+        let value_offset = index.checked_sub(usize::from(self.header_len))?;
         Stream::read_at::<i16>(self.data, value_offset)
     }
 }
 
-pub(crate) fn get_format2_class(glyph_id: u16, offset: usize, data: &[u8]) -> Option<u16> {
-    let mut s = Stream::new_at(data, offset)?;
-    let first_glyph = s.read::<u16>()?;
-    let index = glyph_id.checked_sub(first_glyph)?;
-
-    let number_of_classes = s.read::<u16>()?;
-    let classes = s.read_array16::<u16>(number_of_classes)?;
-    classes.get(index)
-}
-
 /// A format 3 subtable.
 ///
 /// Simple n x m Array of Kerning Indices.
@@ -1,15 +1,11 @@
 //! An [Extended Kerning Table](
 //! https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kerx.html) implementation.
 
-// TODO: find a way to test this table
-// This table is basically untested because it uses Apple's State Tables
-// and I have no idea how to generate them.
-
 use core::num::NonZeroU16;
 
 use crate::kern::KerningPair;
 use crate::parser::{FromData, LazyArray32, NumFrom, Offset, Offset32, Stream};
-use crate::{aat, GlyphId};
+use crate::{aat, ankr, GlyphId};
 
 const HEADER_SIZE: usize = 12;
 
@@ -136,10 +132,12 @@ impl<'a> Subtable2<'a> {
         // adding the class values to the address of the subtable,
         // and fetching the kerning value to which the new address points.'
 
-        let left_class =
-            crate::kern::get_format2_class(left.0, left_hand_table_offset, self.0).unwrap_or(0);
-        let right_class =
-            crate::kern::get_format2_class(right.0, right_hand_table_offset, self.0).unwrap_or(0);
+        let left_class = crate::kern::ClassTable::parse(self.0, left_hand_table_offset)
+            .and_then(|table| table.get(left))
+            .unwrap_or(0);
+        let right_class = crate::kern::ClassTable::parse(self.0, right_hand_table_offset)
+            .and_then(|table| table.get(right))
+            .unwrap_or(0);
 
         // 'Values within the left-hand offset table should not be less than the kerning array offset.'
         if usize::from(left_class) < array_offset {
@@ -313,6 +311,21 @@ pub enum Format<'a> {
     Format6(Subtable6<'a>),
 }
 
+/// A glyph position.
+///
+/// Used by [`Subtable::apply`]. All values are in font units.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct Position {
+    /// A horizontal advance.
+    pub x_advance: i32,
+    /// A vertical advance.
+    pub y_advance: i32,
+    /// A horizontal offset.
+    pub x_offset: i32,
+    /// A vertical offset.
+    pub y_offset: i32,
+}
+
 /// A kerning subtable.
 #[derive(Clone, Debug)]
 pub struct Subtable<'a> {
@@ -348,6 +361,70 @@ impl<'a> Subtable<'a> {
             Format::Format6(ref subtable) => subtable.glyphs_kerning(left, right),
         }
     }
+
+    /// Applies a subtable to a glyph run.
+    ///
+    /// Kerning values are added to `positions`, which must have the same length as `glyphs`.
+    /// Horizontal subtables adjust horizontal advances and vertical offsets (cross-stream)
+    /// and vertical subtables adjust vertical advances and horizontal offsets.
+    ///
+    /// Anchor points used by format 4 subtables are positioned relative to the marked glyph,
+    /// therefore `positions` advances must be initialized with glyph advances beforehand.
+    /// Format 4 subtables are ignored when `ankr` is not set.
+    ///
+    /// The number of state machine steps is limited,
+    /// therefore a malformed table cannot loop forever.
+    pub fn apply(
+        &self,
+        glyphs: &[GlyphId],
+        positions: &mut [Position],
+        ankr: Option<&ankr::Table>,
+    ) {
+        let len = core::cmp::min(glyphs.len(), positions.len());
+        let (glyphs, positions) = (&glyphs[..len], &mut positions[..len]);
+
+        match self.format {
+            Format::Format1(ref table) => {
+                let mut ctx = Format1Ctx {
+                    subtable: self,
+                    table,
+                    stack: [0; MAX_STACK_DEPTH],
+                    depth: 0,
+                };
+                drive(table, glyphs, positions, &mut ctx);
+            }
+            Format::Format4(ref table) => {
+                if let Some(ankr) = ankr {
+                    let mut ctx = Format4Ctx {
+                        subtable: self,
+                        table,
+                        ankr,
+                        mark: None,
+                    };
+                    drive(table, glyphs, positions, &mut ctx);
+                }
+            }
+            _ => {
+                for i in 1..len {
+                    if let Some(value) = self.glyphs_kerning(glyphs[i - 1], glyphs[i]) {
+                        self.adjust(positions, i - 1, i, i32::from(value));
+                    }
+                }
+            }
+        }
+    }
+
+    // Applies a kerning value to a pair of glyphs.
+    // Cross-stream kerning moves the second glyph,
+    // while regular kerning changes the first glyph advance.
+    fn adjust(&self, positions: &mut [Position], first: usize, second: usize, value: i32) {
+        match (self.horizontal, self.has_cross_stream) {
+            (true, false) => positions[first].x_advance += value,
+            (true, true) => positions[second].y_offset += value,
+            (false, false) => positions[first].y_advance += value,
+            (false, true) => positions[second].x_offset += value,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -482,4 +559,239 @@ impl<'a> Table<'a> {
 
         Some(Table { subtables })
     }
+
+    /// Applies all horizontal subtables to a glyph run.
+    ///
+    /// See [`Subtable::apply`] for details.
+    pub fn apply(
+        &self,
+        glyphs: &[GlyphId],
+        positions: &mut [Position],
+        ankr: Option<&ankr::Table>,
+    ) {
+        for subtable in self.subtables {
+            if subtable.horizontal {
+                subtable.apply(glyphs, positions, ankr);
+            }
+        }
+    }
+}
+
+// The code below is based on harfbuzz implementation.
+
+const MAX_STACK_DEPTH: usize = 8;
+const MAX_OPS_FACTOR: usize = 64;
+const MIN_MAX_OPS: usize = 16384;
+
+trait StateMachineCtx {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<EntryData>,
+        glyphs: &[GlyphId],
+        positions: &mut [Position],
+        idx: usize,
+    ) -> Option<()>;
+}
+
+fn drive(
+    machine: &aat::ExtendedStateTable<EntryData>,
+    glyphs: &[GlyphId],
+    positions: &mut [Position],
+    ctx: &mut impl StateMachineCtx,
+) {
+    // `DontAdvance` flag can loop us forever on a malformed table.
+    let mut max_ops = core::cmp::max(glyphs.len().saturating_mul(MAX_OPS_FACTOR), MIN_MAX_OPS);
+
+    let mut state = aat::state::START_OF_TEXT;
+    let mut idx = 0;
+    loop {
+        let class = match glyphs.get(idx) {
+            Some(glyph) => machine
+                .class(*glyph)
+                .unwrap_or(u16::from(aat::class::OUT_OF_BOUNDS)),
+            None => u16::from(aat::class::END_OF_TEXT),
+        };
+
+        let entry = match machine.entry(state, class) {
+            Some(v) => v,
+            None => break,
+        };
+
+        if ctx.transition(&entry, glyphs, positions, idx).is_none() {
+            break;
+        }
+
+        state = entry.new_state;
+
+        if idx >= glyphs.len() {
+            break;
+        }
+
+        if entry.has_advance() || max_ops == 0 {
+            idx += 1;
+        } else {
+            max_ops -= 1;
+        }
+    }
+}
+
+struct Format1Ctx<'a, 'b> {
+    subtable: &'b Subtable<'a>,
+    table: &'b Subtable1<'a>,
+    stack: [usize; MAX_STACK_DEPTH],
+    depth: usize,
+}
+
+impl StateMachineCtx for Format1Ctx<'_, '_> {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<EntryData>,
+        glyphs: &[GlyphId],
+        positions: &mut [Position],
+        idx: usize,
+    ) -> Option<()> {
+        use core::convert::TryFrom;
+
+        if entry.has_reset() {
+            self.depth = 0;
+        }
+
+        if entry.has_push() {
+            if self.depth < MAX_STACK_DEPTH {
+                self.stack[self.depth] = idx;
+                self.depth += 1;
+            } else {
+                // Stack overflow. Clear the stack.
+                self.depth = 0;
+            }
+        }
+
+        if entry.extra.action_index == 0xFFFF || self.depth == 0 {
+            return Some(());
+        }
+
+        // Each action has a value per tuple, but we're using only the default one.
+        let tuple_count = u16::try_from(self.subtable.tuple_count).unwrap_or(0xFFFF);
+        let tuple_count = core::cmp::max(1, tuple_count);
+        let mut action_index = entry.extra.action_index;
+        while self.depth != 0 {
+            self.depth -= 1;
+            let position = self.stack[self.depth];
+
+            let value = match self.table.glyphs_kerning(action_index) {
+                Some(v) => v,
+                None => {
+                    self.depth = 0;
+                    break;
+                }
+            };
+            action_index = action_index.saturating_add(tuple_count);
+
+            if position >= glyphs.len() {
+                continue;
+            }
+
+            // The end of the list is marked by an odd value.
+            let is_last = value & 1 != 0;
+            let value = i32::from(value & !1);
+
+            // Like `kern`, cross-stream kerning with this value resets the offset.
+            if self.subtable.has_cross_stream && value == -0x8000 {
+                let position = &mut positions[position];
+                if self.subtable.horizontal {
+                    position.y_offset = 0;
+                } else {
+                    position.x_offset = 0;
+                }
+            } else if self.subtable.has_cross_stream {
+                self.subtable.adjust(positions, position, position, value);
+            } else {
+                // Like HarfBuzz, moves the glyph along with changing its advance.
+                let position = &mut positions[position];
+                if self.subtable.horizontal {
+                    position.x_advance += value;
+                    position.x_offset += value;
+                } else {
+                    position.y_advance += value;
+                    position.y_offset += value;
+                }
+            }
+
+            if is_last {
+                break;
+            }
+        }
+
+        Some(())
+    }
+}
+
+struct Format4Ctx<'a, 'b> {
+    subtable: &'b Subtable<'a>,
+    table: &'b Subtable4<'a>,
+    ankr: &'b ankr::Table<'a>,
+    mark: Option<usize>,
+}
+
+impl Format4Ctx<'_, '_> {
+    // Returns the current glyph offset that aligns its anchor with the marked glyph one.
+    fn attachment(
+        &self,
+        entry: &aat::GenericStateEntry<EntryData>,
+        glyphs: &[GlyphId],
+        positions: &[Position],
+        mark: usize,
+        idx: usize,
+    ) -> Option<(i32, i32)> {
+        let (mark_point, current_point) = self.table.anchor_points.get(entry.extra.action_index)?;
+        let mark_anchor = self
+            .ankr
+            .points(*glyphs.get(mark)?)?
+            .get(u32::from(mark_point))?;
+        let current_anchor = self
+            .ankr
+            .points(*glyphs.get(idx)?)?
+            .get(u32::from(current_point))?;
+
+        // Anchors are relative to glyphs origin, so we have to take
+        // advances between the marked glyph and the current one into account.
+        let mark_position = positions.get(mark)?;
+        let mut x = mark_position.x_offset + i32::from(mark_anchor.x) - i32::from(current_anchor.x);
+        let mut y = mark_position.y_offset + i32::from(mark_anchor.y) - i32::from(current_anchor.y);
+        for position in positions.get(mark..idx)? {
+            if self.subtable.horizontal {
+                x -= position.x_advance;
+            } else {
+                y -= position.y_advance;
+            }
+        }
+
+        Some((x, y))
+    }
+}
+
+impl StateMachineCtx for Format4Ctx<'_, '_> {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<EntryData>,
+        glyphs: &[GlyphId],
+        positions: &mut [Position],
+        idx: usize,
+    ) -> Option<()> {
+        if let Some(mark) = self.mark {
+            if entry.extra.action_index != 0xFFFF && idx < glyphs.len() {
+                // Actions with missing anchor points are skipped.
+                if let Some((x, y)) = self.attachment(entry, glyphs, positions, mark, idx) {
+                    positions[idx].x_offset = x;
+                    positions[idx].y_offset = y;
+                }
+            }
+        }
+
+        if entry.has_mark() {
+            self.mark = Some(idx);
+        }
+
+        Some(())
+    }
 }
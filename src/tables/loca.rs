@@ -71,7 +71,26 @@ impl<'a> Table<'a> {
         self.len() == 0
     }
 
+    /// Returns an iterator over byte offsets into the `glyf` table.
+    ///
+    /// Short offsets are doubled, as required by the spec.
+    #[inline]
+    pub fn offsets(&self) -> impl Iterator<Item = u32> + 'a {
+        let (short, long) = match *self {
+            Table::Short(array) => (Some(array), None),
+            Table::Long(array) => (None, Some(array)),
+        };
+
+        // 'The actual local offset divided by 2 is stored.'
+        let short = short.into_iter().flatten().map(|n| u32::from(n) * 2);
+        short.chain(long.into_iter().flatten())
+    }
+
     /// Returns glyph's range in the `glyf` table.
+    ///
+    /// Empty glyphs, like space, have an empty range.
+    ///
+    /// Returns `None` when glyph ID is out of bounds or offsets are not in ascending order.
     #[inline]
     pub fn glyph_range(&self, glyph_id: GlyphId) -> Option<Range<usize>> {
         let glyph_id = glyph_id.0;
@@ -94,9 +113,8 @@ impl<'a> Table<'a> {
             }
         };
 
-        if range.start >= range.end {
+        if range.start > range.end {
             // 'The offsets must be in ascending order.'
-            // And range cannot be empty.
             None
         } else {
             Some(range)
@@ -924,4 +924,74 @@ impl<'a> Table<'a> {
             variants: s.parse_at_offset16::<Variants>(data),
         })
     }
+
+    /// Returns glyph's italics correction.
+    ///
+    /// Returns `None` when glyph is not covered.
+    #[inline]
+    pub fn italics_correction(&self, glyph_id: GlyphId) -> Option<MathValue<'a>> {
+        self.glyph_info?.italic_corrections?.get(glyph_id)
+    }
+
+    /// Returns glyph's horizontal position for attaching mathematical accents.
+    ///
+    /// Returns `None` when glyph is not covered.
+    #[inline]
+    pub fn top_accent_attachment(&self, glyph_id: GlyphId) -> Option<MathValue<'a>> {
+        self.glyph_info?.top_accent_attachments?.get(glyph_id)
+    }
+
+    /// Returns minimum overlap of connecting glyphs during glyph construction, in design units.
+    #[inline]
+    pub fn min_connector_overlap(&self) -> Option<u16> {
+        self.variants.map(|v| v.min_connector_overlap)
+    }
+
+    /// Returns prepared vertical variants of the glyph.
+    ///
+    /// Returns `None` when glyph is not covered.
+    #[inline]
+    pub fn vertical_variants(&self, glyph_id: GlyphId) -> Option<LazyArray16<'a, GlyphVariant>> {
+        Some(
+            self.variants?
+                .vertical_constructions
+                .get(glyph_id)?
+                .variants,
+        )
+    }
+
+    /// Returns prepared horizontal variants of the glyph.
+    ///
+    /// Returns `None` when glyph is not covered.
+    #[inline]
+    pub fn horizontal_variants(&self, glyph_id: GlyphId) -> Option<LazyArray16<'a, GlyphVariant>> {
+        Some(
+            self.variants?
+                .horizontal_constructions
+                .get(glyph_id)?
+                .variants,
+        )
+    }
+
+    /// Returns a recipe on how to construct a glyph with large height.
+    ///
+    /// Returns `None` when glyph is not covered or has no assembly.
+    #[inline]
+    pub fn vertical_assembly(&self, glyph_id: GlyphId) -> Option<GlyphAssembly<'a>> {
+        self.variants?
+            .vertical_constructions
+            .get(glyph_id)?
+            .assembly
+    }
+
+    /// Returns a recipe on how to construct a glyph with large width.
+    ///
+    /// Returns `None` when glyph is not covered or has no assembly.
+    #[inline]
+    pub fn horizontal_assembly(&self, glyph_id: GlyphId) -> Option<GlyphAssembly<'a>> {
+        self.variants?
+            .horizontal_constructions
+            .get(glyph_id)?
+            .assembly
+    }
 }
@@ -6,10 +6,44 @@ use core::num::NonZeroU16;
 use crate::parser::Stream;
 
 /// A [Maximum Profile Table](https://docs.microsoft.com/en-us/typography/opentype/spec/maxp).
+///
+/// All fields except `number_of_glyphs` are present only in the version 1.0 table,
+/// which is used by TrueType fonts. They will be `None` for CFF fonts
+/// and for truncated tables.
 #[derive(Clone, Copy, Debug)]
 pub struct Table {
     /// The total number of glyphs in the face.
+    ///
+    /// Set to 1 when the table declares zero glyphs.
+    /// Use [`declared_number_of_glyphs`](Self::declared_number_of_glyphs) to get the raw value.
     pub number_of_glyphs: NonZeroU16,
+    /// Maximum points in a non-composite glyph.
+    pub max_points: Option<u16>,
+    /// Maximum contours in a non-composite glyph.
+    pub max_contours: Option<u16>,
+    /// Maximum points in a composite glyph.
+    pub max_composite_points: Option<u16>,
+    /// Maximum contours in a composite glyph.
+    pub max_composite_contours: Option<u16>,
+    /// 1 if instructions do not use the twilight zone (Z0), or 2 if instructions do use Z0.
+    pub max_zones: Option<u16>,
+    /// Maximum points used in Z0.
+    pub max_twilight_points: Option<u16>,
+    /// Number of Storage Area locations.
+    pub max_storage: Option<u16>,
+    /// Number of FDEFs.
+    pub max_function_defs: Option<u16>,
+    /// Number of IDEFs.
+    pub max_instruction_defs: Option<u16>,
+    /// Maximum stack depth across Font Program, CVT Program and all glyph instructions.
+    pub max_stack_elements: Option<u16>,
+    /// Maximum byte count for glyph instructions.
+    pub max_size_of_instructions: Option<u16>,
+    /// Maximum number of components referenced at "top level" for any composite glyph.
+    pub max_component_elements: Option<u16>,
+    /// Maximum levels of recursion; 1 for simple components.
+    pub max_component_depth: Option<u16>,
+    declared_number_of_glyphs: u16,
 }
 
 impl Table {
@@ -21,8 +55,58 @@ impl Table {
             return None;
         }
 
-        let n = s.read::<u16>()?;
-        let number_of_glyphs = NonZeroU16::new(n)?;
-        Some(Table { number_of_glyphs })
+        let declared_number_of_glyphs = s.read::<u16>()?;
+        // A face must have at least one glyph: `.notdef`.
+        let number_of_glyphs = NonZeroU16::new(declared_number_of_glyphs)
+            .unwrap_or_else(|| NonZeroU16::new(1).unwrap());
+
+        let mut table = Table {
+            number_of_glyphs,
+            max_points: None,
+            max_contours: None,
+            max_composite_points: None,
+            max_composite_contours: None,
+            max_zones: None,
+            max_twilight_points: None,
+            max_storage: None,
+            max_function_defs: None,
+            max_instruction_defs: None,
+            max_stack_elements: None,
+            max_size_of_instructions: None,
+            max_component_elements: None,
+            max_component_depth: None,
+            declared_number_of_glyphs,
+        };
+
+        // Some fonts have a truncated table, which is fine,
+        // since we need only the number of glyphs in most cases.
+        // In which case all the version 1.0 fields are ignored.
+        const V1_FIELDS_LEN: usize = 26;
+        if version == 0x00010000 && s.tail()?.len() >= V1_FIELDS_LEN {
+            table.max_points = s.read::<u16>();
+            table.max_contours = s.read::<u16>();
+            table.max_composite_points = s.read::<u16>();
+            table.max_composite_contours = s.read::<u16>();
+            table.max_zones = s.read::<u16>();
+            table.max_twilight_points = s.read::<u16>();
+            table.max_storage = s.read::<u16>();
+            table.max_function_defs = s.read::<u16>();
+            table.max_instruction_defs = s.read::<u16>();
+            table.max_stack_elements = s.read::<u16>();
+            table.max_size_of_instructions = s.read::<u16>();
+            table.max_component_elements = s.read::<u16>();
+            table.max_component_depth = s.read::<u16>();
+        }
+
+        Some(table)
+    }
+
+    /// Returns the number of glyphs as declared in the table.
+    ///
+    /// Unlike [`number_of_glyphs`](Self::number_of_glyphs), can be zero.
+    /// Such faces are malformed and will be rejected by [`Face`](crate::Face).
+    #[inline]
+    pub fn declared_number_of_glyphs(&self) -> u16 {
+        self.declared_number_of_glyphs
     }
 }
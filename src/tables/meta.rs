@@ -0,0 +1,86 @@
+//! A [Metadata Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/meta) implementation.
+
+use crate::parser::{FromData, LazyArray32, NumFrom, Stream};
+use crate::Tag;
+
+#[derive(Clone, Copy)]
+struct DataMap {
+    tag: Tag,
+    offset: u32,
+    length: u32,
+}
+
+impl FromData for DataMap {
+    const SIZE: usize = 12;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(DataMap {
+            tag: s.read::<Tag>()?,
+            offset: s.read::<u32>()?,
+            length: s.read::<u32>()?,
+        })
+    }
+}
+
+/// A [Metadata Table](https://docs.microsoft.com/en-us/typography/opentype/spec/meta).
+#[derive(Clone, Copy)]
+pub struct Table<'a> {
+    data: &'a [u8],
+    data_maps: LazyArray32<'a, DataMap>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u32>()?;
+        if version != 1 {
+            return None;
+        }
+
+        s.skip::<u32>(); // flags
+        s.skip::<u32>(); // reserved
+        let count = s.read::<u32>()?;
+        let data_maps = s.read_array32::<DataMap>(count)?;
+        Some(Table { data, data_maps })
+    }
+
+    /// Returns raw metadata for the specified tag.
+    pub fn get(&self, tag: Tag) -> Option<&'a [u8]> {
+        let data_map = self.data_maps.into_iter().find(|map| map.tag == tag)?;
+        // Offsets are from the beginning of the table.
+        let start = usize::num_from(data_map.offset);
+        let end = start.checked_add(usize::num_from(data_map.length))?;
+        self.data.get(start..end)
+    }
+
+    /// Returns a list of metadata tags.
+    pub fn tags(&self) -> impl Iterator<Item = Tag> + 'a {
+        self.data_maps.into_iter().map(|map| map.tag)
+    }
+
+    /// Returns design languages.
+    ///
+    /// A comma-separated list of [BCP 47](https://tools.ietf.org/html/bcp47) language tags.
+    #[inline]
+    pub fn design_languages(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.get(Tag::from_bytes(b"dlng"))?).ok()
+    }
+
+    /// Returns supported languages.
+    ///
+    /// A comma-separated list of [BCP 47](https://tools.ietf.org/html/bcp47) language tags.
+    #[inline]
+    pub fn supported_languages(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.get(Tag::from_bytes(b"slng"))?).ok()
+    }
+}
+
+impl core::fmt::Debug for Table<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Table {{ ... }}")
+    }
+}
@@ -2,18 +2,23 @@ pub mod cbdt;
 pub mod cblc;
 mod cff;
 pub mod cmap;
+pub mod cpal;
+pub mod gasp;
 pub mod glyf;
+pub mod hdmx;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod kern;
 pub mod loca;
 pub mod maxp;
+pub mod meta;
 pub mod name;
 pub mod os2;
 pub mod post;
 pub mod sbix;
 pub mod svg;
+pub mod vdmx;
 pub mod vhea;
 pub mod vorg;
 
@@ -47,6 +52,8 @@ pub mod gvar;
 pub mod hvar;
 #[cfg(feature = "variable-fonts")]
 pub mod mvar;
+#[cfg(feature = "variable-fonts")]
+pub mod stat;
 
 pub use cff::cff1;
 #[cfg(feature = "variable-fonts")]
@@ -1,8 +1,8 @@
 //! An [Extended Glyph Metamorphosis Table](
 //! https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6morx.html) implementation.
 
-// Note: We do not have tests for this table because it has a very complicated structure.
-// Specifically, the State Machine Tables. I have no idea how to generate them.
+// Note: We have only basic, handcrafted tests for this table because it has a very complicated
+// structure. Specifically, the State Machine Tables. I have no idea how to generate them.
 // And all fonts that use this table are mainly Apple one, so we cannot use them for legal reasons.
 //
 // On the other hand, this table is tested indirectly by https://github.com/RazrFalcon/rustybuzz
@@ -10,6 +10,7 @@
 // Therefore after applying any changes to this table,
 // you have to check that all rustybuzz tests are still passing.
 
+use core::convert::TryFrom;
 use core::num::NonZeroU16;
 
 use crate::parser::{FromData, LazyArray32, NumFrom, Offset, Offset32, Stream};
@@ -467,4 +468,385 @@ impl<'a> Table<'a> {
     pub fn parse(number_of_glyphs: NonZeroU16, data: &'a [u8]) -> Option<Self> {
         Chains::parse(number_of_glyphs, data).map(|chains| Self { chains })
     }
+
+    /// Applies all chains to a glyph buffer.
+    ///
+    /// Only subtables enabled by chains' default flags are applied.
+    /// Subtables are applied in the chain order, assuming horizontal left-to-right text.
+    /// Insertion subtables are skipped, since we cannot grow the buffer.
+    ///
+    /// Glyphs deleted by ligature subtables are removed from the buffer.
+    /// Returns the number of glyphs left at the start of the buffer.
+    pub fn apply(&self, glyphs: &mut [GlyphId]) -> usize {
+        for chain in self.chains {
+            chain.apply(chain.default_flags, glyphs);
+        }
+
+        let mut len = 0;
+        for i in 0..glyphs.len() {
+            if glyphs[i] != DELETED_GLYPH {
+                glyphs[len] = glyphs[i];
+                len += 1;
+            }
+        }
+
+        len
+    }
+}
+
+impl<'a> Chain<'a> {
+    /// Applies chain subtables enabled by the specified feature flags to a glyph buffer.
+    ///
+    /// See [`Subtable::apply`] for details.
+    pub fn apply(&self, flags: u32, glyphs: &mut [GlyphId]) {
+        for subtable in self.subtables {
+            if subtable.feature_flags & flags == 0 {
+                continue;
+            }
+
+            if subtable.coverage.is_vertical() && !subtable.coverage.is_all_directions() {
+                continue;
+            }
+
+            subtable.apply(glyphs);
+        }
+    }
+}
+
+impl<'a> Subtable<'a> {
+    /// Applies a subtable to a glyph buffer.
+    ///
+    /// Unlike [`Table::apply`], glyphs deleted by a ligature subtable
+    /// are not removed from the buffer, but replaced with `0xFFFF` instead,
+    /// as required by the following subtables.
+    ///
+    /// Insertion subtables are not supported and will be ignored.
+    ///
+    /// The number of state machine steps is limited,
+    /// therefore a malformed table cannot loop forever.
+    pub fn apply(&self, glyphs: &mut [GlyphId]) {
+        let reverse = self.coverage.is_backwards();
+        if reverse {
+            glyphs.reverse();
+        }
+
+        match self.kind {
+            SubtableKind::Rearrangement(ref table) => {
+                let mut ctx = RearrangementCtx { start: 0, end: 0 };
+                drive(table, glyphs, &mut ctx);
+            }
+            SubtableKind::Contextual(ref table) => {
+                let mut ctx = ContextualCtx {
+                    table,
+                    mark_set: false,
+                    mark: 0,
+                };
+                drive(&table.state, glyphs, &mut ctx);
+            }
+            SubtableKind::Ligature(ref table) => {
+                let mut ctx = LigatureCtx {
+                    table,
+                    match_length: 0,
+                    match_positions: [0; MAX_CONTEXT_LENGTH],
+                };
+                drive(&table.state, glyphs, &mut ctx);
+            }
+            SubtableKind::NonContextual(ref lookup) => {
+                for glyph in glyphs.iter_mut() {
+                    if *glyph == DELETED_GLYPH {
+                        continue;
+                    }
+
+                    if let Some(id) = lookup.value(*glyph) {
+                        *glyph = GlyphId(id);
+                    }
+                }
+            }
+            SubtableKind::Insertion(_) => {}
+        }
+
+        if reverse {
+            glyphs.reverse();
+        }
+    }
+}
+
+// The code below is based on harfbuzz implementation.
+
+const DELETED_GLYPH: GlyphId = GlyphId(0xFFFF);
+const MAX_CONTEXT_LENGTH: usize = 64;
+const MAX_OPS_FACTOR: usize = 64;
+const MIN_MAX_OPS: usize = 16384;
+
+const DONT_ADVANCE: u16 = 0x4000;
+
+trait StateMachineCtx<T: FromData> {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<T>,
+        glyphs: &mut [GlyphId],
+        idx: usize,
+    ) -> Option<()>;
+}
+
+fn drive<T: FromData>(
+    machine: &aat::ExtendedStateTable<T>,
+    glyphs: &mut [GlyphId],
+    ctx: &mut impl StateMachineCtx<T>,
+) {
+    // `DontAdvance` flag can loop us forever on a malformed table.
+    let mut max_ops = core::cmp::max(glyphs.len().saturating_mul(MAX_OPS_FACTOR), MIN_MAX_OPS);
+
+    let mut state = aat::state::START_OF_TEXT;
+    let mut idx = 0;
+    loop {
+        let class = match glyphs.get(idx) {
+            Some(glyph) => machine
+                .class(*glyph)
+                .unwrap_or(u16::from(aat::class::OUT_OF_BOUNDS)),
+            None => u16::from(aat::class::END_OF_TEXT),
+        };
+
+        let entry = match machine.entry(state, class) {
+            Some(v) => v,
+            None => break,
+        };
+
+        if ctx.transition(&entry, glyphs, idx).is_none() {
+            break;
+        }
+
+        state = entry.new_state;
+
+        if idx >= glyphs.len() {
+            break;
+        }
+
+        if entry.flags & DONT_ADVANCE == 0 || max_ops == 0 {
+            idx += 1;
+        } else {
+            max_ops -= 1;
+        }
+    }
+}
+
+struct RearrangementCtx {
+    start: usize,
+    end: usize,
+}
+
+impl StateMachineCtx<()> for RearrangementCtx {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<()>,
+        glyphs: &mut [GlyphId],
+        idx: usize,
+    ) -> Option<()> {
+        const MARK_FIRST: u16 = 0x8000;
+        const MARK_LAST: u16 = 0x2000;
+        const VERB: u16 = 0x000F;
+
+        let flags = entry.flags;
+
+        if flags & MARK_FIRST != 0 {
+            self.start = idx;
+        }
+
+        if flags & MARK_LAST != 0 {
+            self.end = core::cmp::min(idx + 1, glyphs.len());
+        }
+
+        if flags & VERB != 0 && self.start < self.end {
+            // The following map has two nibbles, for start-side
+            // and end-side. Values of 0,1,2 mean move that many
+            // to the other side. Value of 3 means move 2 and
+            // flip them.
+            const MAP: [u8; 16] = [
+                0x00, // 0  no change
+                0x10, // 1  Ax => xA
+                0x01, // 2  xD => Dx
+                0x11, // 3  AxD => DxA
+                0x20, // 4  ABx => xAB
+                0x30, // 5  ABx => xBA
+                0x02, // 6  xCD => CDx
+                0x03, // 7  xCD => DCx
+                0x12, // 8  AxCD => CDxA
+                0x13, // 9  AxCD => DCxA
+                0x21, // 10 ABxD => DxAB
+                0x31, // 11 ABxD => DxBA
+                0x22, // 12 ABxCD => CDxAB
+                0x32, // 13 ABxCD => CDxBA
+                0x23, // 14 ABxCD => DCxAB
+                0x33, // 15 ABxCD => DCxBA
+            ];
+
+            let m = MAP[usize::from(flags & VERB)];
+            let l = core::cmp::min(2, usize::from(m >> 4));
+            let r = core::cmp::min(2, usize::from(m & 0x0F));
+            let reverse_l = m >> 4 == 3;
+            let reverse_r = m & 0x0F == 3;
+
+            let (start, end) = (self.start, self.end);
+            if end - start >= l + r && end - start <= MAX_CONTEXT_LENGTH {
+                let mut buf = [GlyphId(0); 4];
+                buf[..l].copy_from_slice(&glyphs[start..start + l]);
+                buf[2..2 + r].copy_from_slice(&glyphs[end - r..end]);
+
+                if l != r {
+                    glyphs.copy_within(start + l..end - r, start + r);
+                }
+
+                glyphs[start..start + r].copy_from_slice(&buf[2..2 + r]);
+                glyphs[end - l..end].copy_from_slice(&buf[..l]);
+
+                if reverse_l {
+                    glyphs.swap(end - 1, end - 2);
+                }
+
+                if reverse_r {
+                    glyphs.swap(start, start + 1);
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+struct ContextualCtx<'a, 'b> {
+    table: &'b ContextualSubtable<'a>,
+    mark_set: bool,
+    mark: usize,
+}
+
+impl StateMachineCtx<ContextualEntryData> for ContextualCtx<'_, '_> {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<ContextualEntryData>,
+        glyphs: &mut [GlyphId],
+        idx: usize,
+    ) -> Option<()> {
+        const SET_MARK: u16 = 0x8000;
+
+        // Looks like CoreText applies neither mark nor
+        // current substitution for end-of-text if mark was not
+        // explicitly set.
+        if idx == glyphs.len() && !self.mark_set {
+            return Some(());
+        }
+
+        if entry.extra.mark_index != 0xFFFF {
+            let lookup = self.table.lookup(u32::from(entry.extra.mark_index))?;
+            if let Some(glyph) = glyphs.get_mut(self.mark) {
+                if let Some(id) = lookup.value(*glyph) {
+                    *glyph = GlyphId(id);
+                }
+            }
+        }
+
+        if entry.extra.current_index != 0xFFFF {
+            let lookup = self.table.lookup(u32::from(entry.extra.current_index))?;
+            let idx = core::cmp::min(idx, glyphs.len().checked_sub(1)?);
+            if let Some(id) = lookup.value(glyphs[idx]) {
+                glyphs[idx] = GlyphId(id);
+            }
+        }
+
+        if entry.flags & SET_MARK != 0 {
+            self.mark_set = true;
+            self.mark = idx;
+        }
+
+        Some(())
+    }
+}
+
+struct LigatureCtx<'a, 'b> {
+    table: &'b LigatureSubtable<'a>,
+    match_length: usize,
+    match_positions: [usize; MAX_CONTEXT_LENGTH],
+}
+
+impl StateMachineCtx<u16> for LigatureCtx<'_, '_> {
+    fn transition(
+        &mut self,
+        entry: &aat::GenericStateEntry<u16>,
+        glyphs: &mut [GlyphId],
+        idx: usize,
+    ) -> Option<()> {
+        const SET_COMPONENT: u16 = 0x8000;
+        const PERFORM_ACTION: u16 = 0x2000;
+
+        const LIG_ACTION_LAST: u32 = 0x80000000;
+        const LIG_ACTION_STORE: u32 = 0x40000000;
+        const LIG_ACTION_OFFSET: u32 = 0x3FFFFFFF;
+
+        if entry.flags & SET_COMPONENT != 0 {
+            // Never mark same index twice, in case DONT_ADVANCE was used...
+            if self.match_length != 0
+                && self.match_positions[(self.match_length - 1) % MAX_CONTEXT_LENGTH] == idx
+            {
+                self.match_length -= 1;
+            }
+
+            self.match_positions[self.match_length % MAX_CONTEXT_LENGTH] = idx;
+            self.match_length += 1;
+        }
+
+        if entry.flags & PERFORM_ACTION == 0 {
+            return Some(());
+        }
+
+        if self.match_length == 0 || idx >= glyphs.len() {
+            return Some(());
+        }
+
+        let mut cursor = self.match_length;
+        let mut action_index = u32::from(entry.extra);
+        let mut ligature_index = 0u32;
+        loop {
+            if cursor == 0 {
+                // Stack underflow. Clear the stack.
+                self.match_length = 0;
+                break;
+            }
+
+            cursor -= 1;
+            let position = self.match_positions[cursor % MAX_CONTEXT_LENGTH];
+            let glyph = *glyphs.get(position)?;
+
+            let action = self.table.ligature_actions.get(action_index)?;
+            action_index = action_index.checked_add(1)?;
+
+            let mut offset = action & LIG_ACTION_OFFSET;
+            if offset & 0x20000000 != 0 {
+                // Sign-extend.
+                offset |= 0xC0000000;
+            }
+
+            let component_index = i64::from(glyph.0) + i64::from(offset as i32);
+            let component_index = u32::try_from(component_index).ok()?;
+            let component = self.table.components.get(component_index)?;
+            ligature_index = ligature_index.checked_add(u32::from(component))?;
+
+            if action & (LIG_ACTION_STORE | LIG_ACTION_LAST) != 0 {
+                glyphs[position] = self.table.ligatures.get(ligature_index)?;
+
+                // Now go and delete all subsequent components.
+                while self.match_length - 1 > cursor {
+                    self.match_length -= 1;
+                    let position = self.match_positions[self.match_length % MAX_CONTEXT_LENGTH];
+                    if let Some(glyph) = glyphs.get_mut(position) {
+                        *glyph = DELETED_GLYPH;
+                    }
+                }
+            }
+
+            if action & LIG_ACTION_LAST != 0 {
+                break;
+            }
+        }
+
+        Some(())
+    }
 }
@@ -67,6 +67,211 @@ impl FromData for PlatformId {
     }
 }
 
+/// A language that can be used for a localized name lookup.
+///
+/// Maps common [BCP 47](https://www.rfc-editor.org/info/bcp47) tags to
+/// [Windows](https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-language-ids)
+/// and [Macintosh](https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-language-ids)
+/// language IDs.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Language {
+    Arabic,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hebrew,
+    Hindi,
+    Hungarian,
+    Italian,
+    Japanese,
+    Korean,
+    Norwegian,
+    Polish,
+    Portuguese,
+    Russian,
+    Spanish,
+    Swedish,
+    Thai,
+    Turkish,
+    Ukrainian,
+    Vietnamese,
+}
+
+impl Language {
+    /// Parses a language from a BCP 47 tag, like `en-US` or `zh-Hant`.
+    ///
+    /// Only the primary language subtag is taken into account,
+    /// except for Chinese, where the script or region subtag selects
+    /// between the Simplified and Traditional variants.
+    pub fn from_bcp47(tag: &str) -> Option<Self> {
+        let mut parts = tag.split(&['-', '_'][..]);
+        let primary = parts.next()?;
+        let eq = |s: &str| primary.eq_ignore_ascii_case(s);
+
+        let lang = if eq("ar") {
+            Language::Arabic
+        } else if eq("zh") {
+            let is_traditional = parts.any(|s| {
+                s.eq_ignore_ascii_case("Hant")
+                    || s.eq_ignore_ascii_case("TW")
+                    || s.eq_ignore_ascii_case("HK")
+                    || s.eq_ignore_ascii_case("MO")
+            });
+
+            if is_traditional {
+                Language::ChineseTraditional
+            } else {
+                Language::ChineseSimplified
+            }
+        } else if eq("cs") {
+            Language::Czech
+        } else if eq("da") {
+            Language::Danish
+        } else if eq("nl") {
+            Language::Dutch
+        } else if eq("en") {
+            Language::English
+        } else if eq("fi") {
+            Language::Finnish
+        } else if eq("fr") {
+            Language::French
+        } else if eq("de") {
+            Language::German
+        } else if eq("el") {
+            Language::Greek
+        } else if eq("he") || eq("iw") {
+            Language::Hebrew
+        } else if eq("hi") {
+            Language::Hindi
+        } else if eq("hu") {
+            Language::Hungarian
+        } else if eq("it") {
+            Language::Italian
+        } else if eq("ja") {
+            Language::Japanese
+        } else if eq("ko") {
+            Language::Korean
+        } else if eq("no") || eq("nb") || eq("nn") {
+            Language::Norwegian
+        } else if eq("pl") {
+            Language::Polish
+        } else if eq("pt") {
+            Language::Portuguese
+        } else if eq("ru") {
+            Language::Russian
+        } else if eq("es") {
+            Language::Spanish
+        } else if eq("sv") {
+            Language::Swedish
+        } else if eq("th") {
+            Language::Thai
+        } else if eq("tr") {
+            Language::Turkish
+        } else if eq("uk") {
+            Language::Ukrainian
+        } else if eq("vi") {
+            Language::Vietnamese
+        } else {
+            return None;
+        };
+
+        Some(lang)
+    }
+
+    /// Returns a Windows language ID of the primary locale.
+    #[rustfmt::skip]
+    pub fn windows_id(self) -> u16 {
+        match self {
+            Language::Arabic                => 0x0401,
+            Language::ChineseSimplified     => 0x0804,
+            Language::ChineseTraditional    => 0x0404,
+            Language::Czech                 => 0x0405,
+            Language::Danish                => 0x0406,
+            Language::Dutch                 => 0x0413,
+            Language::English               => 0x0409,
+            Language::Finnish               => 0x040B,
+            Language::French                => 0x040C,
+            Language::German                => 0x0407,
+            Language::Greek                 => 0x0408,
+            Language::Hebrew                => 0x040D,
+            Language::Hindi                 => 0x0439,
+            Language::Hungarian             => 0x040E,
+            Language::Italian               => 0x0410,
+            Language::Japanese              => 0x0411,
+            Language::Korean                => 0x0412,
+            Language::Norwegian             => 0x0414,
+            Language::Polish                => 0x0415,
+            Language::Portuguese            => 0x0816,
+            Language::Russian               => 0x0419,
+            Language::Spanish               => 0x0C0A,
+            Language::Swedish               => 0x041D,
+            Language::Thai                  => 0x041E,
+            Language::Turkish               => 0x041F,
+            Language::Ukrainian             => 0x0422,
+            Language::Vietnamese            => 0x042A,
+        }
+    }
+
+    /// Returns a Macintosh language ID.
+    #[rustfmt::skip]
+    pub fn macintosh_id(self) -> u16 {
+        match self {
+            Language::Arabic                => 12,
+            Language::ChineseSimplified     => 33,
+            Language::ChineseTraditional    => 19,
+            Language::Czech                 => 38,
+            Language::Danish                => 7,
+            Language::Dutch                 => 4,
+            Language::English               => 0,
+            Language::Finnish               => 13,
+            Language::French                => 1,
+            Language::German                => 2,
+            Language::Greek                 => 14,
+            Language::Hebrew                => 10,
+            Language::Hindi                 => 21,
+            Language::Hungarian             => 26,
+            Language::Italian               => 3,
+            Language::Japanese              => 11,
+            Language::Korean                => 23,
+            Language::Norwegian             => 9,
+            Language::Polish                => 25,
+            Language::Portuguese            => 8,
+            Language::Russian               => 32,
+            Language::Spanish               => 6,
+            Language::Swedish               => 5,
+            Language::Thai                  => 22,
+            Language::Turkish               => 17,
+            Language::Ukrainian             => 45,
+            Language::Vietnamese            => 80,
+        }
+    }
+
+    /// Checks that a Windows language ID belongs to this language.
+    ///
+    /// Unlike [`Language::windows_id`], accepts all regional variants,
+    /// so `en-GB` (0x0809) will match [`Language::English`] as well.
+    #[inline]
+    fn matches_windows_id(self, id: u16) -> bool {
+        // The low 10 bits are the primary language ID.
+        const PRIMARY_LANGUAGE_MASK: u16 = 0x03FF;
+
+        match self {
+            // Both variants share the same primary language ID.
+            Language::ChineseSimplified => matches!(id, 0x0804 | 0x1004),
+            Language::ChineseTraditional => matches!(id, 0x0404 | 0x0C04 | 0x1404),
+            _ => id & PRIMARY_LANGUAGE_MASK == self.windows_id() & PRIMARY_LANGUAGE_MASK,
+        }
+    }
+}
+
 #[inline]
 fn is_unicode_encoding(platform_id: PlatformId, encoding_id: u16) -> bool {
     // https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-encoding-ids
@@ -195,6 +400,10 @@ impl<'a> core::fmt::Debug for Name<'a> {
 }
 
 /// A list of face names.
+///
+/// Names are stored and iterated in the file order.
+/// Fonts usually sort records by platform, encoding, language and name IDs,
+/// but this is not guaranteed.
 #[derive(Clone, Copy, Default)]
 pub struct Names<'a> {
     records: LazyArray16<'a, NameRecord>,
@@ -217,6 +426,57 @@ impl<'a> Names<'a> {
         })
     }
 
+    /// Returns an iterator over names with a specified ID.
+    ///
+    /// Names from all platforms, encodings and languages are returned in the file order,
+    /// so callers can apply their own preference.
+    pub fn filter_by_id(&self, name_id: u16) -> impl Iterator<Item = Name<'a>> + 'a {
+        self.into_iter().filter(move |name| name.name_id == name_id)
+    }
+
+    /// Returns a name with a specified ID in a specified language.
+    ///
+    /// Windows Unicode BMP (3, 1) records are preferred, with an exact language ID match
+    /// being preferred over a regional variant. Then Macintosh Roman (1, 0) records are checked.
+    ///
+    /// When there is no record in the requested language,
+    /// the first record with a specified ID is returned.
+    ///
+    /// Returns `None` only when there are no records with a specified ID.
+    pub fn get_localized(&self, name_id: u16, language: Language) -> Option<Name<'a>> {
+        const WINDOWS_UNICODE_BMP_ENCODING_ID: u16 = 1;
+        const MACINTOSH_ROMAN_ENCODING_ID: u16 = 0;
+
+        let mut first = None;
+        let mut windows_regional = None;
+        let mut macintosh = None;
+        for name in self.filter_by_id(name_id) {
+            if first.is_none() {
+                first = Some(name);
+            }
+
+            if name.platform_id == PlatformId::Windows
+                && name.encoding_id == WINDOWS_UNICODE_BMP_ENCODING_ID
+            {
+                if name.language_id == language.windows_id() {
+                    return Some(name);
+                } else if windows_regional.is_none()
+                    && language.matches_windows_id(name.language_id)
+                {
+                    windows_regional = Some(name);
+                }
+            } else if name.platform_id == PlatformId::Macintosh
+                && name.encoding_id == MACINTOSH_ROMAN_ENCODING_ID
+                && name.language_id == language.macintosh_id()
+                && macintosh.is_none()
+            {
+                macintosh = Some(name);
+            }
+        }
+
+        windows_regional.or(macintosh).or(first)
+    }
+
     /// Returns a number of name records.
     pub fn len(&self) -> u16 {
         self.records.len()
@@ -2,26 +2,34 @@
 //! implementation.
 
 use crate::parser::Stream;
-use crate::LineMetrics;
+use crate::{LineMetrics, Tag};
 
 const WEIGHT_CLASS_OFFSET: usize = 4;
 const WIDTH_CLASS_OFFSET: usize = 6;
+const TYPE_OFFSET: usize = 8;
 const Y_SUBSCRIPT_X_SIZE_OFFSET: usize = 10;
 const Y_SUPERSCRIPT_X_SIZE_OFFSET: usize = 18;
 const Y_STRIKEOUT_SIZE_OFFSET: usize = 26;
 const Y_STRIKEOUT_POSITION_OFFSET: usize = 28;
+const PANOSE_OFFSET: usize = 32;
+const UNICODE_RANGE_OFFSET: usize = 42;
+const VENDOR_ID_OFFSET: usize = 58;
 const FS_SELECTION_OFFSET: usize = 62;
 const TYPO_ASCENDER_OFFSET: usize = 68;
 const TYPO_DESCENDER_OFFSET: usize = 70;
 const TYPO_LINE_GAP_OFFSET: usize = 72;
 const WIN_ASCENT: usize = 74;
 const WIN_DESCENT: usize = 76;
+const CODE_PAGE_RANGE_OFFSET: usize = 78;
 const X_HEIGHT_OFFSET: usize = 86;
 const CAP_HEIGHT_OFFSET: usize = 88;
+const LOWER_OPTICAL_POINT_SIZE_OFFSET: usize = 96;
+const UPPER_OPTICAL_POINT_SIZE_OFFSET: usize = 98;
 
 /// A face [weight](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#usweightclass).
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Weight {
     Thin,
     ExtraLight,
@@ -79,9 +87,29 @@ impl Default for Weight {
     }
 }
 
+impl core::fmt::Display for Weight {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Weight::Thin => "Thin",
+            Weight::ExtraLight => "Extra Light",
+            Weight::Light => "Light",
+            Weight::Normal => "Normal",
+            Weight::Medium => "Medium",
+            Weight::SemiBold => "Semi Bold",
+            Weight::Bold => "Bold",
+            Weight::ExtraBold => "Extra Bold",
+            Weight::Black => "Black",
+            Weight::Other(n) => return write!(f, "{}", n),
+        };
+
+        write!(f, "{} ({})", self.to_number(), name)
+    }
+}
+
 /// A face [width](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#uswidthclass).
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Width {
     UltraCondensed,
     ExtraCondensed,
@@ -119,8 +147,44 @@ impl Default for Width {
     }
 }
 
+impl core::fmt::Display for Width {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Percentages of the normal width, as defined by the spec.
+        let (percentage, name) = match self {
+            Width::UltraCondensed => ("50", "Ultra Condensed"),
+            Width::ExtraCondensed => ("62.5", "Extra Condensed"),
+            Width::Condensed => ("75", "Condensed"),
+            Width::SemiCondensed => ("87.5", "Semi Condensed"),
+            Width::Normal => ("100", "Normal"),
+            Width::SemiExpanded => ("112.5", "Semi Expanded"),
+            Width::Expanded => ("125", "Expanded"),
+            Width::ExtraExpanded => ("150", "Extra Expanded"),
+            Width::UltraExpanded => ("200", "Ultra Expanded"),
+        };
+
+        write!(f, "{}% ({})", percentage, name)
+    }
+}
+
+/// A face [embedding permissions](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fstype).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Permissions {
+    /// The face may be embedded and permanently installed on the remote system.
+    Installable,
+    /// The face must not be modified, embedded or exchanged in any manner
+    /// without first obtaining permission of the legal owner.
+    Restricted,
+    /// The face may be embedded and temporarily loaded on the remote system,
+    /// but documents containing it must be opened read-only.
+    PreviewAndPrint,
+    /// The face may be embedded and temporarily loaded on the remote system,
+    /// and documents containing it may be edited.
+    Editable,
+}
+
 /// A face style.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Style {
     /// A face that is neither italic not obliqued.
     Normal,
@@ -137,6 +201,16 @@ impl Default for Style {
     }
 }
 
+impl core::fmt::Display for Style {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Style::Normal => write!(f, "Normal"),
+            Style::Italic => write!(f, "Italic"),
+            Style::Oblique => write!(f, "Oblique"),
+        }
+    }
+}
+
 /// A script metrics used by subscript and superscript.
 #[repr(C)]
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
@@ -154,17 +228,223 @@ pub struct ScriptMetrics {
     pub y_offset: i16,
 }
 
-// https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fsselection
-#[derive(Clone, Copy)]
-struct SelectionFlags(u16);
+/// A [Unicode range](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#ur) bit.
+///
+/// Some bits cover multiple Unicode blocks, in which case only the first one is named.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum UnicodeBlock {
+    BasicLatin = 0,
+    Latin1Supplement = 1,
+    LatinExtendedA = 2,
+    LatinExtendedB = 3,
+    IpaExtensions = 4,
+    SpacingModifierLetters = 5,
+    CombiningDiacriticalMarks = 6,
+    GreekAndCoptic = 7,
+    Coptic = 8,
+    Cyrillic = 9,
+    Armenian = 10,
+    Hebrew = 11,
+    Vai = 12,
+    Arabic = 13,
+    NKo = 14,
+    Devanagari = 15,
+    Bengali = 16,
+    Gurmukhi = 17,
+    Gujarati = 18,
+    Oriya = 19,
+    Tamil = 20,
+    Telugu = 21,
+    Kannada = 22,
+    Malayalam = 23,
+    Thai = 24,
+    Lao = 25,
+    Georgian = 26,
+    Balinese = 27,
+    HangulJamo = 28,
+    LatinExtendedAdditional = 29,
+    GreekExtended = 30,
+    GeneralPunctuation = 31,
+    SuperscriptsAndSubscripts = 32,
+    CurrencySymbols = 33,
+    CombiningDiacriticalMarksForSymbols = 34,
+    LetterlikeSymbols = 35,
+    NumberForms = 36,
+    Arrows = 37,
+    MathematicalOperators = 38,
+    MiscellaneousTechnical = 39,
+    ControlPictures = 40,
+    OpticalCharacterRecognition = 41,
+    EnclosedAlphanumerics = 42,
+    BoxDrawing = 43,
+    BlockElements = 44,
+    GeometricShapes = 45,
+    MiscellaneousSymbols = 46,
+    Dingbats = 47,
+    CjkSymbolsAndPunctuation = 48,
+    Hiragana = 49,
+    Katakana = 50,
+    Bopomofo = 51,
+    HangulCompatibilityJamo = 52,
+    PhagsPa = 53,
+    EnclosedCjkLettersAndMonths = 54,
+    CjkCompatibility = 55,
+    HangulSyllables = 56,
+    NonPlane0 = 57,
+    Phoenician = 58,
+    CjkUnifiedIdeographs = 59,
+    PrivateUseAreaPlane0 = 60,
+    CjkStrokes = 61,
+    AlphabeticPresentationForms = 62,
+    ArabicPresentationFormsA = 63,
+    CombiningHalfMarks = 64,
+    VerticalForms = 65,
+    SmallFormVariants = 66,
+    ArabicPresentationFormsB = 67,
+    HalfwidthAndFullwidthForms = 68,
+    Specials = 69,
+    Tibetan = 70,
+    Syriac = 71,
+    Thaana = 72,
+    Sinhala = 73,
+    Myanmar = 74,
+    Ethiopic = 75,
+    Cherokee = 76,
+    UnifiedCanadianAboriginalSyllabics = 77,
+    Ogham = 78,
+    Runic = 79,
+    Khmer = 80,
+    Mongolian = 81,
+    BraillePatterns = 82,
+    YiSyllables = 83,
+    Tagalog = 84,
+    OldItalic = 85,
+    Gothic = 86,
+    Deseret = 87,
+    ByzantineMusicalSymbols = 88,
+    MathematicalAlphanumericSymbols = 89,
+    PrivateUsePlane15 = 90,
+    VariationSelectors = 91,
+    Tags = 92,
+    Limbu = 93,
+    TaiLe = 94,
+    NewTaiLue = 95,
+    Buginese = 96,
+    Glagolitic = 97,
+    Tifinagh = 98,
+    YijingHexagramSymbols = 99,
+    SylotiNagri = 100,
+    LinearBSyllabary = 101,
+    AncientGreekNumbers = 102,
+    Ugaritic = 103,
+    OldPersian = 104,
+    Shavian = 105,
+    Osmanya = 106,
+    CypriotSyllabary = 107,
+    Kharoshthi = 108,
+    TaiXuanJingSymbols = 109,
+    Cuneiform = 110,
+    CountingRodNumerals = 111,
+    Sundanese = 112,
+    Lepcha = 113,
+    OlChiki = 114,
+    Saurashtra = 115,
+    KayahLi = 116,
+    Rejang = 117,
+    Cham = 118,
+    AncientSymbols = 119,
+    PhaistosDisc = 120,
+    Carian = 121,
+    DominoTiles = 122,
+}
+
+/// Unicode ranges a face claims to be functional for.
+///
+/// Stores `ulUnicodeRange1..4` fields, where the bit 0 of `ulUnicodeRange1` is the bit 0 here.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash)]
+pub struct UnicodeRanges(pub u128);
+
+impl UnicodeRanges {
+    /// Checks if ranges contain the specified Unicode block.
+    #[inline]
+    pub fn contains(&self, block: UnicodeBlock) -> bool {
+        self.0 & (1 << block as u32) != 0
+    }
+}
+
+/// [Font selection flags](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fsselection).
+///
+/// Stores the raw `fsSelection` field.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash)]
+pub struct SelectionFlags(pub u16);
 
-#[rustfmt::skip]
 impl SelectionFlags {
-    #[inline] fn italic(self) -> bool { self.0 & (1 << 0) != 0 }
-    #[inline] fn bold(self) -> bool { self.0 & (1 << 5) != 0 }
-    // #[inline] fn regular(self) -> bool { self.0 & (1 << 6) != 0 }
-    #[inline] fn use_typo_metrics(self) -> bool { self.0 & (1 << 7) != 0 }
-    #[inline] fn oblique(self) -> bool { self.0 & (1 << 9) != 0 }
+    /// Checks that the `ITALIC` bit is set.
+    #[inline]
+    pub fn italic(self) -> bool {
+        self.0 & (1 << 0) != 0
+    }
+
+    /// Checks that the `UNDERSCORE` bit is set.
+    #[inline]
+    pub fn underscore(self) -> bool {
+        self.0 & (1 << 1) != 0
+    }
+
+    /// Checks that the `NEGATIVE` bit is set.
+    #[inline]
+    pub fn negative(self) -> bool {
+        self.0 & (1 << 2) != 0
+    }
+
+    /// Checks that the `OUTLINED` bit is set.
+    #[inline]
+    pub fn outlined(self) -> bool {
+        self.0 & (1 << 3) != 0
+    }
+
+    /// Checks that the `STRIKEOUT` bit is set.
+    #[inline]
+    pub fn strikeout(self) -> bool {
+        self.0 & (1 << 4) != 0
+    }
+
+    /// Checks that the `BOLD` bit is set.
+    #[inline]
+    pub fn bold(self) -> bool {
+        self.0 & (1 << 5) != 0
+    }
+
+    /// Checks that the `REGULAR` bit is set.
+    #[inline]
+    pub fn regular(self) -> bool {
+        self.0 & (1 << 6) != 0
+    }
+
+    /// Checks that the `USE_TYPO_METRICS` bit is set.
+    ///
+    /// Defined since version 4.
+    #[inline]
+    pub fn use_typo_metrics(self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
+
+    /// Checks that the `WWS` bit is set.
+    ///
+    /// Defined since version 4.
+    #[inline]
+    pub fn wws(self) -> bool {
+        self.0 & (1 << 8) != 0
+    }
+
+    /// Checks that the `OBLIQUE` bit is set.
+    ///
+    /// Defined since version 4.
+    #[inline]
+    pub fn oblique(self) -> bool {
+        self.0 & (1 << 9) != 0
+    }
 }
 
 /// A [OS/2 and Windows Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/os2).
@@ -226,6 +506,60 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns face embedding permissions.
+    ///
+    /// Since version 3, permission bits must be mutually exclusive,
+    /// therefore `None` will be returned when multiple bits are set.
+    /// Prior to that, the least restrictive permission is used.
+    ///
+    /// The reserved bit 0 is ignored.
+    #[inline]
+    pub fn permissions(&self) -> Option<Permissions> {
+        let n = self.fs_type();
+        if self.version <= 2 {
+            let permissions = if n & 0x000E == 0 {
+                Permissions::Installable
+            } else if n & 0x0008 != 0 {
+                Permissions::Editable
+            } else if n & 0x0004 != 0 {
+                Permissions::PreviewAndPrint
+            } else {
+                Permissions::Restricted
+            };
+
+            Some(permissions)
+        } else {
+            match n & 0x000E {
+                0x0000 => Some(Permissions::Installable),
+                0x0002 => Some(Permissions::Restricted),
+                0x0004 => Some(Permissions::PreviewAndPrint),
+                0x0008 => Some(Permissions::Editable),
+                _ => None,
+            }
+        }
+    }
+
+    /// Checks if the face may be subsetted before embedding.
+    ///
+    /// The `No subsetting` flag is ignored when version is < 2.
+    #[inline]
+    pub fn is_subsetting_allowed(&self) -> bool {
+        self.version < 2 || self.fs_type() & 0x0100 == 0
+    }
+
+    /// Checks if only bitmaps contained in the face may be embedded.
+    ///
+    /// The `Bitmap embedding only` flag is ignored when version is < 2.
+    #[inline]
+    pub fn is_bitmap_embedding_only(&self) -> bool {
+        self.version >= 2 && self.fs_type() & 0x0200 != 0
+    }
+
+    #[inline]
+    fn fs_type(&self) -> u16 {
+        Stream::read_at::<u16>(self.data, TYPE_OFFSET).unwrap_or(0)
+    }
+
     /// Returns subscript metrics.
     #[inline]
     pub fn subscript_metrics(&self) -> ScriptMetrics {
@@ -259,14 +593,67 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns [PANOSE](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#panose)
+    /// classification bytes.
     #[inline]
-    fn fs_selection(&self) -> u16 {
-        Stream::read_at::<u16>(self.data, FS_SELECTION_OFFSET).unwrap_or(0)
+    pub fn panose(&self) -> [u8; 10] {
+        let mut panose = [0; 10];
+        // Always present, since the table length was checked during parsing.
+        if let Some(data) = self.data.get(PANOSE_OFFSET..PANOSE_OFFSET + 10) {
+            panose.copy_from_slice(data);
+        }
+        panose
+    }
+
+    /// Returns font vendor ID.
+    ///
+    /// Identifies the font foundry. Usually a registered 4-character code.
+    #[inline]
+    pub fn vendor_id(&self) -> Tag {
+        Stream::read_at::<Tag>(self.data, VENDOR_ID_OFFSET).unwrap_or(Tag(0))
+    }
+
+    /// Returns Unicode ranges the face claims to cover.
+    #[inline]
+    pub fn unicode_ranges(&self) -> UnicodeRanges {
+        let mut s = Stream::new_at(self.data, UNICODE_RANGE_OFFSET).unwrap_or_default();
+        let mut n = 0u128;
+        for i in 0..4 {
+            let range = s.read::<u32>().unwrap_or(0);
+            n |= u128::from(range) << (i * 32);
+        }
+
+        UnicodeRanges(n)
+    }
+
+    /// Returns code pages the face claims to be functional for.
+    ///
+    /// Stores `ulCodePageRange1..2` fields, where the bit 0 of `ulCodePageRange1` is the bit 0 here.
+    /// See the [specification](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#cpr)
+    /// for bits meaning.
+    ///
+    /// Returns `None` when version is < 1.
+    #[inline]
+    pub fn code_page_ranges(&self) -> Option<u64> {
+        if self.version < 1 {
+            return None;
+        }
+
+        let mut s = Stream::new_at(self.data, CODE_PAGE_RANGE_OFFSET)?;
+        let range1 = s.read::<u32>()?;
+        let range2 = s.read::<u32>()?;
+        Some(u64::from(range1) | u64::from(range2) << 32)
+    }
+
+    /// Returns font selection flags.
+    #[inline]
+    pub fn selection_flags(&self) -> SelectionFlags {
+        SelectionFlags(Stream::read_at::<u16>(self.data, FS_SELECTION_OFFSET).unwrap_or(0))
     }
 
     /// Returns style.
     pub fn style(&self) -> Style {
-        let flags = SelectionFlags(self.fs_selection());
+        let flags = self.selection_flags();
         if flags.italic() {
             Style::Italic
         } else if self.version >= 4 && flags.oblique() {
@@ -281,7 +668,7 @@ impl<'a> Table<'a> {
     /// Do not confuse with [`Weight::Bold`].
     #[inline]
     pub fn is_bold(&self) -> bool {
-        SelectionFlags(self.fs_selection()).bold()
+        self.selection_flags().bold()
     }
 
     /// Checks if typographic metrics should be used.
@@ -290,10 +677,28 @@ impl<'a> Table<'a> {
         if self.version < 4 {
             false
         } else {
-            SelectionFlags(self.fs_selection()).use_typo_metrics()
+            self.selection_flags().use_typo_metrics()
         }
     }
 
+    /// Checks that the face's family is organized according to the weight/width/slope model.
+    ///
+    /// The `WWS` flag is ignored when version is < 4.
+    #[inline]
+    pub fn is_wws(&self) -> bool {
+        self.version >= 4 && self.selection_flags().wws()
+    }
+
+    /// Checks that the `OBLIQUE` flag is set.
+    ///
+    /// Unlike [`style`](Self::style), doesn't take the `ITALIC` flag into account.
+    ///
+    /// The `OBLIQUE` flag is ignored when version is < 4.
+    #[inline]
+    pub fn is_oblique_bit(&self) -> bool {
+        self.version >= 4 && self.selection_flags().oblique()
+    }
+
     /// Returns typographic ascender.
     #[inline]
     pub fn typographic_ascender(&self) -> i16 {
@@ -327,9 +732,22 @@ impl<'a> Table<'a> {
 
     /// Returns x height.
     ///
+    /// Returns the stored value, which is not affected by variation axes.
+    /// Use [`Face::x_height`](crate::Face::x_height) to get a variation-aware one.
+    ///
     /// Returns `None` version is < 2.
     #[inline]
     pub fn x_height(&self) -> Option<i16> {
+        self.x_height_raw()
+    }
+
+    /// Returns the stored x height without any `MVAR` adjustments.
+    ///
+    /// Same as [`x_height`](Self::x_height), but makes the intent explicit.
+    ///
+    /// Returns `None` version is < 2.
+    #[inline]
+    pub fn x_height_raw(&self) -> Option<i16> {
         if self.version < 2 {
             None
         } else {
@@ -339,15 +757,46 @@ impl<'a> Table<'a> {
 
     /// Returns capital height.
     ///
+    /// Returns the stored value, which is not affected by variation axes.
+    /// Use [`Face::capital_height`](crate::Face::capital_height) to get a variation-aware one.
+    ///
     /// Returns `None` version is < 2.
     #[inline]
     pub fn capital_height(&self) -> Option<i16> {
+        self.capital_height_raw()
+    }
+
+    /// Returns the stored capital height without any `MVAR` adjustments.
+    ///
+    /// Same as [`capital_height`](Self::capital_height), but makes the intent explicit.
+    ///
+    /// Returns `None` version is < 2.
+    #[inline]
+    pub fn capital_height_raw(&self) -> Option<i16> {
         if self.version < 2 {
             None
         } else {
             Stream::read_at::<i16>(self.data, CAP_HEIGHT_OFFSET)
         }
     }
+
+    /// Returns the range of sizes this face was designed for, in points.
+    ///
+    /// The lower bound is inclusive and the upper one is exclusive.
+    /// Usually used to select the `opsz` axis value.
+    ///
+    /// Returns `None` when version is < 5.
+    #[inline]
+    pub fn optical_size_range(&self) -> Option<(f32, f32)> {
+        if self.version < 5 {
+            return None;
+        }
+
+        // Values are stored in TWIPs, i.e. 1/20 of a point.
+        let lower = Stream::read_at::<u16>(self.data, LOWER_OPTICAL_POINT_SIZE_OFFSET)?;
+        let upper = Stream::read_at::<u16>(self.data, UPPER_OPTICAL_POINT_SIZE_OFFSET)?;
+        Some((f32::from(lower) / 20.0, f32::from(upper) / 20.0))
+    }
 }
 
 impl core::fmt::Debug for Table<'_> {
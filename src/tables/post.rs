@@ -10,6 +10,7 @@ const ITALIC_ANGLE_OFFSET: usize = 4;
 const UNDERLINE_POSITION_OFFSET: usize = 8;
 const UNDERLINE_THICKNESS_OFFSET: usize = 10;
 const IS_FIXED_PITCH_OFFSET: usize = 12;
+const MEMORY_USAGE_OFFSET: usize = 16;
 
 // https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html
 /// A list of Macintosh glyph names.
@@ -316,15 +317,87 @@ impl<'a> Iterator for Names<'a> {
     }
 }
 
+/// An iterator over glyph names in glyph ID order.
+///
+/// Unlike [`Names`], includes default/predefined names as well.
+#[cfg(feature = "glyph-names")]
+#[derive(Clone, Copy, Default)]
+pub struct GlyphNames<'a> {
+    glyph_indexes: LazyArray16<'a, u16>,
+    glyph_id: u16,
+    names_data: &'a [u8],
+    // A cursor into the names list, so we don't have to start from the beginning each time,
+    // since names are usually stored in glyph ID order.
+    names: Names<'a>,
+    names_index: u16,
+}
+
+#[cfg(feature = "glyph-names")]
+impl core::fmt::Debug for GlyphNames<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "GlyphNames {{ ... }}")
+    }
+}
+
+#[cfg(feature = "glyph-names")]
+impl<'a> Iterator for GlyphNames<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.glyph_indexes.get(self.glyph_id)?;
+        self.glyph_id += 1;
+
+        if usize::from(index) < MACINTOSH_NAMES.len() {
+            return Some(MACINTOSH_NAMES[usize::from(index)]);
+        }
+
+        let index = index - MACINTOSH_NAMES.len() as u16;
+        if index < self.names_index {
+            self.names = Names {
+                data: self.names_data,
+                offset: 0,
+            };
+            self.names_index = 0;
+        }
+
+        // A malformed name will stop the iteration.
+        let name = self.names.nth(usize::from(index - self.names_index))?;
+        self.names_index = index + 1;
+        Some(name)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.glyph_indexes.len().saturating_sub(self.glyph_id);
+        (0, Some(usize::from(len)))
+    }
+}
+
 /// A [PostScript Table](https://docs.microsoft.com/en-us/typography/opentype/spec/post).
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
+    /// A raw table version.
+    ///
+    /// Uses a 16.16 format, i.e. version 2.5 is stored as `0x00025000`.
+    pub version: u32,
     /// Italic angle in counter-clockwise degrees from the vertical.
     pub italic_angle: f32,
     /// Underline metrics.
     pub underline_metrics: LineMetrics,
     /// Flag that indicates that the font is monospaced.
     pub is_monospaced: bool,
+    /// A raw `isFixedPitch` value.
+    ///
+    /// Any non-zero value indicates a monospaced font.
+    pub is_fixed_pitch: u32,
+    /// Minimum memory usage when a font is downloaded as a Type 42 font.
+    pub min_mem_type42: u32,
+    /// Maximum memory usage when a font is downloaded as a Type 42 font.
+    pub max_mem_type42: u32,
+    /// Minimum memory usage when a font is downloaded as a Type 1 font.
+    pub min_mem_type1: u32,
+    /// Maximum memory usage when a font is downloaded as a Type 1 font.
+    pub max_mem_type1: u32,
 
     glyph_indexes: LazyArray16<'a, u16>,
     names_data: &'a [u8],
@@ -356,7 +429,13 @@ impl<'a> Table<'a> {
             thickness: Stream::read_at::<i16>(data, UNDERLINE_THICKNESS_OFFSET)?,
         };
 
-        let is_monospaced = Stream::read_at::<u32>(data, IS_FIXED_PITCH_OFFSET)? != 0;
+        let is_fixed_pitch = Stream::read_at::<u32>(data, IS_FIXED_PITCH_OFFSET)?;
+
+        let mut s = Stream::new_at(data, MEMORY_USAGE_OFFSET)?;
+        let min_mem_type42 = s.read::<u32>()?;
+        let max_mem_type42 = s.read::<u32>()?;
+        let min_mem_type1 = s.read::<u32>()?;
+        let max_mem_type1 = s.read::<u32>()?;
 
         let mut names_data: &[u8] = &[];
         let mut glyph_indexes = LazyArray16::default();
@@ -369,9 +448,15 @@ impl<'a> Table<'a> {
         }
 
         Some(Table {
+            version,
             italic_angle,
             underline_metrics,
-            is_monospaced,
+            is_monospaced: is_fixed_pitch != 0,
+            is_fixed_pitch,
+            min_mem_type42,
+            max_mem_type42,
+            min_mem_type1,
+            max_mem_type1,
             names_data,
             glyph_indexes,
         })
@@ -412,6 +497,23 @@ impl<'a> Table<'a> {
         Some(GlyphId(id as u16))
     }
 
+    /// Returns an iterator over glyph names in glyph ID order.
+    ///
+    /// Unlike [`names`](Self::names), resolves default/predefined names as well.
+    /// Only version 2.0 of the table stores glyph names.
+    ///
+    /// The iteration stops on the first malformed name.
+    #[cfg(feature = "glyph-names")]
+    pub fn glyph_names(&self) -> GlyphNames<'a> {
+        GlyphNames {
+            glyph_indexes: self.glyph_indexes,
+            glyph_id: 0,
+            names_data: self.names_data,
+            names: self.names(),
+            names_index: 0,
+        }
+    }
+
     /// Returns an iterator over glyph names.
     ///
     /// Default/predefined names are not included. Just the one in the font file.
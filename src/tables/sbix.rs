@@ -7,6 +7,19 @@ use core::num::NonZeroU16;
 use crate::parser::{FromData, LazyArray16, LazyArray32, Offset, Offset32, Stream};
 use crate::{GlyphId, RasterGlyphImage, RasterImageFormat, Tag};
 
+/// A raw glyph data.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlyphData<'a> {
+    /// Horizontal offset.
+    pub x: i16,
+    /// Vertical offset.
+    pub y: i16,
+    /// An image format as declared by the font, like `png `, `jpg ` or `tiff`.
+    pub graphic_type: Tag,
+    /// A raw image data.
+    pub data: &'a [u8],
+}
+
 /// A strike of glyphs.
 #[derive(Clone, Copy)]
 pub struct Strike<'a> {
@@ -35,10 +48,39 @@ impl<'a> Strike<'a> {
 
     /// Returns a glyph data.
     pub fn get(&self, glyph_id: GlyphId) -> Option<RasterGlyphImage<'a>> {
-        self.get_inner(glyph_id, 0)
+        let glyph = self.glyph_data(glyph_id)?;
+
+        // We do ignore `pdf` and `mask` intentionally, because Apple docs state that:
+        // 'Support for the 'pdf ' and 'mask' data types and sbixDrawOutlines flag
+        // are planned for future releases of iOS and OS X.'
+        let (format, (width, height)) = match &glyph.graphic_type.to_bytes() {
+            b"png " => (RasterImageFormat::PNG, png_size(glyph.data)?),
+            b"jpg " => (RasterImageFormat::JPEG, jpeg_size(glyph.data)?),
+            b"tiff" => (RasterImageFormat::TIFF, tiff_size(glyph.data)?),
+            _ => return None,
+        };
+
+        Some(RasterGlyphImage {
+            x: glyph.x,
+            y: glyph.y,
+            width,
+            height,
+            pixels_per_em: self.pixels_per_em,
+            format,
+            data: glyph.data,
+        })
     }
 
-    fn get_inner(&self, glyph_id: GlyphId, depth: u8) -> Option<RasterGlyphImage<'a>> {
+    /// Returns a raw glyph data.
+    ///
+    /// Unlike [`get`](Self::get), doesn't check the graphic type,
+    /// so any image format can be accessed.
+    /// `dupe` references are resolved.
+    pub fn glyph_data(&self, glyph_id: GlyphId) -> Option<GlyphData<'a>> {
+        self.glyph_data_inner(glyph_id, 0)
+    }
+
+    fn glyph_data_inner(&self, glyph_id: GlyphId, depth: u8) -> Option<GlyphData<'a>> {
         // Recursive `dupe`. Bail.
         if depth == 10 {
             return None;
@@ -56,39 +98,24 @@ impl<'a> Strike<'a> {
         let mut s = Stream::new_at(self.data, start)?;
         let x = s.read::<i16>()?;
         let y = s.read::<i16>()?;
-        let image_type = s.read::<Tag>()?;
-        let image_data = s.read_bytes(data_len)?;
-
-        // We do ignore `pdf` and `mask` intentionally, because Apple docs state that:
-        // 'Support for the 'pdf ' and 'mask' data types and sbixDrawOutlines flag
-        // are planned for future releases of iOS and OS X.'
-        let format = match &image_type.to_bytes() {
-            b"png " => RasterImageFormat::PNG,
-            b"dupe" => {
-                // 'The special graphicType of 'dupe' indicates that
-                // the data field contains a glyph ID. The bitmap data for
-                // the indicated glyph should be used for the current glyph.'
-                let glyph_id = GlyphId::parse(image_data)?;
-                // TODO: The spec isn't clear about which x/y values should we use.
-                //       The current glyph or the referenced one.
-                return self.get_inner(glyph_id, depth + 1);
-            }
-            _ => {
-                // TODO: support JPEG and TIFF
-                return None;
-            }
-        };
-
-        let (width, height) = png_size(image_data)?;
+        let graphic_type = s.read::<Tag>()?;
+        let data = s.read_bytes(data_len)?;
+
+        if &graphic_type.to_bytes() == b"dupe" {
+            // 'The special graphicType of 'dupe' indicates that
+            // the data field contains a glyph ID. The bitmap data for
+            // the indicated glyph should be used for the current glyph.'
+            let glyph_id = GlyphId::parse(data)?;
+            // TODO: The spec isn't clear about which x/y values should we use.
+            //       The current glyph or the referenced one.
+            return self.glyph_data_inner(glyph_id, depth + 1);
+        }
 
-        Some(RasterGlyphImage {
+        Some(GlyphData {
             x,
             y,
-            width,
-            height,
-            pixels_per_em: self.pixels_per_em,
-            format,
-            data: image_data,
+            graphic_type,
+            data,
         })
     }
 
@@ -221,6 +248,13 @@ impl<'a> Table<'a> {
         })
     }
 
+    /// Returns a raw glyph data from the best matching [`Strike`].
+    ///
+    /// See [`Strike::glyph_data`] for details.
+    pub fn glyph_data(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<GlyphData<'a>> {
+        self.best_strike(pixels_per_em)?.glyph_data(glyph_id)
+    }
+
     /// Selects the best matching [`Strike`] based on `pixels_per_em`.
     pub fn best_strike(&self, pixels_per_em: u16) -> Option<Strike<'a>> {
         let mut idx = 0;
@@ -239,8 +273,6 @@ impl<'a> Table<'a> {
 }
 
 // The `sbix` table doesn't store the image size, so we have to parse it manually.
-// Which is quite simple in case of PNG, but way more complex for JPEG.
-// Therefore we are omitting it for now.
 fn png_size(data: &[u8]) -> Option<(u16, u16)> {
     // PNG stores its size as u32 BE at a fixed offset.
     let mut s = Stream::new_at(data, 16)?;
@@ -250,3 +282,97 @@ fn png_size(data: &[u8]) -> Option<(u16, u16)> {
     // PNG size larger than u16::MAX is an error.
     Some((u16::try_from(width).ok()?, u16::try_from(height).ok()?))
 }
+
+fn jpeg_size(data: &[u8]) -> Option<(u16, u16)> {
+    let mut s = Stream::new(data);
+    // SOI marker.
+    if s.read::<u16>()? != 0xFFD8 {
+        return None;
+    }
+
+    loop {
+        if s.read::<u8>()? != 0xFF {
+            return None;
+        }
+
+        let marker = s.read::<u8>()?;
+        match marker {
+            // Fill bytes.
+            0xFF => continue,
+            // Standalone markers without a payload.
+            0x01 | 0xD0..=0xD7 => continue,
+            // End of image or start of scan. The size must be set before them.
+            0xD9 | 0xDA => return None,
+            // Start of frame markers, except DHT, JPG and DAC.
+            0xC0..=0xCF if marker != 0xC4 && marker != 0xC8 && marker != 0xCC => {
+                s.skip::<u16>(); // length
+                s.skip::<u8>(); // precision
+                let height = s.read::<u16>()?;
+                let width = s.read::<u16>()?;
+                return Some((width, height));
+            }
+            _ => {
+                // The length includes itself.
+                let len = s.read::<u16>()?.checked_sub(2)?;
+                s.advance_checked(usize::from(len))?;
+            }
+        }
+    }
+}
+
+fn tiff_size(data: &[u8]) -> Option<(u16, u16)> {
+    // Unlike the rest of the font, TIFF can be little-endian.
+    let is_le = match data.get(0..4)? {
+        b"II*\0" => true,
+        b"MM\0*" => false,
+        _ => return None,
+    };
+
+    let read_u16 = |offset: usize| -> Option<u16> {
+        let bytes = data.get(offset..offset.checked_add(2)?)?;
+        let bytes = [bytes[0], bytes[1]];
+        Some(if is_le {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    };
+
+    let read_u32 = |offset: usize| -> Option<u32> {
+        let bytes = data.get(offset..offset.checked_add(4)?)?;
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        Some(if is_le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    };
+
+    // We care only about the first image file directory.
+    let ifd_offset = usize::try_from(read_u32(4)?).ok()?;
+    let count = read_u16(ifd_offset)?;
+
+    let mut width = None;
+    let mut height = None;
+    for i in 0..usize::from(count) {
+        let entry = ifd_offset.checked_add(2)?.checked_add(i.checked_mul(12)?)?;
+        let tag = read_u16(entry)?;
+        let value = match read_u16(entry.checked_add(2)?)? {
+            3 => u32::from(read_u16(entry.checked_add(8)?)?), // SHORT
+            4 => read_u32(entry.checked_add(8)?)?,            // LONG
+            _ => continue,
+        };
+
+        match tag {
+            256 => width = Some(u16::try_from(value).ok()?),
+            257 => height = Some(u16::try_from(value).ok()?),
+            _ => {}
+        }
+
+        if let (Some(width), Some(height)) = (width, height) {
+            return Some((width, height));
+        }
+    }
+
+    None
+}
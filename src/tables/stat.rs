@@ -0,0 +1,408 @@
+//! A [Style Attributes Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/stat) implementation.
+
+use core::convert::TryFrom;
+
+use crate::parser::{
+    Fixed, FromData, FromSlice, LazyArray16, LazyOffsetArray16, Offset, Offset32, Stream,
+};
+use crate::Tag;
+
+/// A [design axis record](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-records).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AxisRecord {
+    /// An axis tag.
+    pub tag: Tag,
+    /// An axis name in the `name` table.
+    pub name_id: u16,
+    /// A value used to order axis names when composing family or face names.
+    pub ordering: u16,
+}
+
+impl FromData for AxisRecord {
+    const SIZE: usize = 8;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(AxisRecord {
+            tag: s.read::<Tag>()?,
+            name_id: s.read::<u16>()?,
+            ordering: s.read::<u16>()?,
+        })
+    }
+}
+
+/// A list of design axis records.
+///
+/// Unlike [`LazyArray16`], respects the record size declared by the table,
+/// which can be larger than the one we know about.
+#[derive(Clone, Copy, Default)]
+pub struct AxisRecords<'a> {
+    data: &'a [u8],
+    record_size: u16,
+    count: u16,
+}
+
+impl<'a> AxisRecords<'a> {
+    /// Returns an axis record at index.
+    pub fn get(&self, index: u16) -> Option<AxisRecord> {
+        if index >= self.count {
+            return None;
+        }
+
+        let start = usize::from(index) * usize::from(self.record_size);
+        let end = start + AxisRecord::SIZE;
+        self.data.get(start..end).and_then(AxisRecord::parse)
+    }
+
+    /// Returns a number of axis records.
+    pub fn len(&self) -> u16 {
+        self.count
+    }
+
+    /// Checks if there are any axis records.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl core::fmt::Debug for AxisRecords<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a> IntoIterator for AxisRecords<'a> {
+    type Item = AxisRecord;
+    type IntoIter = AxisRecordsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        AxisRecordsIter {
+            records: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over design axis records.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct AxisRecordsIter<'a> {
+    records: AxisRecords<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for AxisRecordsIter<'a> {
+    type Item = AxisRecord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.records.len() {
+            self.index += 1;
+            self.records.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.records.len().saturating_sub(self.index))
+    }
+}
+
+/// [Axis value table flags](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#flags).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct AxisValueFlags(pub u16);
+
+impl AxisValueFlags {
+    /// The axis value applies to older versions of the same family,
+    /// which were released without a variable font.
+    pub const OLDER_SIBLING_FONT_ATTRIBUTE: u16 = 0x0001;
+    /// The name associated with this axis value can be elided
+    /// when composing family or face names.
+    pub const ELIDABLE_AXIS_VALUE_NAME: u16 = 0x0002;
+
+    /// Checks that `OLDER_SIBLING_FONT_ATTRIBUTE` flag is set.
+    #[inline]
+    pub fn older_sibling_font_attribute(self) -> bool {
+        self.0 & Self::OLDER_SIBLING_FONT_ATTRIBUTE != 0
+    }
+
+    /// Checks that `ELIDABLE_AXIS_VALUE_NAME` flag is set.
+    #[inline]
+    pub fn elidable_axis_value_name(self) -> bool {
+        self.0 & Self::ELIDABLE_AXIS_VALUE_NAME != 0
+    }
+}
+
+/// An [axis value table format 1](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-1).
+///
+/// A single value on a single axis.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AxisValueFormat1 {
+    /// An index into the design axis records.
+    pub axis_index: u16,
+    pub flags: AxisValueFlags,
+    /// A value name in the `name` table.
+    pub value_name_id: u16,
+    pub value: f32,
+}
+
+/// An [axis value table format 2](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-2).
+///
+/// A range of values on a single axis.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AxisValueFormat2 {
+    /// An index into the design axis records.
+    pub axis_index: u16,
+    pub flags: AxisValueFlags,
+    /// A value name in the `name` table.
+    pub value_name_id: u16,
+    pub nominal_value: f32,
+    pub range_min_value: f32,
+    pub range_max_value: f32,
+}
+
+/// An [axis value table format 3](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-3).
+///
+/// A single value on a single axis with a style-linked counterpart,
+/// like Regular and Bold.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AxisValueFormat3 {
+    /// An index into the design axis records.
+    pub axis_index: u16,
+    pub flags: AxisValueFlags,
+    /// A value name in the `name` table.
+    pub value_name_id: u16,
+    pub value: f32,
+    pub linked_value: f32,
+}
+
+/// An axis-value pair used by [`AxisValueFormat4`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AxisValueRecord {
+    /// An index into the design axis records.
+    pub axis_index: u16,
+    /// A value on the axis.
+    pub value: f32,
+}
+
+impl FromData for AxisValueRecord {
+    const SIZE: usize = 6;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(AxisValueRecord {
+            axis_index: s.read::<u16>()?,
+            value: s.read::<Fixed>()?.0,
+        })
+    }
+}
+
+/// An [axis value table format 4](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-4).
+///
+/// A combination of values on multiple axes.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug)]
+pub struct AxisValueFormat4<'a> {
+    pub flags: AxisValueFlags,
+    /// A value name in the `name` table.
+    pub value_name_id: u16,
+    pub values: LazyArray16<'a, AxisValueRecord>,
+}
+
+/// An [axis value table](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-tables).
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug)]
+pub enum AxisValue<'a> {
+    Format1(AxisValueFormat1),
+    Format2(AxisValueFormat2),
+    Format3(AxisValueFormat3),
+    Format4(AxisValueFormat4<'a>),
+}
+
+impl<'a> AxisValue<'a> {
+    /// Returns axis value flags.
+    #[inline]
+    pub fn flags(&self) -> AxisValueFlags {
+        match self {
+            AxisValue::Format1(v) => v.flags,
+            AxisValue::Format2(v) => v.flags,
+            AxisValue::Format3(v) => v.flags,
+            AxisValue::Format4(v) => v.flags,
+        }
+    }
+
+    /// Returns a value name ID in the `name` table.
+    #[inline]
+    pub fn value_name_id(&self) -> u16 {
+        match self {
+            AxisValue::Format1(v) => v.value_name_id,
+            AxisValue::Format2(v) => v.value_name_id,
+            AxisValue::Format3(v) => v.value_name_id,
+            AxisValue::Format4(v) => v.value_name_id,
+        }
+    }
+}
+
+impl<'a> FromSlice<'a> for AxisValue<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let format = s.read::<u16>()?;
+        match format {
+            1 => Some(AxisValue::Format1(AxisValueFormat1 {
+                axis_index: s.read::<u16>()?,
+                flags: AxisValueFlags(s.read::<u16>()?),
+                value_name_id: s.read::<u16>()?,
+                value: s.read::<Fixed>()?.0,
+            })),
+            2 => Some(AxisValue::Format2(AxisValueFormat2 {
+                axis_index: s.read::<u16>()?,
+                flags: AxisValueFlags(s.read::<u16>()?),
+                value_name_id: s.read::<u16>()?,
+                nominal_value: s.read::<Fixed>()?.0,
+                range_min_value: s.read::<Fixed>()?.0,
+                range_max_value: s.read::<Fixed>()?.0,
+            })),
+            3 => Some(AxisValue::Format3(AxisValueFormat3 {
+                axis_index: s.read::<u16>()?,
+                flags: AxisValueFlags(s.read::<u16>()?),
+                value_name_id: s.read::<u16>()?,
+                value: s.read::<Fixed>()?.0,
+                linked_value: s.read::<Fixed>()?.0,
+            })),
+            4 => {
+                let axis_count = s.read::<u16>()?;
+                let flags = AxisValueFlags(s.read::<u16>()?);
+                let value_name_id = s.read::<u16>()?;
+                let values = s.read_array16::<AxisValueRecord>(axis_count)?;
+                Some(AxisValue::Format4(AxisValueFormat4 {
+                    flags,
+                    value_name_id,
+                    values,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A list of axis value tables.
+///
+/// Axis value tables with an unknown format will be returned as `None` by `get()`.
+pub type AxisValues<'a> = LazyOffsetArray16<'a, AxisValue<'a>>;
+
+/// A [Style Attributes Table](https://docs.microsoft.com/en-us/typography/opentype/spec/stat).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of design axes.
+    pub axes: AxisRecords<'a>,
+    /// A list of axis value tables.
+    pub axis_values: AxisValues<'a>,
+    /// A name ID used as a fallback when all of the face's axis value names were elided.
+    ///
+    /// Available only since table version 1.1.
+    pub elided_fallback_name_id: Option<u16>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let major_version = s.read::<u16>()?;
+        let minor_version = s.read::<u16>()?;
+        if major_version != 1 {
+            return None;
+        }
+
+        let design_axis_size = s.read::<u16>()?;
+        let design_axis_count = s.read::<u16>()?;
+        let design_axes_offset = s.read::<Offset32>()?;
+        let axis_value_count = s.read::<u16>()?;
+        let axis_value_offsets_offset = s.read::<Offset32>()?;
+        let elided_fallback_name_id = if minor_version > 0 {
+            s.read::<u16>()
+        } else {
+            None
+        };
+
+        if design_axis_size < AxisRecord::SIZE as u16 {
+            return None;
+        }
+
+        let axes = if design_axis_count != 0 {
+            let len = usize::from(design_axis_count) * usize::from(design_axis_size);
+            let start = design_axes_offset.to_usize();
+            AxisRecords {
+                data: data.get(start..start.checked_add(len)?)?,
+                record_size: design_axis_size,
+                count: design_axis_count,
+            }
+        } else {
+            AxisRecords::default()
+        };
+
+        let axis_values = if axis_value_count != 0 {
+            let data = data.get(axis_value_offsets_offset.to_usize()..)?;
+            let offsets = Stream::new(data).read_array16(axis_value_count)?;
+            LazyOffsetArray16::new(data, offsets)
+        } else {
+            LazyOffsetArray16::new(&[], LazyArray16::default())
+        };
+
+        Some(Table {
+            axes,
+            axis_values,
+            elided_fallback_name_id,
+        })
+    }
+
+    /// Returns an axis value table that describes a value on the specified axis.
+    ///
+    /// Only single-axis tables (formats 1, 2 and 3) are checked.
+    /// Tables with an exact value match (formats 1 and 3) have a priority
+    /// over ranges (format 2). Ranges are inclusive.
+    pub fn axis_value(&self, tag: Tag, value: f32) -> Option<AxisValue<'a>> {
+        let axis_index = self.axes.into_iter().position(|axis| axis.tag == tag)?;
+        let axis_index = u16::try_from(axis_index).ok()?;
+
+        let mut range = None;
+        // Tables with an unknown format are skipped, therefore we cannot use an iterator here.
+        for i in 0..self.axis_values.len() {
+            let axis_value = match self.axis_values.get(i) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            match axis_value {
+                AxisValue::Format1(v) if v.axis_index == axis_index && v.value == value => {
+                    return Some(axis_value);
+                }
+                AxisValue::Format3(v) if v.axis_index == axis_index && v.value == value => {
+                    return Some(axis_value);
+                }
+                AxisValue::Format2(v)
+                    if range.is_none()
+                        && v.axis_index == axis_index
+                        && v.range_min_value <= value
+                        && value <= v.range_max_value =>
+                {
+                    range = Some(axis_value);
+                }
+                _ => {}
+            }
+        }
+
+        range
+    }
+}
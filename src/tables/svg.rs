@@ -26,6 +26,27 @@ impl FromData for SvgDocumentRecord {
     }
 }
 
+/// An SVG document.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SvgDocument<'a> {
+    /// The first glyph ID covered by this document.
+    pub start_glyph_id: GlyphId,
+    /// The last glyph ID covered by this document. Inclusive.
+    pub end_glyph_id: GlyphId,
+    /// An SVG document data.
+    ///
+    /// Can be gzip-compressed (SVGZ).
+    pub data: &'a [u8],
+}
+
+impl SvgDocument<'_> {
+    /// Returns the range of glyph IDs covered by this document.
+    #[inline]
+    pub fn glyphs_range(&self) -> core::ops::RangeInclusive<GlyphId> {
+        self.start_glyph_id..=self.end_glyph_id
+    }
+}
+
 /// A list of [SVG documents](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/svg#svg-document-list).
 #[derive(Clone, Copy)]
@@ -49,11 +70,43 @@ impl<'a> SvgDocumentsList<'a> {
     /// Returns a SVG document data by glyph ID.
     #[inline]
     pub fn find(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+        self.find_document(glyph_id).map(|doc| doc.data)
+    }
+
+    /// Returns SVG document at index, including the range of glyphs it covers.
+    ///
+    /// `index` is not a GlyphId. You should use
+    /// [`find_document()`](SvgDocumentsList::find_document) instead.
+    #[inline]
+    pub fn document(&self, index: u16) -> Option<SvgDocument<'a>> {
+        let record = self.records.get(index)?;
+        Some(SvgDocument {
+            start_glyph_id: record.start_glyph_id,
+            end_glyph_id: record.end_glyph_id,
+            data: self.get(index)?,
+        })
+    }
+
+    /// Returns a SVG document by glyph ID, including the range of glyphs it covers.
+    ///
+    /// A single document can define multiple glyphs,
+    /// so it can be rendered once and reused for the whole range.
+    #[inline]
+    pub fn find_document(&self, glyph_id: GlyphId) -> Option<SvgDocument<'a>> {
         let index = self
             .records
             .into_iter()
             .position(|v| (v.start_glyph_id..=v.end_glyph_id).contains(&glyph_id))?;
-        self.get(index as u16)
+        self.document(index as u16)
+    }
+
+    /// Returns an iterator over SVG documents, including the range of glyphs they cover.
+    #[inline]
+    pub fn documents(&self) -> SvgDocumentsIter<'a> {
+        SvgDocumentsIter {
+            list: *self,
+            index: 0,
+        }
     }
 
     /// Returns the number of SVG documents in the list.
@@ -113,6 +166,33 @@ impl<'a> Iterator for SvgDocumentsListIter<'a> {
     }
 }
 
+/// An iterator over [`SvgDocument`]s.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct SvgDocumentsIter<'a> {
+    list: SvgDocumentsList<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for SvgDocumentsIter<'a> {
+    type Item = SvgDocument<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.list.len() {
+            self.index += 1;
+            self.list.document(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.list.len().saturating_sub(self.index))
+    }
+}
+
 /// An [SVG Table](https://docs.microsoft.com/en-us/typography/opentype/spec/svg).
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
@@ -139,3 +219,95 @@ impl<'a> Table<'a> {
         })
     }
 }
+
+/// The maximum size of a decompressed SVGZ document.
+///
+/// Protects from decompression bombs, since a small gzip stream can inflate to gigabytes.
+/// See [`Face::glyph_svg_image_decompressed`](crate::Face::glyph_svg_image_decompressed).
+#[cfg(feature = "svgz")]
+pub const MAX_DECOMPRESSED_SIZE: usize = 16 * 1024 * 1024;
+
+/// Checks that an SVG document is gzip-compressed (SVGZ).
+#[cfg(feature = "svgz")]
+#[inline]
+pub(crate) fn is_gzip(data: &[u8]) -> bool {
+    data.starts_with(&[0x1F, 0x8B])
+}
+
+/// Returns a raw deflate stream from a gzip member.
+///
+/// https://www.rfc-editor.org/rfc/rfc1952#page-5
+#[cfg(feature = "svgz")]
+pub(crate) fn gzip_deflate_stream(data: &[u8]) -> Option<&[u8]> {
+    const FHCRC: u8 = 1 << 1;
+    const FEXTRA: u8 = 1 << 2;
+    const FNAME: u8 = 1 << 3;
+    const FCOMMENT: u8 = 1 << 4;
+    const DEFLATE: u8 = 8;
+
+    let mut s = Stream::new(data);
+    s.skip::<u16>(); // magic
+    if s.read::<u8>()? != DEFLATE {
+        return None;
+    }
+
+    let flags = s.read::<u8>()?;
+    s.skip::<u32>(); // modification time
+    s.skip::<u8>(); // extra flags
+    s.skip::<u8>(); // operating system
+
+    if flags & FEXTRA != 0 {
+        // Unlike the rest of the font, gzip is little-endian.
+        let len = s.read_bytes(2)?;
+        s.advance_checked(usize::from(u16::from_le_bytes([len[0], len[1]])))?;
+    }
+
+    // Zero-terminated strings.
+    for flag in &[FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            while s.read::<u8>()? != 0 {}
+        }
+    }
+
+    if flags & FHCRC != 0 {
+        s.skip::<u16>();
+    }
+
+    // The stream is followed by a CRC-32 and the uncompressed size, which we ignore.
+    s.tail()
+}
+
+/// Inflates a raw deflate stream into `out`, reusing its allocation.
+///
+/// `out` must be empty. Fails when the result is larger than [`MAX_DECOMPRESSED_SIZE`].
+#[cfg(feature = "svgz")]
+pub(crate) fn inflate(mut stream: &[u8], out: &mut std::vec::Vec<u8>) -> Option<()> {
+    use miniz_oxide::inflate::core::{decompress, inflate_flags, DecompressorOxide};
+    use miniz_oxide::inflate::TINFLStatus;
+
+    // The whole output is kept in a single buffer, so matches can refer to any previous data.
+    let flags = inflate_flags::TINFL_FLAG_USING_NON_WRAPPING_OUTPUT_BUF;
+    let len = core::cmp::max(out.capacity(), stream.len().saturating_mul(2));
+    out.resize(core::cmp::min(len, MAX_DECOMPRESSED_SIZE), 0);
+
+    let mut decompressor = std::boxed::Box::<DecompressorOxide>::default();
+    let mut out_pos = 0;
+    loop {
+        let (status, in_consumed, out_consumed) =
+            decompress(&mut decompressor, stream, out, out_pos, flags);
+        out_pos += out_consumed;
+
+        match status {
+            TINFLStatus::Done => {
+                out.truncate(out_pos);
+                return Some(());
+            }
+            TINFLStatus::HasMoreOutput if out.len() < MAX_DECOMPRESSED_SIZE => {
+                stream = stream.get(in_consumed..)?;
+                let len = core::cmp::max(out.len().saturating_mul(2), 64);
+                out.resize(core::cmp::min(len, MAX_DECOMPRESSED_SIZE), 0);
+            }
+            _ => return None,
+        }
+    }
+}
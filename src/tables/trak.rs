@@ -1,7 +1,7 @@
 //! A [Tracking Table](
 //! https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6trak.html) implementation.
 
-use crate::parser::{Fixed, FromData, LazyArray16, Offset, Offset16, Offset32, Stream};
+use crate::parser::{Fixed, FromData, LazyArray16, Offset, Offset16, Offset32, Stream, TryNumFrom};
 
 #[derive(Clone, Copy, Debug)]
 struct TrackTableRecord {
@@ -130,6 +130,62 @@ impl<'a> TrackData<'a> {
 
         Some(TrackData { tracks, sizes })
     }
+
+    /// Returns a tracking value for the specified track and size.
+    ///
+    /// The track with the nearest value is used.
+    /// `ppem` is in the same units as [`sizes`](Self::sizes).
+    /// Values between sizes are linearly interpolated,
+    /// while sizes outside the table's range are clamped to the first/last one.
+    ///
+    /// Returns `None` when there are no tracks or sizes.
+    pub fn tracking(&self, track: f32, ppem: f32) -> Option<i16> {
+        let mut nearest: Option<Track> = None;
+        for t in self.tracks {
+            let is_closer = match nearest {
+                Some(n) => distance(t.value, track) < distance(n.value, track),
+                None => true,
+            };
+
+            if is_closer {
+                nearest = Some(t);
+            }
+        }
+
+        let values = nearest?.values;
+        let last_index = self.sizes.len().checked_sub(1)?;
+        if ppem <= self.sizes.get(0)?.0 {
+            return values.get(0);
+        } else if ppem >= self.sizes.get(last_index)?.0 {
+            return values.get(last_index);
+        }
+
+        // `ppem` is within the range, therefore the upper size is always present.
+        let index = self.sizes.into_iter().position(|size| size.0 >= ppem)? as u16;
+        let s0 = self.sizes.get(index - 1)?.0;
+        let s1 = self.sizes.get(index)?.0;
+        let v0 = f32::from(values.get(index - 1)?);
+        let v1 = f32::from(values.get(index)?);
+
+        let t = if s0 == s1 {
+            0.0
+        } else {
+            (ppem - s0) / (s1 - s0)
+        };
+        let v = v0 + t * (v1 - v0);
+        // We can't use `round()` in `no_std`, so this is the next best thing.
+        let v = if v < 0.0 { v - 0.5 } else { v + 0.5 };
+        i16::try_num_from(v)
+    }
+}
+
+#[inline]
+fn distance(a: f32, b: f32) -> f32 {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
 }
 
 /// A [Tracking Table](
@@ -178,4 +234,20 @@ impl<'a> Table<'a> {
             vertical,
         })
     }
+
+    /// Returns a horizontal tracking value for the specified track and size.
+    ///
+    /// See [`TrackData::tracking`] for details.
+    #[inline]
+    pub fn horizontal_tracking(&self, track: f32, ppem: f32) -> Option<i16> {
+        self.horizontal.tracking(track, ppem)
+    }
+
+    /// Returns a vertical tracking value for the specified track and size.
+    ///
+    /// See [`TrackData::tracking`] for details.
+    #[inline]
+    pub fn vertical_tracking(&self, track: f32, ppem: f32) -> Option<i16> {
+        self.vertical.tracking(track, ppem)
+    }
 }
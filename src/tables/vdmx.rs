@@ -0,0 +1,162 @@
+//! A [Vertical Device Metrics Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx) implementation.
+
+use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
+
+/// A [ratio range record](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx#ratio-range-record).
+#[derive(Clone, Copy, Debug)]
+pub struct RatioRange {
+    /// A character set.
+    pub char_set: u8,
+    /// A value to use for x-ratio.
+    pub x_ratio: u8,
+    /// A starting y-ratio value.
+    pub y_start_ratio: u8,
+    /// An ending y-ratio value.
+    pub y_end_ratio: u8,
+}
+
+impl RatioRange {
+    /// Checks that the range contains the specified aspect ratio.
+    ///
+    /// A range with all ratios set to zero matches any aspect ratio.
+    #[inline]
+    pub fn contains(&self, x_ratio: u8, y_ratio: u8) -> bool {
+        if self.x_ratio == 0 && self.y_start_ratio == 0 && self.y_end_ratio == 0 {
+            return true;
+        }
+
+        // Compare y_ratio / x_ratio with y_start_ratio / x_ratio and y_end_ratio / x_ratio.
+        let y = u16::from(y_ratio) * u16::from(self.x_ratio);
+        let y_start = u16::from(self.y_start_ratio) * u16::from(x_ratio);
+        let y_end = u16::from(self.y_end_ratio) * u16::from(x_ratio);
+        y_start <= y && y <= y_end
+    }
+}
+
+impl FromData for RatioRange {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(RatioRange {
+            char_set: s.read::<u8>()?,
+            x_ratio: s.read::<u8>()?,
+            y_start_ratio: s.read::<u8>()?,
+            y_end_ratio: s.read::<u8>()?,
+        })
+    }
+}
+
+/// A vertical metrics record.
+#[derive(Clone, Copy, Debug)]
+pub struct Record {
+    /// A pixel size, as ppem.
+    pub pel_height: u16,
+    /// Maximum value, in pixels, for this height.
+    pub y_max: i16,
+    /// Minimum value, in pixels, for this height.
+    pub y_min: i16,
+}
+
+impl FromData for Record {
+    const SIZE: usize = 6;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(Record {
+            pel_height: s.read::<u16>()?,
+            y_max: s.read::<i16>()?,
+            y_min: s.read::<i16>()?,
+        })
+    }
+}
+
+/// A [VDMX group](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx#vdmx-group).
+#[derive(Clone, Copy, Debug)]
+pub struct Group<'a> {
+    /// The first pixel size in the group.
+    pub start_size: u8,
+    /// The last pixel size in the group.
+    pub end_size: u8,
+    /// A list of records sorted by `pel_height`.
+    pub records: LazyArray16<'a, Record>,
+}
+
+impl<'a> Group<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let count = s.read::<u16>()?;
+        let start_size = s.read::<u8>()?;
+        let end_size = s.read::<u8>()?;
+        let records = s.read_array16::<Record>(count)?;
+        Some(Group {
+            start_size,
+            end_size,
+            records,
+        })
+    }
+
+    /// Returns a record for the specified pixel size.
+    #[inline]
+    pub fn get(&self, ppem: u16) -> Option<Record> {
+        self.records
+            .binary_search_by(|record| record.pel_height.cmp(&ppem))
+            .map(|(_, record)| record)
+    }
+}
+
+/// A [Vertical Device Metrics Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of ratio ranges.
+    pub ratios: LazyArray16<'a, RatioRange>,
+    offsets: LazyArray16<'a, Offset16>,
+    data: &'a [u8],
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        s.skip::<u16>(); // number of groups
+        let count = s.read::<u16>()?;
+        let ratios = s.read_array16::<RatioRange>(count)?;
+        let offsets = s.read_array16::<Offset16>(count)?;
+        Some(Table {
+            ratios,
+            offsets,
+            data,
+        })
+    }
+
+    /// Returns a group for the ratio range at index.
+    ///
+    /// Multiple ratio ranges can reference the same group.
+    pub fn group(&self, index: u16) -> Option<Group<'a>> {
+        let offset = self.offsets.get(index)?;
+        Group::parse(self.data.get(offset.to_usize()..)?)
+    }
+
+    /// Returns a group for the specified aspect ratio.
+    ///
+    /// The first matching ratio range is used.
+    pub fn find_group(&self, x_ratio: u8, y_ratio: u8) -> Option<Group<'a>> {
+        let (index, _) = self
+            .ratios
+            .into_iter()
+            .enumerate()
+            .find(|(_, ratio)| ratio.contains(x_ratio, y_ratio))?;
+        self.group(index as u16)
+    }
+}
@@ -55,11 +55,26 @@ impl<'a> Table<'a> {
         Some(Table { default_y, metrics })
     }
 
+    /// Returns the default Y origin.
+    ///
+    /// Used for glyphs without an explicit entry.
+    #[inline]
+    pub fn default_y_origin(&self) -> i16 {
+        self.default_y
+    }
+
     /// Returns glyph's Y origin.
+    ///
+    /// Fallbacks to [`default_y_origin`](Self::default_y_origin) when glyph has no explicit entry.
     pub fn glyph_y_origin(&self, glyph_id: GlyphId) -> i16 {
+        self.glyph_y_origin_explicit(glyph_id)
+            .unwrap_or(self.default_y)
+    }
+
+    /// Returns glyph's Y origin only when glyph has an explicit entry.
+    pub fn glyph_y_origin_explicit(&self, glyph_id: GlyphId) -> Option<i16> {
         self.metrics
             .binary_search_by(|m| m.glyph_id.cmp(&glyph_id))
             .map(|(_, m)| m.y)
-            .unwrap_or(self.default_y)
     }
 }
@@ -2,6 +2,8 @@
 //!
 //! <https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store>
 
+use core::convert::TryFrom;
+
 use crate::parser::{FromData, LazyArray16, NumFrom, Stream};
 use crate::NormalizedCoordinate;
 
@@ -109,6 +111,57 @@ impl<'a> ItemVariationStore<'a> {
     }
 }
 
+/// A [Delta-Set Index Mapping](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data).
+pub(crate) struct DeltaSetIndexMap<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DeltaSetIndexMap<'a> {
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        DeltaSetIndexMap { data }
+    }
+
+    /// Returns outer and inner indices for an item.
+    pub fn map(&self, mut index: u32) -> Option<(u16, u16)> {
+        let mut s = Stream::new(self.data);
+        let format = s.read::<u8>()?;
+        let entry_format = s.read::<u8>()?;
+        let map_count = match format {
+            0 => u32::from(s.read::<u16>()?),
+            1 => s.read::<u32>()?,
+            _ => return None,
+        };
+
+        if map_count == 0 {
+            return None;
+        }
+
+        // 'If a given glyph ID is greater than mapCount-1, then the last entry is used.'
+        if index >= map_count {
+            index = map_count - 1;
+        }
+
+        let entry_size = usize::from(((entry_format >> 4) & 3) + 1);
+        let inner_index_bit_count = u32::from((entry_format & 0xF) + 1);
+
+        s.advance_checked(entry_size.checked_mul(usize::num_from(index))?)?;
+
+        let mut n = 0u32;
+        for b in s.read_bytes(entry_size)? {
+            n = (n << 8) + u32::from(*b);
+        }
+
+        let outer_index = n >> inner_index_bit_count;
+        let inner_index = n & ((1 << inner_index_bit_count) - 1);
+        Some((
+            u16::try_from(outer_index).ok()?,
+            u16::try_from(inner_index).ok()?,
+        ))
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct VariationRegionList<'a> {
     axis_count: u16,
@@ -0,0 +1,120 @@
+#![cfg(feature = "serde")]
+
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+use ttf_parser::{NormalizedCoordinate, Rect, Style, Tag, Weight, Width};
+
+#[test]
+fn tag() {
+    assert_tokens(&Tag::from_bytes(b"wght"), &[Token::Str("wght")]);
+    assert_tokens(&Tag::from_bytes(b"cv01"), &[Token::Str("cv01")]);
+    assert_tokens(&Tag::from_bytes(b"OS/2"), &[Token::Str("OS/2")]);
+    // Latin-1 characters are stored as a single byte.
+    assert_tokens(&Tag::from_bytes(b"ab\xE9d"), &[Token::Str("abéd")]);
+}
+
+#[test]
+fn tag_with_invalid_length() {
+    assert_de_tokens_error::<Tag>(
+        &[Token::Str("wgh")],
+        "invalid length 3, expected a 4-character tag",
+    );
+    assert_de_tokens_error::<Tag>(
+        &[Token::Str("wghts")],
+        "invalid length 5, expected a 4-character tag",
+    );
+    assert_de_tokens_error::<Tag>(
+        &[Token::Str("")],
+        "invalid length 0, expected a 4-character tag",
+    );
+}
+
+#[test]
+fn tag_with_non_latin1_chars() {
+    assert_de_tokens_error::<Tag>(
+        &[Token::Str("wgh€")],
+        "invalid value: string \"wgh€\", expected a 4-character tag",
+    );
+}
+
+#[test]
+fn normalized_coordinate() {
+    assert_tokens(&NormalizedCoordinate::from(-8192i16), &[Token::I16(-8192)]);
+
+    // Deserialized like `From<i16>`, therefore clamped.
+    assert_de_tokens(&NormalizedCoordinate::from(16384i16), &[Token::I16(20000)]);
+    assert_de_tokens(
+        &NormalizedCoordinate::from(-16384i16),
+        &[Token::I16(-20000)],
+    );
+}
+
+#[test]
+fn rect() {
+    let rect = Rect {
+        x_min: -10,
+        y_min: -20,
+        x_max: 30,
+        y_max: 40,
+    };
+    assert_tokens(
+        &rect,
+        &[
+            Token::Struct {
+                name: "Rect",
+                len: 4,
+            },
+            Token::Str("x_min"),
+            Token::I16(-10),
+            Token::Str("y_min"),
+            Token::I16(-20),
+            Token::Str("x_max"),
+            Token::I16(30),
+            Token::Str("y_max"),
+            Token::I16(40),
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn weight() {
+    assert_tokens(
+        &Weight::Bold,
+        &[Token::UnitVariant {
+            name: "Weight",
+            variant: "Bold",
+        }],
+    );
+    assert_tokens(
+        &Weight::Other(450),
+        &[
+            Token::NewtypeVariant {
+                name: "Weight",
+                variant: "Other",
+            },
+            Token::U16(450),
+        ],
+    );
+}
+
+#[test]
+fn width() {
+    assert_tokens(
+        &Width::SemiCondensed,
+        &[Token::UnitVariant {
+            name: "Width",
+            variant: "SemiCondensed",
+        }],
+    );
+}
+
+#[test]
+fn style() {
+    assert_tokens(
+        &Style::Italic,
+        &[Token::UnitVariant {
+            name: "Style",
+            variant: "Italic",
+        }],
+    );
+}
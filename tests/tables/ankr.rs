@@ -131,4 +131,6 @@ fn two_glyphs() {
     assert_eq!(points.get(0).unwrap(), Point { x: -5, y: 11 });
     let points = table.points(GlyphId(1)).unwrap();
     assert_eq!(points.get(0).unwrap(), Point { x: 40, y: 10 });
+    // Not in the lookup table.
+    assert!(table.points(GlyphId(2)).is_none());
 }
@@ -0,0 +1,113 @@
+use ttf_parser::avar::Table;
+use ttf_parser::NormalizedCoordinate;
+use crate::{convert, Unit::*};
+
+fn avar_data() -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        UInt16(0), // reserved
+        UInt16(2), // axis count
+
+        // Segment map [0]
+        UInt16(3), // position map count
+        Int16(-16384), Int16(-16384), // axis value map [0]
+        Int16(0), Int16(0), // axis value map [1]
+        Int16(16384), Int16(16384), // axis value map [2]
+
+        // Segment map [1]
+        UInt16(4), // position map count
+        Int16(-16384), Int16(-16384), // axis value map [0]
+        Int16(0), Int16(0), // axis value map [1]
+        Int16(8192), Int16(12288), // axis value map [2]
+        Int16(16384), Int16(16384), // axis value map [3]
+    ])
+}
+
+#[test]
+fn segment_maps() {
+    let data = avar_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.segment_maps.len(), 2);
+
+    let maps: Vec<Vec<(i16, i16)>> = table
+        .segment_maps
+        .into_iter()
+        .map(|map| map.into_iter().map(|v| (v.from_coordinate, v.to_coordinate)).collect())
+        .collect();
+
+    assert_eq!(maps, vec![
+        vec![(-16384, -16384), (0, 0), (16384, 16384)],
+        vec![(-16384, -16384), (0, 0), (8192, 12288), (16384, 16384)],
+    ]);
+}
+
+#[test]
+fn map_coordinates() {
+    let data = avar_data();
+    let table = Table::parse(&data).unwrap();
+
+    let mut coords = [NormalizedCoordinate::from(8192), NormalizedCoordinate::from(4096)];
+    table.map_coordinates(&mut coords).unwrap();
+    assert_eq!(coords[0].get(), 8192);
+    assert_eq!(coords[1].get(), 6144);
+
+    // The number of coordinates must match the number of segment maps.
+    let mut coords = [NormalizedCoordinate::from(8192)];
+    assert!(table.map_coordinates(&mut coords).is_none());
+}
+
+#[test]
+fn version2() {
+    let data = convert(&[
+        Fixed(2.0), // version
+        UInt16(0), // reserved
+        UInt16(1), // axis count
+
+        // Segment map [0]
+        UInt16(0), // position map count
+
+        UInt32(50), // offset to axis index map
+        UInt32(18), // offset to item variation store
+
+        // Item variation store
+        UInt16(1), // format
+        UInt32(12), // offset to variation region list
+        UInt16(1), // item variation data count
+        UInt32(22), // offset to item variation data [0]
+
+        // Variation region list
+        UInt16(1), // axis count
+        UInt16(1), // region count
+        Int16(0), Int16(16384), Int16(16384), // region [0]: start, peak, end
+
+        // Item variation data [0]
+        UInt16(1), // item count
+        UInt16(1), // short delta count
+        UInt16(1), // region index count
+        UInt16(0), // region index [0]
+        Int16(2000), // delta set [0]
+
+        // Axis index map
+        UInt8(0), // format
+        UInt8(0), // entry format: 1 byte entries, 1 inner index bit
+        UInt16(1), // map count
+        UInt8(0), // entry [0]: outer 0, inner 0
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.segment_maps.len(), 1);
+
+    let mut coords = [NormalizedCoordinate::from(8192)];
+    table.map_coordinates(&mut coords).unwrap();
+    assert_eq!(coords[0].get(), 9192);
+
+    // Clamped.
+    let mut coords = [NormalizedCoordinate::from(16384)];
+    table.map_coordinates(&mut coords).unwrap();
+    assert_eq!(coords[0].get(), 16384);
+
+    // Outside of the region.
+    let mut coords = [NormalizedCoordinate::from(-8192)];
+    table.map_coordinates(&mut coords).unwrap();
+    assert_eq!(coords[0].get(), -8192);
+}
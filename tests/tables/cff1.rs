@@ -2,7 +2,7 @@
 
 use std::fmt::Write;
 
-use ttf_parser::{cff, GlyphId, CFFError, Rect};
+use ttf_parser::{cff, ControlFlow, GlyphId, CFFError, Rect};
 
 struct Builder(String);
 impl ttf_parser::OutlineBuilder for Builder {
@@ -65,6 +65,7 @@ mod top_dict_operator {
     pub const CHARSET_OFFSET: u16               = 15;
     pub const CHAR_STRINGS_OFFSET: u16          = 17;
     pub const PRIVATE_DICT_SIZE_AND_OFFSET: u16 = 18;
+    pub const CHARSTRING_TYPE: u16              = 1206;
     pub const ROS: u16                          = 1230;
     pub const FD_ARRAY: u16                     = 1236;
     pub const FD_SELECT: u16                    = 1237;
@@ -364,6 +365,7 @@ fn non_default_header_size() {
     let mut builder = Builder(String::new());
     let rect = table.outline(GlyphId(0), &mut builder).unwrap();
 
+    assert_eq!(table.charstring_type(), 2);
     assert_eq!(builder.0, "M 10 0 Z ");
     assert_eq!(rect, Rect { x_min: 10, y_min: 0, x_max: 10, y_max: 0 });
 }
@@ -927,6 +929,77 @@ fn recursive_mixed_subr() {
     assert_eq!(res.unwrap_err(), CFFError::NestingLimitReached);
 }
 
+// Stops after the specified number of segments.
+struct StoppableBuilder(String, usize);
+
+impl StoppableBuilder {
+    fn flow(&mut self) -> ControlFlow {
+        self.1 -= 1;
+        if self.1 == 0 { ControlFlow::Break } else { ControlFlow::Continue }
+    }
+}
+
+impl ttf_parser::StoppableOutlineBuilder for StoppableBuilder {
+    fn move_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "M {} {} ", x, y).unwrap();
+        self.flow()
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "L {} {} ", x, y).unwrap();
+        self.flow()
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+        self.flow()
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+        self.flow()
+    }
+
+    fn close(&mut self) -> ControlFlow {
+        write!(&mut self.0, "Z ").unwrap();
+        self.flow()
+    }
+}
+
+#[test]
+fn stoppable_outline_returns_early() {
+    // The data after the stop point is malformed and must not be parsed.
+    let data = gen_cff(
+        &[],
+        &[&[
+            CFFInt(30),
+            CFFInt(40),
+            UInt8(operator::LINE_TO),
+            UInt8(0), // reserved operator
+        ]],
+        &[
+            CFFInt(10),
+            UInt8(operator::HORIZONTAL_MOVE_TO),
+            CFFInt(0 - 107), // subr index - subr bias
+            UInt8(operator::CALL_LOCAL_SUBROUTINE),
+        ]
+    );
+
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(ttf_parser::RawFaceTables {
+        cff: Some(&data),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let mut builder = Builder(String::new());
+    assert_eq!(face.outline_glyph(GlyphId(0), &mut builder), None);
+
+    let mut builder = StoppableBuilder(String::new(), 2);
+    let rect = face.outline_glyph_stoppable(GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 10 0 L 40 40 ");
+    assert_eq!(rect, Rect { x_min: 10, y_min: 0, x_max: 40, y_max: 40 });
+}
+
 #[test]
 fn zero_char_string_offset() {
     let data = convert(&[
@@ -979,6 +1052,56 @@ fn invalid_char_string_offset() {
     assert!(cff::Table::parse(&data).is_none());
 }
 
+#[test]
+fn unsupported_charstring_type() {
+    let data = convert(&[
+        // Header
+        UInt8(1), // major version
+        UInt8(0), // minor version
+        UInt8(4), // header size
+        UInt8(0), // absolute offset
+
+        // Name INDEX
+        UInt16(0), // count
+
+        // Top DICT
+        // INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(6), // index[1]
+        // Data
+        CFFInt(1),
+        UInt8(12),
+        UInt8((top_dict_operator::CHARSTRING_TYPE - 1200) as u8),
+        CFFInt(20),
+        UInt8(top_dict_operator::CHAR_STRINGS_OFFSET as u8),
+
+        // String INDEX
+        UInt16(0), // count
+
+        // Global Subroutines INDEX
+        UInt16(0), // count
+
+        // CharString INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(4), // index[1]
+        // Data
+        CFFInt(10),
+        UInt8(operator::HORIZONTAL_MOVE_TO),
+        UInt8(operator::ENDCHAR),
+    ]);
+
+    let table = cff::Table::parse(&data).unwrap();
+    assert_eq!(table.charstring_type(), 1);
+
+    let mut builder = Builder(String::new());
+    let res = table.outline(GlyphId(0), &mut builder);
+    assert_eq!(res.unwrap_err(), CFFError::UnsupportedCharstringType);
+}
+
 // TODO: return from main
 // TODO: return without endchar
 // TODO: data after return
@@ -996,3 +1119,166 @@ fn invalid_char_string_offset() {
 // TODO: FLEX
 // TODO: HFLEX1
 // TODO: FLEX1
+
+#[test]
+fn cid_keyed() {
+    let data = convert(&[
+        // Header
+        UInt8(1), // major version
+        UInt8(0), // minor version
+        UInt8(4), // header size
+        UInt8(0), // absolute offset
+
+        // Name INDEX
+        UInt16(0), // count
+
+        // Top DICT
+        // INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(16), // index[1]
+        // Data
+        CFFInt(0), // registry
+        CFFInt(0), // ordering
+        CFFInt(0), // supplement
+        UInt8(12), UInt8(30), // ROS
+        CFFInt(40),
+        UInt8(top_dict_operator::CHARSET_OFFSET as u8),
+        CFFInt(45),
+        UInt8(12), UInt8(37), // FDSelect
+        CFFInt(56),
+        UInt8(12), UInt8(36), // FDArray
+        CFFInt(30),
+        UInt8(top_dict_operator::CHAR_STRINGS_OFFSET as u8),
+
+        // String INDEX
+        UInt16(0), // count
+
+        // Global Subroutines INDEX
+        UInt16(0), // count
+
+        // CharString INDEX
+        UInt16(3), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(2), // index[1]
+        UInt8(3), // index[2]
+        UInt8(4), // index[3]
+        // Data
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+
+        // Charset
+        UInt8(0), // format
+        UInt16(100), // CID [1]
+        UInt16(200), // CID [2]
+
+        // FDSelect
+        UInt8(3), // format
+        UInt16(2), // number of ranges
+        UInt16(0), // first glyph [0]
+        UInt8(0), // FD index [0]
+        UInt16(2), // first glyph [1]
+        UInt8(1), // FD index [1]
+        UInt16(3), // sentinel
+
+        // FDArray INDEX
+        UInt16(2), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(1), // index[1]
+        UInt8(1), // index[2]
+    ]);
+
+    let table = cff::Table::parse(&data).unwrap();
+    assert!(table.is_cid_keyed());
+    assert_eq!(table.glyph_fd_index(GlyphId(0)), Some(0));
+    assert_eq!(table.glyph_fd_index(GlyphId(1)), Some(0));
+    assert_eq!(table.glyph_fd_index(GlyphId(2)), Some(1));
+    assert_eq!(table.glyph_fd_index(GlyphId(3)), None);
+    assert_eq!(table.glyph_cid(GlyphId(0)), Some(0));
+    assert_eq!(table.glyph_cid(GlyphId(1)), Some(100));
+    assert_eq!(table.glyph_cid(GlyphId(2)), Some(200));
+    assert_eq!(table.glyph_cid(GlyphId(3)), None);
+    assert_eq!(table.glyph_names().collect::<Vec<_>>(), vec![None, None, None]);
+}
+
+#[test]
+fn glyph_names() {
+    let data = convert(&[
+        // Header
+        UInt8(1), // major version
+        UInt8(0), // minor version
+        UInt8(4), // header size
+        UInt8(0), // absolute offset
+
+        // Name INDEX
+        UInt16(0), // count
+
+        // Top DICT
+        // INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(5), // index[1]
+        // Data
+        CFFInt(43),
+        UInt8(top_dict_operator::CHARSET_OFFSET as u8),
+        CFFInt(29),
+        UInt8(top_dict_operator::CHAR_STRINGS_OFFSET as u8),
+
+        // String INDEX
+        UInt16(2), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(4), // index[1]
+        UInt8(7), // index[2]
+        // Data
+        Raw(b"foo"),
+        Raw(b"b\xFFr"), // not UTF-8
+
+        // Global Subroutines INDEX
+        UInt16(0), // count
+
+        // CharString INDEX
+        UInt16(5), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(2), // index[1]
+        UInt8(3), // index[2]
+        UInt8(4), // index[3]
+        UInt8(5), // index[4]
+        UInt8(6), // index[5]
+        // Data
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+
+        // Charset
+        UInt8(1), // format
+        UInt16(391), // first SID [0]
+        UInt8(1), // left [0]
+        UInt16(1), // first SID [1]
+        UInt8(1), // left [1]
+    ]);
+
+    let table = cff::Table::parse(&data).unwrap();
+    let names: Vec<_> = table.glyph_names().collect();
+    assert_eq!(names, vec![Some(".notdef"), Some("foo"), None, Some("space"), Some("exclam")]);
+    for (i, name) in names.iter().enumerate() {
+        assert_eq!(table.glyph_name(GlyphId(i as u16)), *name);
+    }
+}
+
+#[test]
+fn sid_keyed() {
+    let data = gen_cff(&[], &[], &[UInt8(operator::ENDCHAR)]);
+    let table = cff::Table::parse(&data).unwrap();
+    assert!(!table.is_cid_keyed());
+    assert_eq!(table.glyph_fd_index(GlyphId(0)), None);
+    assert_eq!(table.glyph_cid(GlyphId(0)), None);
+}
@@ -553,3 +553,590 @@ mod format4 {
         assert_eq!(vec, [27, 28, 29, 30, 31, 32, 33, 34, 65533, 65534, 65535]);
     }
 }
+
+mod format14 {
+    use ttf_parser::cmap::{self, GlyphVariationResult, UVSMappingRecord, UnicodeRangeRecord};
+    use ttf_parser::GlyphId;
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn variation_selectors() {
+        let data = convert(&[
+            UInt16(14), // format
+            UInt32(58), // subtable size
+            UInt32(2), // number of records
+            // Record [0]
+            Raw(&[0x00, 0xFE, 0x00]), // variation selector
+            UInt32(32), // offset to default UVS
+            UInt32(40), // offset to non-default UVS
+            // Record [1]
+            Raw(&[0x0E, 0x01, 0x00]), // variation selector
+            UInt32(0), // offset to default UVS
+            UInt32(49), // offset to non-default UVS
+            // Default UVS [0]
+            UInt32(1), // number of ranges
+            Raw(&[0x00, 0x4E, 0x00]), // start Unicode value
+            UInt8(2), // additional count
+            // Non-default UVS [0]
+            UInt32(1), // number of mappings
+            Raw(&[0x00, 0x00, 0x30]), // Unicode value
+            UInt16(5), // glyph ID
+            // Non-default UVS [1]
+            UInt32(1), // number of mappings
+            Raw(&[0x00, 0x4E, 0x01]), // Unicode value
+            UInt16(7), // glyph ID
+        ]);
+
+        let subtable = cmap::Subtable14::parse(&data).unwrap();
+        assert_eq!(subtable.variation_selectors().collect::<Vec<_>>(), vec![0xFE00, 0xE0100]);
+
+        let ranges: Vec<_> = subtable.default_uvs(0xFE00).unwrap().into_iter().collect();
+        assert_eq!(ranges, vec![UnicodeRangeRecord { start_unicode_value: 0x4E00, additional_count: 2 }]);
+        let mappings: Vec<_> = subtable.non_default_uvs(0xFE00).unwrap().into_iter().collect();
+        assert_eq!(mappings, vec![UVSMappingRecord { unicode_value: 0x30, glyph_id: GlyphId(5) }]);
+
+        assert!(subtable.default_uvs(0xE0100).is_none());
+        assert_eq!(subtable.non_default_uvs(0xE0100).unwrap().len(), 1);
+        assert!(subtable.default_uvs(0xFE01).is_none());
+        assert!(subtable.non_default_uvs(0xFE01).is_none());
+
+        assert_eq!(subtable.glyph_index(0x4E02, 0xFE00), Some(GlyphVariationResult::UseDefault));
+        assert_eq!(subtable.glyph_index(0x30, 0xFE00), Some(GlyphVariationResult::Found(GlyphId(5))));
+        assert_eq!(subtable.glyph_index(0x4E01, 0xE0100), Some(GlyphVariationResult::Found(GlyphId(7))));
+        assert_eq!(subtable.glyph_index(0x4E03, 0xFE00), None);
+    }
+}
+
+mod best_unicode_subtable {
+    use ttf_parser::{cmap, GlyphId, PlatformId};
+    use crate::{convert, Unit::*};
+
+    fn cmap_data(encodings: &[(u16, u16)]) -> Vec<u8> {
+        let mut units = vec![
+            UInt16(0), // version
+            UInt16(encodings.len() as u16), // number of tables
+        ];
+
+        // All records are pointing to the same subtable.
+        let offset = 4 + encodings.len() as u32 * 8;
+        for &(platform_id, encoding_id) in encodings {
+            units.push(UInt16(platform_id));
+            units.push(UInt16(encoding_id));
+            units.push(UInt32(offset));
+        }
+
+        units.extend_from_slice(&[
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(1), // entry count
+            UInt16(10), // glyph ID [0]
+        ]);
+
+        convert(&units)
+    }
+
+    fn best(encodings: &[(u16, u16)]) -> Option<(PlatformId, u16)> {
+        let data = cmap_data(encodings);
+        let table = cmap::Table::parse(&data).unwrap();
+        table.best_unicode_subtable().map(|subtable| (subtable.platform_id, subtable.encoding_id))
+    }
+
+    #[test]
+    fn order() {
+        // Windows (3, 10) requires a format 12 or 13 subtable, therefore it's skipped.
+        assert_eq!(best(&[(3, 0), (0, 3), (3, 10), (3, 1)]), Some((PlatformId::Windows, 1)));
+        assert_eq!(best(&[(3, 0), (0, 4), (0, 3)]), Some((PlatformId::Unicode, 4)));
+        assert_eq!(best(&[(1, 0), (3, 0)]), Some((PlatformId::Windows, 0)));
+        assert_eq!(best(&[(1, 0), (3, 2)]), None);
+    }
+
+    #[test]
+    fn format_number() {
+        let data = cmap_data(&[(3, 1)]);
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.best_unicode_subtable().unwrap();
+        assert_eq!(subtable.format_number(), 6);
+        assert_eq!(subtable.glyph_index(0x41), Some(GlyphId(10)));
+    }
+}
+
+mod glyph_index {
+    use ttf_parser::{GlyphId, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    fn face_glyph_index(cmap: &[u8], c: char) -> Option<GlyphId> {
+        let tables = crate::RequiredTables::new(300);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(cmap),
+            ..RawFaceTables::default()
+        });
+        face.glyph_index(c)
+    }
+
+    #[test]
+    fn mac_roman_format0() {
+        let mut cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(1), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(0), // format
+            UInt16(262), // subtable size
+            UInt16(0), // language ID
+        ]);
+
+        cmap.extend_from_slice(&[0; 256]);
+        cmap[18 + 0x41] = 10; // A
+        cmap[18 + 0x80] = 20; // Ä
+        cmap[18 + 0xDE] = 30; // ﬁ
+
+        assert_eq!(face_glyph_index(&cmap, 'A'), Some(GlyphId(10)));
+        assert_eq!(face_glyph_index(&cmap, '\u{00C4}'), Some(GlyphId(20)));
+        assert_eq!(face_glyph_index(&cmap, '\u{FB01}'), Some(GlyphId(30)));
+        // Not in Mac OS Roman.
+        assert_eq!(face_glyph_index(&cmap, '\u{0080}'), None);
+        assert_eq!(face_glyph_index(&cmap, '\u{0410}'), None);
+    }
+
+    #[test]
+    fn mac_roman_format6() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(1), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x8A), // first code
+            UInt16(2), // entry count
+            UInt16(10), // glyph ID [0]
+            UInt16(20), // glyph ID [1]
+        ]);
+
+        assert_eq!(face_glyph_index(&cmap, '\u{00E4}'), Some(GlyphId(10)));
+        assert_eq!(face_glyph_index(&cmap, '\u{00E3}'), Some(GlyphId(20)));
+        assert_eq!(face_glyph_index(&cmap, '\u{008A}'), None);
+    }
+
+    #[test]
+    fn variation_fallback() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of tables
+            UInt16(0), // platform ID [0]
+            UInt16(3), // encoding ID [0]
+            UInt32(20), // offset [0]
+            UInt16(0), // platform ID [1]
+            UInt16(5), // encoding ID [1]
+            UInt32(32), // offset [1]
+
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(1), // entry count
+            UInt16(10), // glyph ID [0]
+
+            UInt16(14), // format
+            UInt32(30), // subtable size
+            UInt32(1), // number of records
+            Raw(&[0x00, 0xFE, 0x00]), // variation selector [0]
+            UInt32(0), // offset to default UVS [0]
+            UInt32(21), // offset to non-default UVS [0]
+            UInt32(1), // number of mappings
+            Raw(&[0x00, 0x00, 0x41]), // Unicode value
+            UInt16(12), // glyph ID
+        ]);
+
+        let tables = crate::RequiredTables::new(300);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        assert_eq!(face.glyph_variation_index('A', '\u{FE00}'), Some(GlyphId(12)));
+        // Unknown selector.
+        assert_eq!(face.glyph_variation_index('A', '\u{FE01}'), Some(GlyphId(10)));
+        assert_eq!(face.glyph_variation_index('B', '\u{FE00}'), None);
+    }
+
+    #[test]
+    fn symbol() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(3), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0xF041), // first code
+            UInt16(2), // entry count
+            UInt16(10), // glyph ID [0]
+            UInt16(20), // glyph ID [1]
+        ]);
+
+        assert_eq!(face_glyph_index(&cmap, 'A'), Some(GlyphId(10)));
+        assert_eq!(face_glyph_index(&cmap, '\u{F042}'), Some(GlyphId(20)));
+        // Only code points up to U+00FF are offset.
+        assert_eq!(face_glyph_index(&cmap, '\u{0141}'), None);
+        assert_eq!(face_glyph_index(&cmap, 'C'), None);
+    }
+
+    #[test]
+    fn unicode_before_symbol() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of tables
+            UInt16(3), // platform ID [0]
+            UInt16(0), // encoding ID [0]
+            UInt32(20), // offset [0]
+            UInt16(3), // platform ID [1]
+            UInt16(1), // encoding ID [1]
+            UInt32(32), // offset [1]
+
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(0xF041), // first code
+            UInt16(1), // entry count
+            UInt16(10), // glyph ID [0]
+
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(1), // entry count
+            UInt16(20), // glyph ID [0]
+        ]);
+
+        assert_eq!(face_glyph_index(&cmap, 'A'), Some(GlyphId(20)));
+    }
+
+    #[test]
+    fn unicode_before_mac_roman() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of tables
+            UInt16(1), // platform ID [0]
+            UInt16(0), // encoding ID [0]
+            UInt32(20), // offset [0]
+            UInt16(0), // platform ID [1]
+            UInt16(3), // encoding ID [1]
+            UInt32(32), // offset [1]
+
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(1), // entry count
+            UInt16(10), // glyph ID [0]
+
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(2), // entry count
+            UInt16(20), // glyph ID [0]
+            UInt16(0), // glyph ID [1]
+        ]);
+
+        assert_eq!(face_glyph_index(&cmap, 'A'), Some(GlyphId(20)));
+        // Not in Unicode subtable.
+        assert_eq!(face_glyph_index(&cmap, 'B'), None);
+    }
+
+    #[test]
+    fn raw_code_point() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(0), // platform ID
+            UInt16(3), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0xD800), // first code
+            UInt16(2), // entry count
+            UInt16(10), // glyph ID [0]
+            UInt16(20), // glyph ID [1]
+        ]);
+
+        let tables = crate::RequiredTables::new(300);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        // Lone surrogates are not valid `char`s.
+        assert_eq!(face.glyph_index_u32(0xD800), Some(GlyphId(10)));
+        assert_eq!(face.glyph_index_u32(0xD801), Some(GlyphId(20)));
+        assert_eq!(face.glyph_index_u32(0xD802), None);
+        assert_eq!(face.glyph_index_u32(0x110000), None);
+    }
+}
+
+mod cmap_cache {
+    use ttf_parser::{GlyphId, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn prefers_full_repertoire() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of tables
+            UInt16(0), // platform ID [0]
+            UInt16(3), // encoding ID [0]
+            UInt32(20), // offset [0]
+            UInt16(3), // platform ID [1]
+            UInt16(10), // encoding ID [1]
+            UInt32(34), // offset [1]
+
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0x41), // first code
+            UInt16(2), // entry count
+            UInt16(10), // glyph ID [0]
+            UInt16(20), // glyph ID [1]
+
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(40), // length
+            UInt32(0), // language
+            UInt32(2), // number of groups
+            // Group [0]
+            UInt32(0x41), // start char code
+            UInt32(0x42), // end char code
+            UInt32(30), // start glyph ID
+            // Group [1]
+            UInt32(0x1F600), // start char code
+            UInt32(0x1F600), // end char code
+            UInt32(40), // start glyph ID
+        ]);
+
+        let tables = crate::RequiredTables::new(300);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        let cache = face.build_cmap_cache();
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.glyph_index(0x41), Some(GlyphId(30)));
+        assert_eq!(cache.glyph_index(0x42), Some(GlyphId(31)));
+        assert_eq!(cache.glyph_index(0x1F600), Some(GlyphId(40)));
+        assert_eq!(cache.glyph_index(0x43), None);
+    }
+
+    #[test]
+    fn malformed_groups() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(52), // length
+            UInt32(0), // language
+            UInt32(3), // number of groups
+            // Group [0]
+            UInt32(0x10), // start char code
+            UInt32(0xFFFFFFFF), // end char code
+            UInt32(0xFFF0), // start glyph ID
+            // Group [1]
+            UInt32(0x18), // start char code
+            UInt32(0x30), // end char code
+            UInt32(100), // start glyph ID
+            // Group [2]
+            UInt32(0x110000), // start char code
+            UInt32(0x110010), // end char code
+            UInt32(1), // start glyph ID
+        ]);
+
+        let tables = crate::RequiredTables::new(1);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        let cache = face.build_cmap_cache();
+        // Group [0] stops at glyph 0xFFFF, group [1] is trimmed
+        // and group [2] is outside of the Unicode range.
+        assert_eq!(cache.len(), 0x21);
+        assert_eq!(cache.glyph_index(0x10), Some(GlyphId(0xFFF0)));
+        assert_eq!(cache.glyph_index(0x1F), Some(GlyphId(0xFFFF)));
+        assert_eq!(cache.glyph_index(0x20), Some(GlyphId(108)));
+        assert_eq!(cache.glyph_index(0x30), Some(GlyphId(124)));
+        assert_eq!(cache.glyph_index(0x31), None);
+        assert_eq!(cache.glyph_index(0x110000), None);
+    }
+
+    #[test]
+    fn many_to_one() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(13), // format
+            UInt16(0), // reserved
+            UInt32(28), // length
+            UInt32(0), // language
+            UInt32(1), // number of groups
+            // Group [0]
+            UInt32(0x41), // start char code
+            UInt32(0xFFFFFFFF), // end char code
+            UInt32(5), // glyph ID
+        ]);
+
+        let tables = crate::RequiredTables::new(1);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        let cache = face.build_cmap_cache();
+        assert_eq!(cache.len(), 0x10FFFF - 0x41 + 1);
+        assert_eq!(cache.glyph_index(0x40), None);
+        assert_eq!(cache.glyph_index(0x41), Some(GlyphId(5)));
+        assert_eq!(cache.glyph_index(0x10FFFF), Some(GlyphId(5)));
+        assert_eq!(cache.glyph_index(0x110000), None);
+    }
+
+    #[test]
+    fn no_cmap() {
+        let tables = crate::RequiredTables::new(1);
+        let face = tables.face(RawFaceTables::default());
+
+        let cache = face.build_cmap_cache();
+        assert!(cache.is_empty());
+        assert_eq!(cache.glyph_index(0x41), None);
+    }
+}
+
+#[cfg(feature = "unicode-scripts")]
+mod coverage_by_script {
+    use ttf_parser::script::Script;
+    use ttf_parser::RawFaceTables;
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn basic() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of tables
+            // Both records are pointing to the same subtable,
+            // so code points must not be counted twice.
+            UInt16(0), // platform ID [0]
+            UInt16(3), // encoding ID [0]
+            UInt32(20), // offset [0]
+            UInt16(3), // platform ID [1]
+            UInt16(1), // encoding ID [1]
+            UInt32(20), // offset [1]
+
+            UInt16(4), // format
+            UInt16(48), // subtable size
+            UInt16(0), // language ID
+            UInt16(8), // 2 x segCount
+            UInt16(8), // search range
+            UInt16(2), // entry selector
+            UInt16(0), // range shift
+            // End character codes
+            UInt16(0x31), // char code [0]
+            UInt16(0x43), // char code [1]
+            UInt16(0x392), // char code [2]
+            UInt16(65535), // char code [3]
+            UInt16(0), // reserved
+            // Start character codes
+            UInt16(0x30), // char code [0]
+            UInt16(0x41), // char code [1]
+            UInt16(0x391), // char code [2]
+            UInt16(65535), // char code [3]
+            // Deltas
+            Int16(1), // delta [0]
+            Int16(1), // delta [1]
+            Int16(1), // delta [2]
+            Int16(1), // delta [3]
+            // Offsets into Glyph index array
+            UInt16(0), // offset [0]
+            UInt16(0), // offset [1]
+            UInt16(0), // offset [2]
+            UInt16(0), // offset [3]
+        ]);
+
+        let tables = crate::RequiredTables::new(100);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        let coverage: Vec<_> = face.coverage_by_script().collect();
+        assert_eq!(coverage, [
+            (Script::Common, 2),
+            (Script::Greek, 2),
+            (Script::Latin, 3),
+        ]);
+    }
+
+    #[test]
+    fn overlapping_groups_outside_unicode() {
+        let cmap = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(40), // length
+            UInt32(0), // language
+            UInt32(2), // number of groups
+            // Group [0]
+            UInt32(0), // start char code
+            UInt32(0xFFFFFFFF), // end char code
+            UInt32(0), // start glyph ID
+            // Group [1]
+            UInt32(0x41), // start char code
+            UInt32(0x5A), // end char code
+            UInt32(0), // start glyph ID
+        ]);
+
+        let tables = crate::RequiredTables::new(1);
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            ..RawFaceTables::default()
+        });
+
+        let total: u32 = face.coverage_by_script().map(|(_, count)| count).sum();
+        assert_eq!(total, 0x110000);
+    }
+
+    #[test]
+    fn from_code_point() {
+        assert_eq!(Script::from_code_point(0x0000), Script::Common);
+        assert_eq!(Script::from_code_point(0x0410), Script::Cyrillic);
+        assert_eq!(Script::from_code_point(0x0301), Script::Inherited);
+        assert_eq!(Script::from_code_point(0x4E00), Script::Han);
+        assert_eq!(Script::from_code_point(0x1E900), Script::Adlam);
+        assert_eq!(Script::from_code_point(0x10FFFF), Script::Unknown);
+        assert_eq!(Script::from_code_point(0x110000), Script::Unknown);
+    }
+}
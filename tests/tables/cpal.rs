@@ -0,0 +1,129 @@
+use ttf_parser::{cpal, GlyphId, RawFaceTables};
+use ttf_parser::cpal::BgraColor;
+use crate::{convert, Unit::*};
+
+fn cpal_data() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of palette entries
+        UInt16(2), // number of palettes
+        UInt16(3), // number of color records
+        UInt32(16), // offset to color records
+        UInt16(0), // color record index [0]
+        UInt16(1), // color record index [1]
+
+        // Color records
+        UInt8(10), UInt8(20), UInt8(30), UInt8(255),
+        UInt8(40), UInt8(50), UInt8(60), UInt8(128),
+        UInt8(70), UInt8(80), UInt8(90), UInt8(0),
+    ])
+}
+
+#[test]
+fn palettes() {
+    let data = cpal_data();
+    let table = cpal::Table::parse(&data).unwrap();
+    assert_eq!(table.palettes(), 2);
+    assert_eq!(table.palette_entries(), 2);
+
+    let palette: Vec<_> = table.palette(0).unwrap().into_iter().collect();
+    assert_eq!(palette, vec![
+        BgraColor { blue: 10, green: 20, red: 30, alpha: 255 },
+        BgraColor { blue: 40, green: 50, red: 60, alpha: 128 },
+    ]);
+
+    // Palettes are allowed to overlap.
+    assert_eq!(table.get(1, 0), Some(BgraColor { blue: 40, green: 50, red: 60, alpha: 128 }));
+    assert_eq!(table.get(1, 1), Some(BgraColor { blue: 70, green: 80, red: 90, alpha: 0 }));
+    assert_eq!(table.get(1, 2), None);
+    assert!(table.palette(2).is_none());
+}
+
+#[test]
+fn version_1() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of palette entries
+        UInt16(2), // number of palettes
+        UInt16(2), // number of color records
+        UInt32(28), // offset to color records
+        UInt16(0), // color record index [0]
+        UInt16(0), // color record index [1]
+        UInt32(36), // offset to palette types
+        UInt32(44), // offset to palette labels
+        UInt32(0), // offset to palette entry labels
+
+        // Color records
+        UInt8(10), UInt8(20), UInt8(30), UInt8(255),
+        UInt8(40), UInt8(50), UInt8(60), UInt8(128),
+
+        // Palette types
+        UInt32(1), // palette [0]: light
+        UInt32(2), // palette [1]: dark
+
+        // Palette labels
+        UInt16(256), // palette [0]
+        UInt16(0xFFFF), // palette [1]
+    ]);
+
+    let table = cpal::Table::parse(&data).unwrap();
+    assert!(table.palette_flags(0).light_background());
+    assert!(!table.palette_flags(0).dark_background());
+    assert!(table.palette_flags(1).dark_background());
+    assert_eq!(table.palette_flags(2), cpal::PaletteFlags(0));
+    assert_eq!(table.palette_label(0), Some(256));
+    assert_eq!(table.palette_label(1), None);
+    assert_eq!(table.entry_label(0), None);
+
+    // Version 0 has no labels.
+    let data = cpal_data();
+    let table = cpal::Table::parse(&data).unwrap();
+    assert_eq!(table.palette_flags(0), cpal::PaletteFlags(0));
+    assert_eq!(table.palette_label(0), None);
+}
+
+#[test]
+fn palette_out_of_bounds() {
+    let mut data = cpal_data();
+    data[15] = 2; // color record index [1]
+    let table = cpal::Table::parse(&data).unwrap();
+    assert!(table.palette(0).is_some());
+    assert!(table.palette(1).is_none());
+}
+
+#[test]
+fn unknown_version() {
+    let mut data = cpal_data();
+    data[1] = 2;
+    assert!(cpal::Table::parse(&data).is_none());
+}
+
+#[test]
+fn svg_glyph_palette() {
+    let svg = convert(&[
+        UInt16(0), // version
+        UInt32(10), // offset to document list
+        UInt32(0), // reserved
+
+        // Document list
+        UInt16(1), // number of records
+        UInt16(1), // start glyph ID
+        UInt16(1), // end glyph ID
+        UInt32(14), // document offset
+        UInt32(5), // document length
+        Raw(b"<svg>"),
+    ]);
+
+    let tables = crate::RequiredTables::new(2);
+    let cpal = cpal_data();
+    let face = tables.face(RawFaceTables {
+        cpal: Some(&cpal),
+        svg: Some(&svg),
+        ..RawFaceTables::default()
+    });
+
+    assert!(face.svg_glyph_palette(GlyphId(0)).is_none());
+    let palette = face.svg_glyph_palette(GlyphId(1)).unwrap();
+    assert_eq!(palette.len(), 2);
+    assert_eq!(palette.get(0), Some(BgraColor { blue: 10, green: 20, red: 30, alpha: 255 }));
+}
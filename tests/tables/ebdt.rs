@@ -0,0 +1,273 @@
+use ttf_parser::{cbdt, cblc, GlyphId, RasterGlyphImage, RasterImageFormat, RawFaceTables};
+use crate::{convert, Unit, Unit::*};
+
+fn eblc_data(bit_depth: u8, index_subtable: &[Unit]) -> Vec<u8> {
+    let mut data = convert(&[
+        UInt32(0x00020000), // version
+        UInt32(1), // number of sizes
+
+        // BitmapSize [0]
+        UInt32(56), // index subtable array offset
+        UInt32(0), // index tables size
+        UInt32(1), // number of index subtables
+        UInt32(0), // color ref
+    ]);
+
+    data.extend_from_slice(&[0; 24]); // horizontal and vertical line metrics
+    data.extend_from_slice(&convert(&[
+        UInt16(1), // start glyph index
+        UInt16(2), // end glyph index
+        UInt8(12), // ppem x
+        UInt8(12), // ppem y
+        UInt8(bit_depth), // bit depth
+        UInt8(1), // flags
+
+        // IndexSubtableArray [0]
+        UInt16(1), // first glyph index
+        UInt16(2), // last glyph index
+        UInt32(8), // additional offset to index subtable
+    ]));
+    data.extend_from_slice(&convert(index_subtable));
+    data
+}
+
+fn glyph_image<'a>(eblc: &'a [u8], ebdt: &'a [u8], glyph_id: u16) -> Option<RasterGlyphImage<'a>> {
+    let eblc = cblc::Table::parse(eblc)?;
+    let ebdt = cbdt::Table::parse(eblc, ebdt)?;
+    ebdt.get(GlyphId(glyph_id), 12)
+}
+
+#[test]
+fn byte_aligned_small_metrics() {
+    let eblc = eblc_data(1, &[
+        UInt16(1), // index format
+        UInt16(1), // image format
+        UInt32(4), // image data offset
+        UInt32(0), // offset [0]
+        UInt32(9), // offset [1]
+        UInt32(18), // offset [2]
+    ]);
+
+    let ebdt = convert(&[
+        UInt32(0x00020000), // version
+
+        // Glyph [1]
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(1), // bearing x
+        Int8(2), // bearing y
+        UInt8(11), // advance
+        UInt16(0xFFC0), // row [0]
+        UInt16(0x8040), // row [1]
+
+        // Glyph [2]
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(0), // bearing x
+        Int8(2), // bearing y
+        UInt8(11), // advance
+        UInt16(0x0000), // row [0]
+        UInt16(0xFFC0), // row [1]
+    ]);
+
+    let image = glyph_image(&eblc, &ebdt, 1).unwrap();
+    assert_eq!(image.x, 1);
+    assert_eq!(image.y, 0);
+    assert_eq!(image.width, 10);
+    assert_eq!(image.height, 2);
+    assert_eq!(image.pixels_per_em, 12);
+    assert_eq!(image.format, RasterImageFormat::BitmapMono);
+    assert_eq!(image.data, &[0xFF, 0xC0, 0x80, 0x40]);
+
+    let image = glyph_image(&eblc, &ebdt, 2).unwrap();
+    assert_eq!(image.x, 0);
+    assert_eq!(image.data, &[0x00, 0x00, 0xFF, 0xC0]);
+
+    assert!(glyph_image(&eblc, &ebdt, 0).is_none());
+
+    let tables = crate::RequiredTables::new(3);
+    let face = tables.face(RawFaceTables {
+        ebdt: Some(&ebdt),
+        eblc: Some(&eblc),
+        ..RawFaceTables::default()
+    });
+
+    let image = face.glyph_raster_image(GlyphId(1), 12).unwrap();
+    assert_eq!(image.format, RasterImageFormat::BitmapMono);
+    assert_eq!(image.data, &[0xFF, 0xC0, 0x80, 0x40]);
+    use ttf_parser::RasterSource;
+    let image = face.glyph_raster_image_with(GlyphId(2), 12, &[RasterSource::Sbix, RasterSource::Ebdt]);
+    assert_eq!(image.unwrap().data, &[0x00, 0x00, 0xFF, 0xC0]);
+    assert!(face.glyph_raster_image_with(GlyphId(1), 12, &[RasterSource::Cbdt]).is_none());
+    assert!(face.glyph_raster_image_with(GlyphId(1), 12, &[]).is_none());
+}
+
+#[test]
+fn bit_aligned_small_metrics() {
+    let eblc = eblc_data(1, &[
+        UInt16(1), // index format
+        UInt16(2), // image format
+        UInt32(4), // image data offset
+        UInt32(0), // offset [0]
+        UInt32(8), // offset [1]
+        UInt32(16), // offset [2]
+    ]);
+
+    let ebdt = convert(&[
+        UInt32(0x00020000), // version
+
+        // Glyph [1]
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(1), // bearing x
+        Int8(2), // bearing y
+        UInt8(11), // advance
+        // 20 bits, without row padding
+        UInt8(0xFF), UInt8(0xE0), UInt8(0x10),
+
+        // Glyph [2]
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(0), // bearing x
+        Int8(2), // bearing y
+        UInt8(11), // advance
+        UInt8(0x00), UInt8(0x3F), UInt8(0xF0),
+    ]);
+
+    let image = glyph_image(&eblc, &ebdt, 1).unwrap();
+    assert_eq!(image.width, 10);
+    assert_eq!(image.height, 2);
+    assert_eq!(image.format, RasterImageFormat::BitmapMonoPacked);
+    assert_eq!(image.data, &[0xFF, 0xE0, 0x10]);
+}
+
+#[test]
+fn metrics_in_location_table() {
+    let eblc = eblc_data(1, &[
+        UInt16(2), // index format
+        UInt16(5), // image format
+        UInt32(4), // image data offset
+        UInt32(3), // image size
+        // Big metrics
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(1), // horizontal bearing x
+        Int8(2), // horizontal bearing y
+        UInt8(11), // horizontal advance
+        Int8(0), // vertical bearing x
+        Int8(0), // vertical bearing y
+        UInt8(0), // vertical advance
+    ]);
+
+    let ebdt = convert(&[
+        UInt32(0x00020000), // version
+        UInt8(0xFF), UInt8(0xE0), UInt8(0x10), // glyph [1]
+        UInt8(0x00), UInt8(0x3F), UInt8(0xF0), // glyph [2]
+    ]);
+
+    let image = glyph_image(&eblc, &ebdt, 2).unwrap();
+    assert_eq!(image.x, 1);
+    assert_eq!(image.y, 0);
+    assert_eq!(image.width, 10);
+    assert_eq!(image.height, 2);
+    assert_eq!(image.format, RasterImageFormat::BitmapMonoPacked);
+    assert_eq!(image.data, &[0x00, 0x3F, 0xF0]);
+}
+
+#[test]
+fn grayscale() {
+    let eblc = eblc_data(8, &[
+        UInt16(1), // index format
+        UInt16(1), // image format
+        UInt32(4), // image data offset
+        UInt32(0), // offset [0]
+        UInt32(25), // offset [1]
+        UInt32(25), // offset [2]
+    ]);
+
+    let mut ebdt = convert(&[
+        UInt32(0x00020000), // version
+        UInt8(2), // height
+        UInt8(10), // width
+        Int8(1), // bearing x
+        Int8(2), // bearing y
+        UInt8(11), // advance
+    ]);
+    ebdt.extend_from_slice(&[0; 20]);
+
+    assert!(glyph_image(&eblc, &ebdt, 1).is_none());
+}
+
+// The largest strike covers glyph 1 by its range, but has an image only for glyph 2.
+#[test]
+fn color_glyph_format_in_any_strike() {
+    use ttf_parser::ColorGlyphFormat;
+
+    let strike = |index_subtable_array_offset: u32, ppem: u8| {
+        let mut data = convert(&[
+            UInt32(index_subtable_array_offset), // index subtable array offset
+            UInt32(0), // index tables size
+            UInt32(1), // number of index subtables
+            UInt32(0), // color ref
+        ]);
+        data.extend_from_slice(&[0; 24]); // horizontal and vertical line metrics
+        data.extend_from_slice(&convert(&[
+            UInt16(1), // start glyph index
+            UInt16(2), // end glyph index
+            UInt8(ppem), // ppem x
+            UInt8(ppem), // ppem y
+            UInt8(32), // bit depth
+            UInt8(1), // flags
+        ]));
+        data
+    };
+
+    let index_subtable = |glyph_id: u16| convert(&[
+        // IndexSubtableArray [0]
+        UInt16(glyph_id), // first glyph index
+        UInt16(glyph_id), // last glyph index
+        UInt32(8), // additional offset to index subtable
+
+        UInt16(1), // index format
+        UInt16(17), // image format
+        UInt32(4), // image data offset
+        UInt32(0), // offset [0]
+        UInt32(10), // offset [1]
+    ]);
+
+    let mut cblc = convert(&[
+        UInt32(0x00030000), // version
+        UInt32(2), // number of sizes
+    ]);
+    cblc.extend_from_slice(&strike(104, 12));
+    cblc.extend_from_slice(&strike(128, 24));
+    cblc.extend_from_slice(&index_subtable(1));
+    cblc.extend_from_slice(&index_subtable(2));
+
+    let cbdt = convert(&[
+        UInt32(0x00030000), // version
+        UInt8(1), // height
+        UInt8(1), // width
+        Int8(0), // bearing x
+        Int8(0), // bearing y
+        UInt8(1), // advance
+        UInt32(1), // data length
+        UInt8(0), // data
+    ]);
+
+    let tables = crate::RequiredTables::new(3);
+    let face = tables.face(RawFaceTables {
+        cbdt: Some(&cbdt),
+        cblc: Some(&cblc),
+        ..RawFaceTables::default()
+    });
+
+    assert_eq!(face.color_glyph_format(GlyphId(1)), Some(ColorGlyphFormat::Cbdt));
+    assert_eq!(face.color_glyph_format(GlyphId(2)), Some(ColorGlyphFormat::Cbdt));
+    assert_eq!(face.color_glyph_format(GlyphId(0)), None);
+
+    let image = face.glyph_raster_image(GlyphId(1), 12).unwrap();
+    assert_eq!(image.pixels_per_em, 12);
+    let image = face.glyph_raster_image(GlyphId(2), 24).unwrap();
+    assert_eq!(image.pixels_per_em, 24);
+}
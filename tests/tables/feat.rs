@@ -81,3 +81,48 @@ fn basic() {
     assert_eq!(feature3.default_setting_index, 1);
     assert_eq!(feature3.exclusive, true);
 }
+
+#[test]
+fn iter() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        UInt16(2), // number of features
+        UInt16(0), // reserved
+        UInt32(0), // reserved
+
+        // Feature Name [0]
+        UInt16(1), // feature
+        UInt16(1), // number of settings
+        UInt32(36), // offset to settings table
+        UInt16(0), // flags: none
+        UInt16(256), // name index
+
+        // Feature Name [1]
+        UInt16(11), // feature
+        UInt16(2), // number of settings
+        UInt32(40), // offset to settings table
+        Raw(&[0x80, 0x00]), // flags: exclusive
+        UInt16(257), // name index
+
+        // Setting Name [0]
+        UInt16(0), // setting
+        UInt16(258), // name index
+
+        // Setting Name [1]
+        UInt16(1), // setting
+        UInt16(259), // name index
+        UInt16(2), // setting
+        UInt16(260), // name index
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let features: Vec<_> = table.names.into_iter().map(|f| (f.feature, f.name_index)).collect();
+    assert_eq!(features, vec![(1, 256), (11, 257)]);
+
+    let fractions = table.names.find(11).unwrap();
+    assert!(fractions.exclusive);
+    let settings: Vec<_> = fractions.setting_names.into_iter().map(|s| (s.setting, s.name_index)).collect();
+    assert_eq!(settings, vec![(1, 259), (2, 260)]);
+
+    assert!(table.names.find(2).is_none());
+}
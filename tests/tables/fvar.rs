@@ -0,0 +1,318 @@
+use ttf_parser::{GlyphId, NormalizedCoordinate, RawFaceTables, RoundingMode, Tag, Variations};
+use crate::{convert, Unit::*};
+
+fn fvar_data() -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        UInt16(16), // axes array offset
+        UInt16(2), // reserved
+        UInt16(2), // axis count
+        UInt16(20), // axis size
+        UInt16(1), // instance count
+        UInt16(14), // instance size
+
+        // Axis [0]
+        Raw(b"wght"), // tag
+        Fixed(100.0), // min value
+        Fixed(400.0), // default value
+        Fixed(900.0), // max value
+        UInt16(0), // flags
+        UInt16(256), // name ID
+
+        // Axis [1]
+        Raw(b"XTRA"), // tag
+        Fixed(300.0), // min value
+        Fixed(500.0), // default value
+        Fixed(600.0), // max value
+        UInt16(0x0008), // flags: hidden
+        UInt16(257), // name ID
+
+        // Instance [0]
+        UInt16(258), // subfamily name ID
+        UInt16(0), // flags
+        Fixed(650.0), // coordinate [0]
+        Fixed(550.0), // coordinate [1]
+        UInt16(259), // PostScript name ID
+    ])
+}
+
+fn name_data() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of records
+        UInt16(18), // offset to storage
+
+        // Record [0]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x0409), // language ID: English (United States)
+        UInt16(256), // name ID
+        UInt16(12), // length
+        UInt16(0), // offset
+
+        // Storage
+        Raw(b"\0W\0e\0i\0g\0h\0t"),
+    ])
+}
+
+#[test]
+fn axis_info() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let name = name_data();
+    let face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        name: Some(&name),
+        ..RawFaceTables::default()
+    });
+
+    let axes: Vec<_> = face.axis_info().collect();
+    assert_eq!(axes.len(), 2);
+
+    assert_eq!(axes[0].tag, Tag::from_bytes(b"wght"));
+    assert_eq!(axes[0].name.as_deref(), Some("Weight"));
+    assert_eq!(axes[0].min, 100.0);
+    assert_eq!(axes[0].default, 400.0);
+    assert_eq!(axes[0].max, 900.0);
+    assert!(!axes[0].hidden);
+
+    assert_eq!(axes[1].tag, Tag::from_bytes(b"XTRA"));
+    assert_eq!(axes[1].name_id, 257);
+    assert_eq!(axes[1].name, None);
+    assert!(axes[1].hidden);
+}
+
+fn avar_data() -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        UInt16(0), // reserved
+        UInt16(2), // axis count
+
+        // Segment map [0]
+        UInt16(4), // number of pairs
+        Int16(-16384), Int16(-16384), // -1.0 -> -1.0
+        Int16(0), Int16(0), // 0.0 -> 0.0
+        Int16(8192), Int16(16384), // 0.5 -> 1.0
+        Int16(16384), Int16(16384), // 1.0 -> 1.0
+
+        // Segment map [1]
+        UInt16(0), // number of pairs
+    ])
+}
+
+#[test]
+fn variations_equivalent() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let avar = avar_data();
+    let face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        avar: Some(&avar),
+        ..RawFaceTables::default()
+    });
+
+    let c = NormalizedCoordinate::from;
+    assert!(face.variations_equivalent(&[c(8192), c(0)], &[c(16384), c(0)]));
+    assert!(!face.variations_equivalent(&[c(4096), c(0)], &[c(16384), c(0)]));
+    assert!(!face.variations_equivalent(&[c(0), c(8192)], &[c(0), c(16384)]));
+    // Missing coordinates are the default ones.
+    assert!(face.variations_equivalent(&[c(8192)], &[c(16384), c(0)]));
+    assert!(face.variations_equivalent(&[], &[c(0), c(0), c(100)]));
+}
+
+fn hvar_data() -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        UInt32(20), // item variation store offset
+        UInt32(0), // advance width mapping offset
+        UInt32(0), // left side bearing mapping offset
+        UInt32(0), // right side bearing mapping offset
+
+        // Item Variation Store
+        UInt16(1), // format
+        UInt32(12), // variation region list offset
+        UInt16(1), // item variation data count
+        UInt32(28), // item variation data offset [0]
+
+        // Variation Region List
+        UInt16(2), // axis count
+        UInt16(1), // region count
+        // Region [0]
+        Int16(0), Int16(16384), Int16(16384), // wght: 0.0, 1.0, 1.0
+        Int16(0), Int16(0), Int16(0), // XTRA: not used
+
+        // Item Variation Data [0]
+        UInt16(1), // item count
+        UInt16(0), // short delta count
+        UInt16(1), // region index count
+        UInt16(0), // region index [0]
+        Int8(3), // delta set [0]
+    ])
+}
+
+#[test]
+fn rounding_mode() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let hvar = hvar_data();
+    let hmtx = convert(&[
+        UInt16(100), // advance width [0]
+        Int16(10), // side bearing [0]
+    ]);
+    let mut face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        hvar: Some(&hvar),
+        hmtx: Some(&hmtx),
+        ..RawFaceTables::default()
+    });
+
+    assert_eq!(face.rounding_mode(), RoundingMode::Round);
+    assert_eq!(face.glyph_hor_advance(GlyphId(0)), Some(100));
+    assert_eq!(face.glyph_hor_advance_unrounded(GlyphId(0)), Some(100.0));
+
+    // Normalized to 0.5, therefore the delta is 1.5.
+    face.set_variation(Tag::from_bytes(b"wght"), 650.0).unwrap();
+    assert_eq!(face.glyph_hor_advance(GlyphId(0)), Some(102));
+    assert_eq!(face.glyph_hor_advance_unrounded(GlyphId(0)), Some(101.5));
+
+    face.set_rounding_mode(RoundingMode::Truncate);
+    assert_eq!(face.glyph_hor_advance(GlyphId(0)), Some(101));
+    assert_eq!(face.glyph_hor_advance_unrounded(GlyphId(0)), Some(101.5));
+}
+
+#[test]
+fn named_instances() {
+    let data = fvar_data();
+    let fvar = ttf_parser::fvar::Table::parse(&data).unwrap();
+    assert_eq!(fvar.instances.len(), 1);
+
+    let instance = fvar.instances.get(0).unwrap();
+    assert_eq!(instance.subfamily_name_id, 258);
+    assert_eq!(instance.post_script_name_id, Some(259));
+    assert_eq!(instance.coordinates.len(), 2);
+    assert_eq!(instance.coordinates.get(0).unwrap().0, 650.0);
+    assert_eq!(instance.coordinates.get(1).unwrap().0, 550.0);
+    assert!(fvar.instances.get(1).is_none());
+    assert_eq!(fvar.instances.into_iter().count(), 1);
+}
+
+#[test]
+fn set_variations() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let avar = avar_data();
+    let mut face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        avar: Some(&avar),
+        ..RawFaceTables::default()
+    });
+
+    let c = NormalizedCoordinate::from;
+    let variations = [
+        ttf_parser::Variation { axis: Tag::from_bytes(b"wght"), value: 650.0 },
+        ttf_parser::Variation { axis: Tag::from_bytes(b"XTRA"), value: 550.0 },
+        // Unknown axes are ignored.
+        ttf_parser::Variation { axis: Tag::from_bytes(b"wdth"), value: 100.0 },
+    ];
+    assert_eq!(face.set_variations(&variations), Some(()));
+    // `wght` is mapped via `avar`.
+    assert_eq!(face.variation_coordinates(), &[c(16384), c(8192)]);
+
+    let unknown = [ttf_parser::Variation { axis: Tag::from_bytes(b"wdth"), value: 100.0 }];
+    assert_eq!(face.set_variations(&unknown), None);
+}
+
+#[test]
+fn variations() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let avar = avar_data();
+    let hvar = hvar_data();
+    let hmtx = convert(&[
+        UInt16(100), // advance width [0]
+        Int16(10), // side bearing [0]
+    ]);
+    let face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        avar: Some(&avar),
+        hvar: Some(&hvar),
+        hmtx: Some(&hmtx),
+        ..RawFaceTables::default()
+    });
+
+    let c = NormalizedCoordinate::from;
+    let variations = Variations::new(&face, &[
+        ttf_parser::Variation { axis: Tag::from_bytes(b"XTRA"), value: 550.0 },
+        // Unknown axes are ignored.
+        ttf_parser::Variation { axis: Tag::from_bytes(b"wdth"), value: 100.0 },
+    ]);
+    assert_eq!(variations.coordinates(), &[c(0), c(8192)]);
+    assert_eq!(face.glyph_hor_advance_with(GlyphId(0), &variations), Some(100));
+
+    // `wght` is mapped via `avar`.
+    let variations = Variations::new(&face, &[
+        ttf_parser::Variation { axis: Tag::from_bytes(b"wght"), value: 650.0 },
+    ]);
+    assert_eq!(variations.coordinates(), &[c(16384), c(0)]);
+    assert_eq!(face.glyph_hor_advance_with(GlyphId(0), &variations), Some(103));
+
+    // Face's own coordinates are not affected.
+    assert!(!face.has_non_default_variation_coordinates());
+    assert_eq!(face.glyph_hor_advance(GlyphId(0)), Some(100));
+}
+
+#[test]
+fn set_named_variation() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let avar = avar_data();
+    let mut face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        avar: Some(&avar),
+        ..RawFaceTables::default()
+    });
+
+    let instance = face.tables().fvar.unwrap().instances.get(0).unwrap();
+    assert_eq!(face.set_named_variation(instance), Some(()));
+
+    let c = NormalizedCoordinate::from;
+    assert_eq!(face.variation_coordinates(), &[c(16384), c(8192)]);
+}
+
+#[test]
+fn reset_variations() {
+    let tables = crate::RequiredTables::new(1);
+    let fvar = fvar_data();
+    let avar = avar_data();
+    let mut face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        avar: Some(&avar),
+        ..RawFaceTables::default()
+    });
+
+    face.set_variation(Tag::from_bytes(b"wght"), 650.0).unwrap();
+    assert!(face.has_non_default_variation_coordinates());
+
+    face.reset_variations();
+    assert!(!face.has_non_default_variation_coordinates());
+    assert_eq!(face.variation_coordinates().len(), 2);
+}
+
+#[test]
+fn default_coords() {
+    let data = fvar_data();
+    let fvar = ttf_parser::fvar::Table::parse(&data).unwrap();
+
+    let c = NormalizedCoordinate::from;
+    let mut coords = [c(100); 3];
+    fvar.default_coords(&mut coords);
+    assert_eq!(coords, [c(0), c(0), c(100)]);
+
+    let mut coords = [0.0; 3];
+    fvar.default_user_coords(&mut coords);
+    assert_eq!(coords, [400.0, 500.0, 0.0]);
+
+    let mut coords = [0.0; 1];
+    fvar.default_user_coords(&mut coords);
+    assert_eq!(coords, [400.0]);
+}
@@ -0,0 +1,64 @@
+use ttf_parser::gasp::{GaspBehavior, Table};
+use crate::{convert, Unit::*};
+
+#[test]
+fn version1() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(3), // number of ranges
+        // Range [0]
+        UInt16(8), // max PPEM
+        UInt16(0x000A), // behavior: do gray, symmetric smoothing
+        // Range [1]
+        UInt16(16), // max PPEM
+        UInt16(0x0005), // behavior: gridfit, symmetric gridfit
+        // Range [2]
+        UInt16(0xFFFF), // max PPEM
+        UInt16(0x000F), // behavior: all
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.ranges.len(), 3);
+
+    let behavior = table.behavior(8);
+    assert!(!behavior.gridfit());
+    assert!(behavior.do_gray());
+    assert!(!behavior.symmetric_gridfit());
+    assert!(behavior.symmetric_smoothing());
+
+    let behavior = table.behavior(9);
+    assert!(behavior.gridfit());
+    assert!(!behavior.do_gray());
+    assert!(behavior.symmetric_gridfit());
+    assert!(!behavior.symmetric_smoothing());
+
+    assert_eq!(table.behavior(100), GaspBehavior(0x000F));
+    assert_eq!(table.behavior(0xFFFF), GaspBehavior(0x000F));
+}
+
+#[test]
+fn version0() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of ranges
+        // Range [0]
+        UInt16(8), // max PPEM
+        UInt16(0x000F), // behavior: all
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    // Symmetric flags are ignored in version 0.
+    assert_eq!(table.behavior(8), GaspBehavior(0x0003));
+    // Not covered by any range.
+    assert_eq!(table.behavior(9), GaspBehavior(0));
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // number of ranges
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
@@ -0,0 +1,90 @@
+use ttf_parser::GlyphId;
+use ttf_parser::gdef::{GlyphClass, Table};
+use crate::{convert, Unit::*};
+
+fn gdef_data() -> Vec<u8> {
+    convert(&[
+        UInt32(0x00010002), // version
+        UInt16(14), // offset to glyph class definition
+        UInt16(0), // offset to attachment point list
+        UInt16(0), // offset to ligature caret list
+        UInt16(42), // offset to mark attachment class definition
+        UInt16(52), // offset to mark glyph sets
+
+        // Glyph class definition
+        UInt16(2), // format
+        UInt16(4), // number of ranges
+        UInt16(1), UInt16(1), UInt16(1), // Base
+        UInt16(2), UInt16(2), UInt16(2), // Ligature
+        UInt16(3), UInt16(4), UInt16(3), // Mark
+        UInt16(5), UInt16(5), UInt16(4), // Component
+
+        // Mark attachment class definition
+        UInt16(1), // format
+        UInt16(3), // start glyph
+        UInt16(2), // number of classes
+        UInt16(1), // class [0]
+        UInt16(2), // class [1]
+
+        // Mark glyph sets
+        UInt16(1), // format
+        UInt16(2), // number of coverages
+        UInt32(12), // offset to coverage [0]
+        UInt32(18), // offset to coverage [1]
+
+        // Coverage [0]
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(3), // glyph ID
+
+        // Coverage [1]
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(4), // glyph ID
+    ])
+}
+
+#[test]
+fn glyph_class() {
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    assert!(table.has_glyph_classes());
+    assert_eq!(table.glyph_class(GlyphId(0)), None);
+    assert_eq!(table.glyph_class(GlyphId(1)), Some(GlyphClass::Base));
+    assert_eq!(table.glyph_class(GlyphId(2)), Some(GlyphClass::Ligature));
+    assert_eq!(table.glyph_class(GlyphId(3)), Some(GlyphClass::Mark));
+    assert_eq!(table.glyph_class(GlyphId(4)), Some(GlyphClass::Mark));
+    assert_eq!(table.glyph_class(GlyphId(5)), Some(GlyphClass::Component));
+    assert_eq!(table.glyph_class(GlyphId(6)), None);
+}
+
+#[test]
+fn mark_attachment_class() {
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.glyph_mark_attachment_class(GlyphId(2)), 0);
+    assert_eq!(table.glyph_mark_attachment_class(GlyphId(3)), 1);
+    assert_eq!(table.glyph_mark_attachment_class(GlyphId(4)), 2);
+    assert_eq!(table.glyph_mark_attachment_class(GlyphId(5)), 0);
+}
+
+#[test]
+fn mark_glyph_sets() {
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    assert!(table.is_mark_glyph(GlyphId(3), Some(0)));
+    assert!(!table.is_mark_glyph(GlyphId(3), Some(1)));
+    assert!(table.is_mark_glyph(GlyphId(4), Some(1)));
+    assert!(!table.is_mark_glyph(GlyphId(4), Some(2)));
+    assert!(table.is_mark_glyph(GlyphId(4), None));
+    assert!(!table.is_mark_glyph(GlyphId(5), None));
+}
+
+#[test]
+fn no_mark_glyph_sets_in_v1_0() {
+    let mut data = gdef_data();
+    data[3] = 0; // version 1.0
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.glyph_class(GlyphId(1)), Some(GlyphClass::Base));
+    assert!(!table.is_mark_glyph(GlyphId(3), None));
+}
@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use ttf_parser::ControlFlow;
+
 struct Builder(String);
 
 impl ttf_parser::OutlineBuilder for Builder {
@@ -45,3 +47,737 @@ fn endless_loop() {
     let face = ttf_parser::Face::parse(data, 0).unwrap();
     let _ = face.outline_glyph(ttf_parser::GlyphId(0), &mut Builder(String::new()));
 }
+
+fn glyph_data() -> (Vec<u8>, Vec<u8>) {
+    use crate::{convert, Unit::*};
+
+    let glyf = convert(&[
+        Int16(1), // number of contours
+        Int16(0), // x min
+        Int16(0), // y min
+        Int16(100), // x max
+        Int16(100), // y max
+        UInt16(2), // end point [0]
+        UInt16(0), // instructions length
+        UInt8(1), // flags [0]: on curve
+        UInt8(1), // flags [1]: on curve
+        UInt8(1), // flags [2]: on curve
+        Int16(0), // x [0]
+        Int16(100), // x [1]
+        Int16(-50), // x [2]
+        Int16(0), // y [0]
+        Int16(0), // y [1]
+        Int16(100), // y [2]
+        UInt8(0), // padding
+    ]);
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(glyf.len() as u16 / 2), // offset [1]
+    ]);
+
+    (loca, glyf)
+}
+
+#[test]
+fn known_glyph_data_format() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let mut builder = Builder(String::new());
+    let bbox = face.outline_glyph(ttf_parser::GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 50 100 L 0 0 Z ");
+    assert_eq!(bbox, ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 });
+}
+
+#[test]
+fn glyph_metrics() {
+    use crate::{convert, Unit::*};
+
+    let tables = crate::RequiredTables::new(1);
+    let hmtx = convert(&[
+        UInt16(120), // advance width [0]
+        Int16(0), // side bearing [0]
+    ]);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        hmtx: Some(&hmtx),
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let metrics = face.glyph_metrics(ttf_parser::GlyphId(0)).unwrap();
+    assert_eq!(metrics, ttf_parser::GlyphMetrics {
+        hor_advance: 120,
+        // No `vmtx`, therefore ascender - descender.
+        ver_advance: 1000,
+        bbox: ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 },
+        left_side_bearing: 0,
+        right_side_bearing: 20,
+        // Relative to the ascender.
+        top_side_bearing: 700,
+        bottom_side_bearing: 200,
+    });
+    assert!(face.glyph_metrics(ttf_parser::GlyphId(1)).is_none());
+}
+
+#[test]
+fn scaled_metrics() {
+    use crate::{convert, Unit::*};
+
+    let tables = crate::RequiredTables::new(1);
+    let hmtx = convert(&[
+        UInt16(500), // advance width [0]
+        Int16(0), // side bearing [0]
+    ]);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        hmtx: Some(&hmtx),
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    // 1000 units per EM.
+    let glyph_id = ttf_parser::GlyphId(0);
+    assert_eq!(face.glyph_hor_advance_scaled(glyph_id, 16.0), Some(8.0));
+    assert_eq!(face.glyph_bounding_box_scaled(glyph_id, 20.0), Some(ttf_parser::RectF {
+        x_min: 0.0, y_min: 0.0, x_max: 2.0, y_max: 2.0,
+    }));
+    assert!(face.glyph_hor_advance_scaled(ttf_parser::GlyphId(1), 16.0).is_none());
+}
+
+#[test]
+fn raw_glyph_data() {
+    use std::num::NonZeroU16;
+    use ttf_parser::{glyf, head::IndexToLocationFormat, loca, GlyphId};
+    use crate::{convert, Unit::*};
+
+    let (_, glyf_data) = glyph_data();
+    let loca_data = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(glyf_data.len() as u16 / 2), // offset [1]
+        UInt16(glyf_data.len() as u16 / 2), // offset [2]
+    ]);
+    let number_of_glyphs = NonZeroU16::new(2).unwrap();
+    let loca = loca::Table::parse(number_of_glyphs, IndexToLocationFormat::Short, &loca_data).unwrap();
+    assert_eq!(loca.glyph_range(GlyphId(0)), Some(0..glyf_data.len()));
+    assert_eq!(loca.glyph_range(GlyphId(1)), Some(glyf_data.len()..glyf_data.len()));
+    assert_eq!(loca.glyph_range(GlyphId(2)), None);
+    let len = glyf_data.len() as u32;
+    assert_eq!(loca.offsets().collect::<Vec<_>>(), vec![0, len, len]);
+
+    let table = glyf::Table::parse(loca, &glyf_data).unwrap();
+    assert_eq!(table.glyph_data(GlyphId(0)), Some(&glyf_data[..]));
+    // An empty glyph is not a missing one.
+    assert_eq!(table.glyph_data(GlyphId(1)), Some(&[][..]));
+    assert_eq!(table.glyph_data(GlyphId(2)), None);
+    assert_eq!(table.number_of_contours(GlyphId(1)), Some(0));
+    assert!(table.bbox(GlyphId(1)).is_none());
+}
+
+#[test]
+fn bounding_box_i32() {
+    use ttf_parser::{GlyphId, RawFaceTables, Rect, RectI32};
+    use crate::{convert, Unit::*};
+
+    let glyf = convert(&[
+        // Glyph [0]
+        Int16(1), // number of contours
+        Int16(0), // x min
+        Int16(0), // y min
+        Int16(10000), // x max
+        Int16(100), // y max
+        UInt16(2), // end point [0]
+        UInt16(0), // instructions length
+        UInt8(1), // flags [0]: on curve
+        UInt8(1), // flags [1]: on curve
+        UInt8(1), // flags [2]: on curve
+        Int16(0), // x [0]
+        Int16(10000), // x [1]
+        Int16(-10000), // x [2]
+        Int16(0), // y [0]
+        Int16(0), // y [1]
+        Int16(100), // y [2]
+        UInt8(0), // padding
+
+        // Glyph [1]
+        Int16(-1), // number of contours
+        Int16(30000), // x min
+        Int16(0), // y min
+        Int16(32767), // x max
+        Int16(100), // y max
+        UInt16(0x0003), // flags: ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES
+        UInt16(0), // glyph ID
+        Int16(30000), // x offset
+        Int16(0), // y offset
+    ]);
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(15), // offset [1]
+        UInt16(24), // offset [2]
+    ]);
+
+    let tables = crate::RequiredTables::new(2);
+    let face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    let rect = face.glyph_bounding_box(GlyphId(0)).unwrap();
+    assert_eq!(rect, Rect { x_min: 0, y_min: 0, x_max: 10000, y_max: 100 });
+    assert_eq!(RectI32::from(rect).width(), 10000);
+
+    // Doesn't fit into i16.
+    assert!(face.glyph_bounding_box(GlyphId(1)).is_none());
+    let rect = face.glyph_bounding_box_i32(GlyphId(1)).unwrap();
+    assert_eq!(rect, RectI32 { x_min: 30000, y_min: 0, x_max: 40000, y_max: 100 });
+    assert_eq!(rect.width(), 10000);
+
+    assert!(face.glyph_bounding_box_i32(GlyphId(2)).is_none());
+}
+
+#[test]
+fn depth_limit() {
+    use ttf_parser::{glyf, GlyphId, OutlineError, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    let (_, mut glyf) = glyph_data();
+    for glyph_id in 0..2 {
+        glyf.extend_from_slice(&convert(&[
+            Int16(-1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(100), // x max
+            Int16(100), // y max
+            UInt16(0x0003), // flags: ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES
+            UInt16(glyph_id), // glyph ID
+            Int16(0), // x offset
+            Int16(0), // y offset
+        ]));
+    }
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(15), // offset [1]
+        UInt16(24), // offset [2]
+        UInt16(33), // offset [3]
+    ]);
+
+    let tables = crate::RequiredTables::new(3);
+    let mut face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    assert!(face.glyph_bounding_box(GlyphId(2)).is_some());
+
+    face.set_outline_depth_limit(2);
+    assert!(face.glyph_bounding_box(GlyphId(1)).is_some());
+    assert!(face.glyph_bounding_box(GlyphId(2)).is_none());
+    assert_eq!(face.validate_glyph_outline(GlyphId(2)), Err(OutlineError::DepthLimitExceeded));
+
+    face.set_outline_depth_limit(0);
+    assert!(face.glyph_bounding_box(GlyphId(0)).is_none());
+
+    // Clamped.
+    face.set_outline_depth_limit(255);
+    assert_eq!(glyf::MAX_COMPONENT_DEPTH, 32);
+    assert_eq!(glyf::MAX_ALLOWED_COMPONENT_DEPTH, 64);
+    assert!(face.glyph_bounding_box(GlyphId(2)).is_some());
+}
+
+#[test]
+fn depth_limit_above_default() {
+    use ttf_parser::{glyf, GlyphId, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    // Each glyph references the previous one, so the last one is nested 40 levels deep.
+    let (_, mut glyf) = glyph_data();
+    let mut offsets = vec![0, glyf.len() as u16];
+    for glyph_id in 0..39 {
+        glyf.extend_from_slice(&convert(&[
+            Int16(-1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(100), // x max
+            Int16(100), // y max
+            UInt16(0x0003), // flags: ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES
+            UInt16(glyph_id), // glyph ID
+            Int16(0), // x offset
+            Int16(0), // y offset
+        ]));
+        offsets.push(glyf.len() as u16);
+    }
+
+    let loca: Vec<_> = offsets.iter().map(|offset| UInt16(offset / 2)).collect();
+    let loca = convert(&loca);
+
+    let tables = crate::RequiredTables::new(40);
+    let mut face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    assert!(face.glyph_bounding_box(GlyphId(39)).is_none());
+
+    face.set_outline_depth_limit(glyf::MAX_ALLOWED_COMPONENT_DEPTH);
+    assert!(face.glyph_bounding_box(GlyphId(39)).is_some());
+}
+
+#[test]
+fn cubic_outline() {
+    use ttf_parser::{GlyphId, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    let glyf = convert(&[
+        Int16(2), // number of contours
+        Int16(0), // x min
+        Int16(0), // y min
+        Int16(100), // x max
+        Int16(100), // y max
+        UInt16(3), // end point [0]
+        UInt16(7), // end point [1]
+        UInt16(0), // instructions length
+        UInt8(0x01), // flags [0]: on curve
+        UInt8(0x80), // flags [1]: cubic
+        UInt8(0x80), // flags [2]: cubic
+        UInt8(0x01), // flags [3]: on curve
+        UInt8(0x80), // flags [4]: cubic
+        UInt8(0x80), // flags [5]: cubic
+        UInt8(0x80), // flags [6]: cubic
+        UInt8(0x80), // flags [7]: cubic
+        Int16(0), // x [0]
+        Int16(0), // x [1]
+        Int16(100), // x [2]
+        Int16(0), // x [3]
+        Int16(-100), // x [4]
+        Int16(0), // x [5]
+        Int16(100), // x [6]
+        Int16(0), // x [7]
+        Int16(0), // y [0]
+        Int16(100), // y [1]
+        Int16(0), // y [2]
+        Int16(-100), // y [3]
+        Int16(0), // y [4]
+        Int16(100), // y [5]
+        Int16(0), // y [6]
+        Int16(-100), // y [7]
+    ]);
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(glyf.len() as u16 / 2), // offset [1]
+    ]);
+
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    let mut builder = Builder(String::new());
+    face.outline_glyph(GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0,
+        "M 0 0 C 0 100 100 100 100 0 L 0 0 Z \
+         M 50 100 C 100 100 100 0 50 0 C 0 0 0 100 50 100 Z ");
+}
+
+#[test]
+fn validate_glyph_outline() {
+    use ttf_parser::{GlyphId, OutlineError, RawFaceTables, Rect};
+
+    let (loca, glyf) = glyph_data();
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    let rect = Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 };
+    assert_eq!(face.glyph_bounding_box(GlyphId(0)), Some(rect));
+    assert_eq!(face.validate_glyph_outline(GlyphId(0)), Ok(rect));
+    assert_eq!(face.validate_glyph_outline(GlyphId(1)), Err(OutlineError::Empty));
+
+    let face = tables.face(RawFaceTables::default());
+    assert_eq!(face.validate_glyph_outline(GlyphId(0)), Err(OutlineError::MissingTable));
+}
+
+#[test]
+fn contour_winding() {
+    use ttf_parser::{
+        GlyphId, IntegerOutlineBuilder, OutlineBuilder, RawFaceTables,
+        StoppableOutlineBuilder,
+    };
+    use ttf_parser::outline::FlipY;
+
+    struct WindingBuilder(Vec<bool>);
+
+    impl OutlineBuilder for WindingBuilder {
+        fn move_to(&mut self, _: f32, _: f32) {}
+        fn line_to(&mut self, _: f32, _: f32) {}
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+        fn close(&mut self) {}
+
+        fn contour_winding(&mut self, clockwise: bool) {
+            self.0.push(clockwise);
+        }
+    }
+
+    impl IntegerOutlineBuilder for WindingBuilder {
+        fn move_to(&mut self, _: i32, _: i32) {}
+        fn line_to(&mut self, _: i32, _: i32) {}
+        fn quad_to(&mut self, _: i32, _: i32, _: i32, _: i32) {}
+        fn curve_to(&mut self, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32) {}
+        fn close(&mut self) {}
+
+        fn contour_winding(&mut self, clockwise: bool) {
+            self.0.push(clockwise);
+        }
+    }
+
+    impl StoppableOutlineBuilder for WindingBuilder {
+        fn move_to(&mut self, _: f32, _: f32) -> ControlFlow { ControlFlow::Continue }
+        fn line_to(&mut self, _: f32, _: f32) -> ControlFlow { ControlFlow::Continue }
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) -> ControlFlow { ControlFlow::Continue }
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) -> ControlFlow { ControlFlow::Continue }
+        fn close(&mut self) -> ControlFlow { ControlFlow::Continue }
+
+        fn contour_winding(&mut self, clockwise: bool) {
+            self.0.push(clockwise);
+        }
+    }
+
+    let (loca, glyf) = glyph_data();
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..RawFaceTables::default()
+    });
+
+    let mut builder = WindingBuilder(Vec::new());
+    assert!(face.outline_glyph(GlyphId(0), &mut builder).is_some());
+    assert_eq!(builder.0, vec![false]);
+
+    let mut builder = WindingBuilder(Vec::new());
+    assert!(face.outline_glyph(GlyphId(0), &mut FlipY::new(&mut builder, 0.0)).is_some());
+    assert_eq!(builder.0, vec![true]);
+
+    let mut builder = WindingBuilder(Vec::new());
+    assert!(face.outline_glyph_i32(GlyphId(0), &mut builder).is_some());
+    assert_eq!(builder.0, vec![false]);
+
+    let mut builder = WindingBuilder(Vec::new());
+    assert!(face.outline_glyph_stoppable(GlyphId(0), &mut builder).is_some());
+    assert_eq!(builder.0, vec![false]);
+
+    let mut builder = WindingBuilder(Vec::new());
+    let mut adapter = ttf_parser::NeverStop(&mut builder);
+    assert!(face.outline_glyph_stoppable(GlyphId(0), &mut adapter).is_some());
+    assert_eq!(builder.0, vec![false]);
+}
+
+#[test]
+fn unknown_glyph_data_format() {
+    let mut head = crate::head_data();
+    head[52..54].copy_from_slice(&1i16.to_be_bytes()); // glyph data format
+    let hhea = crate::hhea_data();
+    let maxp = crate::maxp_data(1);
+    let (loca, glyf) = glyph_data();
+    let face = ttf_parser::Face::from_raw_tables(ttf_parser::RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    }).unwrap();
+
+    assert_eq!(face.tables().head.glyph_data_format, 1);
+    assert!(face.tables().glyf.is_none());
+    let mut builder = Builder(String::new());
+    assert!(face.outline_glyph(ttf_parser::GlyphId(0), &mut builder).is_none());
+    assert!(builder.0.is_empty());
+}
+
+#[test]
+fn outline_sources() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let sources = face.outline_sources();
+    assert_eq!(sources.0, ttf_parser::OutlineSources::GLYF);
+    assert!(sources.glyf());
+    assert!(!sources.cff());
+    assert!(!sources.has_cff_conflict());
+
+    let face = tables.face(ttf_parser::RawFaceTables::default());
+
+    assert!(face.outline_sources().is_empty());
+}
+
+struct IntegerBuilder(String);
+
+impl ttf_parser::IntegerOutlineBuilder for IntegerBuilder {
+    fn move_to(&mut self, x: i32, y: i32) {
+        write!(&mut self.0, "M {} {} ", x, y).unwrap();
+    }
+
+    fn line_to(&mut self, x: i32, y: i32) {
+        write!(&mut self.0, "L {} {} ", x, y).unwrap();
+    }
+
+    fn quad_to(&mut self, x1: i32, y1: i32, x: i32, y: i32) {
+        write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+    }
+
+    fn curve_to(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x: i32, y: i32) {
+        write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+    }
+
+    fn close(&mut self) {
+        write!(&mut self.0, "Z ").unwrap();
+    }
+}
+
+#[test]
+fn outline_glyph_i32() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let mut builder = IntegerBuilder(String::new());
+    let bbox = face.outline_glyph_i32(ttf_parser::GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 50 100 L 0 0 Z ");
+    assert_eq!(bbox, ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 });
+}
+
+// Stops after the specified number of segments.
+struct StoppableBuilder(String, usize);
+
+impl StoppableBuilder {
+    fn flow(&mut self) -> ControlFlow {
+        self.1 -= 1;
+        if self.1 == 0 {
+            ControlFlow::Break
+        } else {
+            ControlFlow::Continue
+        }
+    }
+}
+
+impl ttf_parser::StoppableOutlineBuilder for StoppableBuilder {
+    fn move_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "M {} {} ", x, y).unwrap();
+        self.flow()
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "L {} {} ", x, y).unwrap();
+        self.flow()
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+        self.flow()
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> ControlFlow {
+        write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+        self.flow()
+    }
+
+    fn close(&mut self) -> ControlFlow {
+        write!(&mut self.0, "Z ").unwrap();
+        self.flow()
+    }
+}
+
+#[test]
+fn outline_glyph_stoppable() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let mut builder = StoppableBuilder(String::new(), 2);
+    let bbox = face.outline_glyph_stoppable(ttf_parser::GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 ");
+    // Covers only the emitted segments.
+    assert_eq!(bbox, ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 0 });
+
+    let mut builder = StoppableBuilder(String::new(), 100);
+    let bbox = face.outline_glyph_stoppable(ttf_parser::GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 50 100 L 0 0 Z ");
+    assert_eq!(bbox, ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 });
+}
+
+#[test]
+fn glyph_contains_point() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let glyph_id = ttf_parser::GlyphId(0);
+    assert_eq!(face.glyph_contains_point(glyph_id, 50.0, 50.0), Some(true));
+    assert_eq!(face.glyph_contains_point(glyph_id, 10.0, 5.0), Some(true));
+    assert_eq!(face.glyph_contains_point(glyph_id, 10.0, 50.0), Some(false));
+    assert_eq!(face.glyph_contains_point(glyph_id, 50.0, 150.0), Some(false));
+    assert_eq!(face.glyph_contains_point(ttf_parser::GlyphId(1), 50.0, 50.0), None);
+}
+
+#[test]
+fn outline_glyph_from() {
+    use ttf_parser::OutlineSource;
+
+    let tables = crate::RequiredTables::new(1);
+    let (loca, glyf) = glyph_data();
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let id = ttf_parser::GlyphId(0);
+    let mut builder = Builder(String::new());
+    assert!(face.outline_glyph_from(id, OutlineSource::Glyf, &mut builder).is_some());
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 50 100 L 0 0 Z ");
+
+    let mut builder = Builder(String::new());
+    assert!(face.outline_glyph_from(id, OutlineSource::Auto, &mut builder).is_some());
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 50 100 L 0 0 Z ");
+
+    // No fallback to `glyf`.
+    let mut builder = Builder(String::new());
+    assert!(face.outline_glyph_from(id, OutlineSource::Cff, &mut builder).is_none());
+    assert!(face.outline_glyph_from(id, OutlineSource::Cff2, &mut builder).is_none());
+    assert!(builder.0.is_empty());
+}
+
+#[test]
+fn glyph_bounding_box_fast() {
+    let tables = crate::RequiredTables::new(1);
+    let (loca, mut glyf) = glyph_data();
+    glyf[2..4].copy_from_slice(&(-5i16).to_be_bytes()); // x min
+    glyf[8..10].copy_from_slice(&200i16.to_be_bytes()); // y max
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let id = ttf_parser::GlyphId(0);
+    // The stored bbox is returned as is.
+    assert_eq!(face.glyph_bounding_box_fast(id), Some(ttf_parser::Rect { x_min: -5, y_min: 0, x_max: 100, y_max: 200 }));
+    assert_eq!(face.glyph_bounding_box(id), Some(ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 100 }));
+    assert_eq!(face.glyph_bounding_box_fast(ttf_parser::GlyphId(1)), None);
+}
+
+#[test]
+fn number_of_contours() {
+    use crate::{convert, Unit::*};
+
+    let glyf = convert(&[
+        // Glyph [0]
+        Int16(-1), // number of contours
+        Int16(0), // x min
+        Int16(0), // y min
+        Int16(100), // x max
+        Int16(100), // y max
+        UInt16(0x0002), // flags: ARGS_ARE_XY_VALUES
+        UInt16(2), // glyph index
+        Int8(0), // dx
+        Int8(0), // dy
+
+        // Glyph [1] is empty.
+
+        // Glyph [2]
+        Int16(1), // number of contours
+        Int16(0), // x min
+        Int16(0), // y min
+        Int16(100), // x max
+        Int16(100), // y max
+        UInt16(0), // end point [0]
+        UInt16(0), // instructions length
+        UInt8(1), // flags [0]: on curve
+        Int16(0), // x [0]
+        Int16(0), // y [0]
+        UInt8(0), // padding
+    ]);
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(8), // offset [1]
+        UInt16(8), // offset [2]
+        UInt16(glyf.len() as u16 / 2), // offset [3]
+    ]);
+
+    let tables = crate::RequiredTables::new(3);
+    let face = tables.face(ttf_parser::RawFaceTables {
+        loca: Some(&loca),
+        glyf: Some(&glyf),
+        ..ttf_parser::RawFaceTables::default()
+    });
+
+    let glyf = face.tables().glyf.unwrap();
+    assert_eq!(glyf.number_of_contours(ttf_parser::GlyphId(0)), Some(-1));
+    assert_eq!(glyf.number_of_contours(ttf_parser::GlyphId(1)), Some(0));
+    assert_eq!(glyf.number_of_contours(ttf_parser::GlyphId(2)), Some(1));
+    assert_eq!(glyf.number_of_contours(ttf_parser::GlyphId(3)), None);
+
+    assert!(face.is_composite_glyph(ttf_parser::GlyphId(0)));
+    assert!(!face.is_composite_glyph(ttf_parser::GlyphId(1)));
+    assert!(!face.is_composite_glyph(ttf_parser::GlyphId(2)));
+    assert!(!face.is_composite_glyph(ttf_parser::GlyphId(3)));
+}
+
+#[test]
+fn number_of_contours_with_malformed_loca() {
+    use std::num::NonZeroU16;
+    use ttf_parser::{glyf, head::IndexToLocationFormat, loca, GlyphId};
+    use crate::{convert, Unit::*};
+
+    let (_, glyf_data) = glyph_data();
+    let loca_data = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(glyf_data.len() as u16 / 2), // offset [1]
+        UInt16(0), // offset [2]: not in ascending order
+        UInt16(0x1000), // offset [3]: past the end of `glyf`
+    ]);
+    let number_of_glyphs = NonZeroU16::new(3).unwrap();
+    let loca = loca::Table::parse(number_of_glyphs, IndexToLocationFormat::Short, &loca_data).unwrap();
+    let table = glyf::Table::parse(loca, &glyf_data).unwrap();
+    assert_eq!(table.number_of_contours(GlyphId(0)), Some(1));
+    assert_eq!(table.number_of_contours(GlyphId(1)), None);
+    assert_eq!(table.number_of_contours(GlyphId(2)), None);
+}
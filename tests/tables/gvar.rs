@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use ttf_parser::gvar::Table;
+use ttf_parser::{GlyphId, NormalizedCoordinate, RawFaceTables, Rect, Tag, Variation, Variations};
+use crate::{convert, Unit::*};
+
+#[test]
+fn has_deltas_short_offsets() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        UInt16(1), // axis count
+        UInt16(0), // shared tuple count
+        UInt32(28), // offset to shared tuples
+        UInt16(3), // glyph count
+        UInt16(0), // flags: short offsets
+        UInt32(28), // offset to glyph variation data array
+        UInt16(0), // offset [0]
+        UInt16(0), // offset [1]
+        UInt16(2), // offset [2]
+        UInt16(2), // offset [3]
+        Raw(&[0; 4]), // glyph variation data [1]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(!table.has_deltas(GlyphId(0)));
+    assert!(table.has_deltas(GlyphId(1)));
+    assert!(!table.has_deltas(GlyphId(2)));
+    assert!(!table.has_deltas(GlyphId(3)));
+}
+
+#[test]
+fn has_deltas_long_offsets() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        UInt16(1), // axis count
+        UInt16(0), // shared tuple count
+        UInt32(36), // offset to shared tuples
+        UInt16(3), // glyph count
+        UInt16(1), // flags: long offsets
+        UInt32(36), // offset to glyph variation data array
+        UInt32(0), // offset [0]
+        UInt32(4), // offset [1]
+        UInt32(4), // offset [2]
+        UInt32(100), // offset [3]
+        Raw(&[0; 4]), // glyph variation data [0]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(table.has_deltas(GlyphId(0)));
+    assert!(!table.has_deltas(GlyphId(1)));
+    // Out of bounds data.
+    assert!(!table.has_deltas(GlyphId(2)));
+}
+
+struct Builder(String);
+
+impl ttf_parser::OutlineBuilder for Builder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        write!(&mut self.0, "M {} {} ", x, y).unwrap();
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        write!(&mut self.0, "L {} {} ", x, y).unwrap();
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+    }
+
+    fn close(&mut self) {
+        write!(&mut self.0, "Z ").unwrap();
+    }
+}
+
+#[test]
+fn outline_glyph_instance() {
+    let fvar = convert(&[
+        Fixed(1.0), // version
+        UInt16(16), // axes array offset
+        UInt16(2), // reserved
+        UInt16(1), // axis count
+        UInt16(20), // axis size
+        UInt16(0), // instance count
+        UInt16(8), // instance size
+
+        // Axis [0]
+        Raw(b"wght"), // tag
+        Fixed(100.0), // min value
+        Fixed(400.0), // default value
+        Fixed(900.0), // max value
+        UInt16(0), // flags
+        UInt16(256), // name ID
+    ]);
+
+    let glyf = convert(&[
+        Int16(1), // number of contours
+        Int16(0), Int16(0), Int16(100), Int16(100), // bbox
+        UInt16(2), // end point [0]
+        UInt16(0), // instructions length
+        UInt8(1), UInt8(1), UInt8(1), // flags: on curve
+        Int16(0), Int16(100), Int16(-100), // x coordinates
+        Int16(0), Int16(0), Int16(100), // y coordinates
+        UInt8(0), // padding
+    ]);
+
+    let loca = convert(&[
+        UInt16(0), // offset [0]
+        UInt16(15), // offset [1]
+    ]);
+
+    let gvar = convert(&[
+        Fixed(1.0), // version
+        UInt16(1), // axis count
+        UInt16(0), // shared tuple count
+        UInt32(24), // offset to shared tuples
+        UInt16(1), // glyph count
+        UInt16(0), // flags: short offsets
+        UInt32(24), // offset to glyph variation data array
+        UInt16(0), // offset [0]
+        UInt16(9), // offset [1]
+
+        // Glyph variation data [0]
+        UInt16(1), // tuple variation count
+        UInt16(10), // offset to serialized data
+        UInt16(7), // variation data size
+        UInt16(0xA000), // tuple index: embedded peak tuple, private point numbers
+        Int16(16384), // peak tuple: 1.0
+        // Serialized data
+        UInt8(0), // point numbers: all points
+        UInt8(2), Int8(10), Int8(10), Int8(10), // x deltas
+        UInt8(0x83), // x deltas: 4 zeros for phantom points
+        UInt8(0x86), // y deltas: 7 zeros
+        UInt8(0), // padding
+    ]);
+
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables {
+        fvar: Some(&fvar),
+        glyf: Some(&glyf),
+        gvar: Some(&gvar),
+        loca: Some(&loca),
+        ..RawFaceTables::default()
+    });
+
+    let mut builder = Builder(String::new());
+    let bbox = face.outline_glyph_instance(
+        GlyphId(0),
+        &[NormalizedCoordinate::from(16384)],
+        &mut builder,
+    ).unwrap();
+    assert_eq!(builder.0, "M 10 0 L 110 0 L 10 100 L 10 0 Z ");
+    assert_eq!(bbox, Rect { x_min: 10, y_min: 0, x_max: 110, y_max: 100 });
+
+    // Face's own coordinates are not affected.
+    let mut builder = Builder(String::new());
+    face.outline_glyph(GlyphId(0), &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 0 100 L 0 0 Z ");
+
+    // Missing coordinates are zero.
+    let mut builder = Builder(String::new());
+    face.outline_glyph_instance(GlyphId(0), &[], &mut builder).unwrap();
+    assert_eq!(builder.0, "M 0 0 L 100 0 L 0 100 L 0 0 Z ");
+
+    let variations = Variations::new(&face, &[Variation { axis: Tag::from_bytes(b"wght"), value: 900.0 }]);
+    let mut builder = Builder(String::new());
+    face.outline_glyph_with(GlyphId(0), &variations, &mut builder).unwrap();
+    assert_eq!(builder.0, "M 10 0 L 110 0 L 10 100 L 10 0 Z ");
+}
@@ -0,0 +1,72 @@
+use core::num::NonZeroU16;
+use ttf_parser::{hdmx::Table, GlyphId, RawFaceTables};
+use crate::{convert, Unit::*};
+
+fn padded_data() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        Int16(2), // number of records
+        Int32(8), // size of a record
+
+        // DeviceRecord [0]
+        UInt8(12), // pixel size
+        UInt8(9), // max width
+        UInt8(7), // width [0]
+        UInt8(9), // width [1]
+        UInt8(8), // width [2]
+        Raw(&[0, 0, 0]), // padding
+
+        // DeviceRecord [1]
+        UInt8(16), // pixel size
+        UInt8(12), // max width
+        UInt8(10), // width [0]
+        UInt8(12), // width [1]
+        UInt8(11), // width [2]
+        Raw(&[0, 0, 0]), // padding
+    ])
+}
+
+#[test]
+fn padded_records() {
+    let data = padded_data();
+    let table = Table::parse(NonZeroU16::new(3).unwrap(), &data).unwrap();
+    assert_eq!(table.records.len(), 2);
+    assert_eq!(table.records.into_iter().count(), 2);
+
+    let record = table.records.get(1).unwrap();
+    assert_eq!(record.pixel_size, 16);
+    assert_eq!(record.max_width, 12);
+    assert_eq!(record.widths, &[10, 12, 11]);
+
+    let record = table.records.find(12).unwrap();
+    assert_eq!(record.width(GlyphId(1)), Some(9));
+    assert_eq!(record.width(GlyphId(3)), None);
+    assert!(table.records.find(14).is_none());
+}
+
+#[test]
+fn record_size_too_small() {
+    let data = convert(&[
+        UInt16(0), // version
+        Int16(1), // number of records
+        Int32(4), // size of a record
+        Raw(&[12, 9, 7, 9]),
+    ]);
+
+    assert!(Table::parse(NonZeroU16::new(3).unwrap(), &data).is_none());
+}
+
+#[test]
+fn glyph_pixel_advance() {
+    let tables = crate::RequiredTables::new(3);
+    let hdmx = padded_data();
+    let face = tables.face(RawFaceTables {
+        hdmx: Some(&hdmx),
+        ..RawFaceTables::default()
+    });
+
+    assert_eq!(face.glyph_pixel_advance(GlyphId(2), 16), Some(11));
+    assert_eq!(face.glyph_pixel_advance(GlyphId(0), 12), Some(7));
+    assert_eq!(face.glyph_pixel_advance(GlyphId(0), 14), None);
+    assert_eq!(face.glyph_pixel_advance(GlyphId(0), 300), None);
+}
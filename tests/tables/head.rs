@@ -0,0 +1,110 @@
+use ttf_parser::head::{MacStyle, Table};
+use ttf_parser::{Face, RawFaceTables};
+
+#[test]
+fn basic() {
+    let mut data = crate::head_data();
+    data[16..18].copy_from_slice(&0x000Bu16.to_be_bytes()); // flags
+    data[44..46].copy_from_slice(&0x0022u16.to_be_bytes()); // mac style
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.flags, 0x000B);
+    assert_eq!(table.lowest_rec_ppem, 8);
+    assert_eq!(table.mac_style, MacStyle(MacStyle::ITALIC | MacStyle::CONDENSED));
+    assert!(table.mac_style.italic());
+    assert!(table.mac_style.condensed());
+    assert!(!table.mac_style.bold());
+}
+
+#[test]
+fn mac_style_fallback() {
+    let mut head = crate::head_data();
+    head[44..46].copy_from_slice(&0x0003u16.to_be_bytes()); // mac style
+    let hhea = crate::hhea_data();
+    let maxp = crate::maxp_data(1);
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+
+    assert!(face.is_bold());
+    assert!(face.is_italic());
+}
+
+#[test]
+fn synthetic_styles() {
+    let mut head = crate::head_data();
+    let hhea = crate::hhea_data();
+    let maxp = crate::maxp_data(1);
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+
+    assert!(face.needs_synthetic_bold(true));
+    assert!(!face.needs_synthetic_bold(false));
+    assert!(face.needs_synthetic_italic(true));
+    assert!(!face.needs_synthetic_italic(false));
+
+    head[44..46].copy_from_slice(&0x0003u16.to_be_bytes()); // mac style
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+
+    assert!(!face.needs_synthetic_bold(true));
+    assert!(!face.needs_synthetic_italic(true));
+}
+
+#[test]
+fn timestamps() {
+    use ttf_parser::head::DateTime;
+
+    let mut head = crate::head_data();
+    head[20..28].copy_from_slice(&3706609530i64.to_be_bytes()); // created
+    head[28..36].copy_from_slice(&(-121046401i64).to_be_bytes()); // modified
+    let hhea = crate::hhea_data();
+    let maxp = crate::maxp_data(1);
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+
+    assert_eq!(face.tables().head.created, 3706609530);
+    assert_eq!(face.created_time(), Some(DateTime {
+        year: 2021, month: 6, day: 15, hour: 13, minute: 45, second: 30,
+    }));
+    assert_eq!(face.modified_time(), Some(DateTime {
+        year: 1900, month: 2, day: 28, hour: 23, minute: 59, second: 59,
+    }));
+
+    // Epoch.
+    assert_eq!(DateTime::from_long_date_time(0), Some(DateTime {
+        year: 1904, month: 1, day: 1, hour: 0, minute: 0, second: 0,
+    }));
+    // Leap day.
+    assert_eq!(DateTime::from_long_date_time(59 * 86400), Some(DateTime {
+        year: 1904, month: 2, day: 29, hour: 0, minute: 0, second: 0,
+    }));
+    assert_eq!(DateTime::from_long_date_time(0x7FFF_FFFF_FFFF_FFFF), None);
+}
+
+#[test]
+fn system_time() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let mut data = crate::head_data();
+    data[20..28].copy_from_slice(&3706609530i64.to_be_bytes()); // created
+    data[28..36].copy_from_slice(&2082844799i64.to_be_bytes()); // modified
+    let table = ttf_parser::head::Table::parse(&data).unwrap();
+    assert_eq!(table.created_system_time(), Some(UNIX_EPOCH + Duration::from_secs(1623764730)));
+    assert_eq!(table.modified_system_time(), Some(UNIX_EPOCH - Duration::from_secs(1)));
+}
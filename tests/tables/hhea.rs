@@ -0,0 +1,62 @@
+use ttf_parser::{hhea, vhea, Face, RawFaceTables};
+use crate::{convert, Unit::*};
+
+fn header_data() -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        Int16(800), // ascender
+        Int16(-200), // descender
+        Int16(0), // line gap
+        UInt16(1000), // max advance
+        Int16(0), // min side bearing [0]
+        Int16(0), // min side bearing [1]
+        Int16(0), // max extent
+        Int16(1000), // caret slope rise
+        Int16(200), // caret slope run
+        Int16(-10), // caret offset
+        Raw(&[0; 8]), // reserved
+        Int16(0), // metric data format
+        UInt16(3), // number of metrics
+    ])
+}
+
+#[test]
+fn caret() {
+    let data = header_data();
+
+    let table = hhea::Table::parse(&data).unwrap();
+    assert_eq!(table.caret_slope_rise, 1000);
+    assert_eq!(table.caret_slope_run, 200);
+    assert_eq!(table.caret_offset, -10);
+    assert_eq!(table.number_of_metrics, 3);
+
+    let table = vhea::Table::parse(&data).unwrap();
+    assert_eq!(table.caret_slope_rise, 1000);
+    assert_eq!(table.caret_slope_run, 200);
+    assert_eq!(table.caret_offset, -10);
+    assert_eq!(table.number_of_metrics, 3);
+}
+
+#[test]
+fn face_caret_slope() {
+    let head = crate::head_data();
+    let hhea = header_data();
+    let maxp = crate::maxp_data(3);
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+    assert_eq!(face.caret_slope(), Some((1000, 200, -10)));
+
+    // Undefined slope.
+    let hhea = crate::hhea_data();
+    let face = Face::from_raw_tables(RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &maxp,
+        ..RawFaceTables::default()
+    }).unwrap();
+    assert_eq!(face.caret_slope(), None);
+}
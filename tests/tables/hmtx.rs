@@ -78,8 +78,31 @@ fn less_metrics_than_glyphs() {
     ]);
 
     let table = Table::parse(2, nzu16!(1), &data).unwrap();
+    assert_eq!(table.number_of_metrics, 1);
     assert_eq!(table.side_bearing(GlyphId(0)), Some(2));
+    // Metrics past the number of glyphs are ignored.
+    assert_eq!(table.side_bearing(GlyphId(1)), None);
+    assert_eq!(table.side_bearing(GlyphId(2)), None);
+}
+
+#[test]
+fn inflated_number_of_metrics() {
+    let data = convert(&[
+        UInt16(1), // advance width [0]
+        Int16(2), // side bearing [0]
+
+        UInt16(3), // advance width [1]
+        Int16(4), // side bearing [1]
+
+        // The table declares 10 metrics, but the rest is missing.
+    ]);
+
+    let table = Table::parse(10, nzu16!(2), &data).unwrap();
+    assert_eq!(table.number_of_metrics, 2);
+    assert_eq!(table.advance(GlyphId(0)), Some(1));
+    assert_eq!(table.advance(GlyphId(1)), Some(3));
     assert_eq!(table.side_bearing(GlyphId(1)), Some(4));
+    assert_eq!(table.advance(GlyphId(2)), None);
     assert_eq!(table.side_bearing(GlyphId(2)), None);
 }
 
@@ -112,3 +135,20 @@ fn glyph_out_of_bounds_1() {
     assert_eq!(table.advance(GlyphId(2)), None);
     assert_eq!(table.side_bearing(GlyphId(2)), None);
 }
+
+#[test]
+fn glyph_metrics() {
+    let data = convert(&[
+        UInt16(1), // advance width [0]
+        Int16(2), // side bearing [0]
+        UInt16(3), // advance width [1]
+        Int16(4), // side bearing [1]
+
+        Int16(5), // side bearing [2]
+        Int16(6), // side bearing [3]
+    ]);
+
+    let table = Table::parse(2, nzu16!(4), &data).unwrap();
+    let metrics: Vec<_> = table.glyph_metrics().map(|m| (m.advance, m.side_bearing)).collect();
+    assert_eq!(metrics, vec![(1, 2), (3, 4), (3, 5), (3, 6)]);
+}
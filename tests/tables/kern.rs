@@ -0,0 +1,114 @@
+use ttf_parser::kern::{Format, Table};
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+#[test]
+fn format0() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of subtables
+
+        // Subtable [0]
+        UInt16(0), // version
+        UInt16(26), // length
+        UInt8(0), // format
+        UInt8(0x01), // coverage: horizontal
+        UInt16(2), // number of pairs
+        UInt16(0), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+        UInt16(1), UInt16(2), Int16(-50), // pair [0]
+        UInt16(1), UInt16(3), Int16(-20), // pair [1]
+
+        // Subtable [1]
+        UInt16(0), // version
+        UInt16(20), // length
+        UInt8(0), // format
+        UInt8(0x0D), // coverage: horizontal, cross-stream, override
+        UInt16(1), // number of pairs
+        UInt16(0), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+        UInt16(4), UInt16(5), Int16(10), // pair [0]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtables: Vec<_> = table.subtables.into_iter().collect();
+    assert_eq!(subtables.len(), 2);
+
+    let subtable = &subtables[0];
+    assert!(subtable.horizontal);
+    assert!(!subtable.has_cross_stream);
+    assert!(!subtable.has_minimum);
+    assert!(!subtable.has_override);
+    assert_eq!(subtable.glyphs_kerning(GlyphId(1), GlyphId(3)), Some(-20));
+    assert_eq!(subtable.glyphs_kerning(GlyphId(1), GlyphId(4)), None);
+    match subtable.format {
+        Format::Format0(ref subtable) => {
+            let pairs: Vec<_> = subtable.glyph_pairs().collect();
+            assert_eq!(pairs, vec![(GlyphId(1), GlyphId(2), -50), (GlyphId(1), GlyphId(3), -20)]);
+        }
+        _ => panic!("unexpected format"),
+    }
+
+    let subtable = &subtables[1];
+    assert!(subtable.horizontal);
+    assert!(subtable.has_cross_stream);
+    assert!(!subtable.has_minimum);
+    assert!(subtable.has_override);
+    assert_eq!(subtable.glyphs_kerning(GlyphId(4), GlyphId(5)), Some(10));
+}
+
+#[test]
+fn format2() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of subtables
+
+        // Subtable [0]
+        UInt16(0), // version
+        UInt16(40), // length
+        UInt8(2), // format
+        UInt8(0x01), // coverage: horizontal
+        UInt16(4), // row width
+        UInt16(14), // offset to left-hand class table
+        UInt16(24), // offset to right-hand class table
+        UInt16(32), // offset to kerning values array
+
+        // Left-hand class table
+        UInt16(1), // first glyph
+        UInt16(3), // number of classes
+        UInt16(32), UInt16(36), UInt16(1000), // classes
+
+        // Right-hand class table
+        UInt16(3), // first glyph
+        UInt16(2), // number of classes
+        UInt16(0), UInt16(2), // classes
+
+        // Kerning values array
+        Int16(-10), Int16(-20), // row [0]
+        Int16(-30), Int16(-40), // row [1]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtable = table.subtables.into_iter().next().unwrap();
+    assert_eq!(subtable.glyphs_kerning(GlyphId(1), GlyphId(3)), Some(-10));
+    assert_eq!(subtable.glyphs_kerning(GlyphId(1), GlyphId(4)), Some(-20));
+    assert_eq!(subtable.glyphs_kerning(GlyphId(2), GlyphId(3)), Some(-30));
+    assert_eq!(subtable.glyphs_kerning(GlyphId(2), GlyphId(4)), Some(-40));
+    // Not in the left-hand class range.
+    assert_eq!(subtable.glyphs_kerning(GlyphId(5), GlyphId(3)), None);
+    // Class offset past the table end.
+    assert_eq!(subtable.glyphs_kerning(GlyphId(3), GlyphId(3)), None);
+
+    match subtable.format {
+        Format::Format2(ref subtable) => {
+            assert_eq!(subtable.row_width, 4);
+            let classes = subtable.right_hand_classes.unwrap();
+            assert_eq!(classes.first_glyph, GlyphId(3));
+            assert_eq!(classes.get(GlyphId(4)), Some(2));
+            assert_eq!(classes.get(GlyphId(5)), None);
+        }
+        _ => panic!("unexpected format"),
+    }
+}
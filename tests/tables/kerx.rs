@@ -0,0 +1,219 @@
+use std::num::NonZeroU16;
+use ttf_parser::{ankr, GlyphId};
+use ttf_parser::kerx::{Position, Table};
+use crate::{convert, Unit, Unit::*};
+
+fn table_data(format: u8, body: &[Unit]) -> Vec<u8> {
+    let body = convert(body);
+    let mut data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // padding
+        UInt32(1), // number of subtables
+
+        // Subtable [0]
+        UInt32(12 + body.len() as u32), // length
+        UInt8(0), // coverage: horizontal
+        UInt16(0), // unused
+        UInt8(format), // format
+        UInt32(0), // tuple count
+    ]);
+    data.extend_from_slice(&body);
+    data
+}
+
+fn apply(data: &[u8], glyphs: &[u16], ankr: Option<&ankr::Table>) -> Vec<(i32, i32, i32)> {
+    let table = Table::parse(NonZeroU16::new(10).unwrap(), data).unwrap();
+    let glyphs: Vec<_> = glyphs.iter().map(|id| GlyphId(*id)).collect();
+    let mut positions = vec![Position { x_advance: 500, ..Position::default() }; glyphs.len()];
+    table.apply(&glyphs, &mut positions, ankr);
+    positions.iter().map(|p| (p.x_advance, p.x_offset, p.y_offset)).collect()
+}
+
+#[test]
+fn format0() {
+    let data = table_data(0, &[
+        UInt32(1), // number of pairs
+        UInt32(0), // search range
+        UInt32(0), // entry selector
+        UInt32(0), // range shift
+        UInt16(1), // left
+        UInt16(2), // right
+        Int16(-50), // value
+    ]);
+
+    assert_eq!(apply(&data, &[1, 2, 1], None), vec![(450, 0, 0), (500, 0, 0), (500, 0, 0)]);
+}
+
+// Kerns `1 2` by -100.
+#[test]
+fn format1() {
+    let data = table_data(1, &[
+        UInt32(6), // number of classes
+        UInt32(20), // offset to class table
+        UInt32(30), // offset to state array
+        UInt32(66), // offset to entry table
+        UInt32(84), // offset to actions
+
+        // Class table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(2), // number of glyphs
+        UInt16(4), UInt16(5), // classes
+
+        // State array
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of text
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of line
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(2), // seen `1`
+
+        // Entry [0]
+        UInt16(0), // new state
+        UInt16(0), // flags
+        UInt16(0xFFFF), // action index
+        // Entry [1]
+        UInt16(2), // new state
+        UInt16(0xA000), // flags: push, reset
+        UInt16(0xFFFF), // action index
+        // Entry [2]
+        UInt16(0), // new state
+        UInt16(0x8000), // flags: push
+        UInt16(0), // action index
+
+        // Actions
+        Int16(0), // `2`
+        Int16(-99), // `1`, last
+    ]);
+
+    assert_eq!(
+        apply(&data, &[1, 2, 3, 1, 1, 2], None),
+        vec![(400, -100, 0), (500, 0, 0), (500, 0, 0), (500, 0, 0), (400, -100, 0), (500, 0, 0)]
+    );
+}
+
+// Attaches `2` to `1` using anchor points.
+#[test]
+fn format4() {
+    let data = table_data(4, &[
+        UInt32(6), // number of classes
+        UInt32(20), // offset to class table
+        UInt32(30), // offset to state array
+        UInt32(66), // offset to entry table
+        UInt32(0x40000000 | 84), // flags: anchor points, offset to anchor points
+
+        // Class table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(2), // number of glyphs
+        UInt16(4), UInt16(5), // classes
+
+        // State array
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of text
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of line
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(2), // seen `1`
+
+        // Entry [0]
+        UInt16(0), // new state
+        UInt16(0), // flags
+        UInt16(0xFFFF), // action index
+        // Entry [1]
+        UInt16(2), // new state
+        UInt16(0x8000), // flags: mark
+        UInt16(0xFFFF), // action index
+        // Entry [2]
+        UInt16(2), // new state
+        UInt16(0), // flags
+        UInt16(0), // action index
+
+        // Anchor points
+        UInt16(0), // mark point
+        UInt16(0), // current point
+    ]);
+
+    let ankr_data = convert(&[
+        UInt16(0), // version
+        UInt16(0), // reserved
+        UInt32(12), // offset to lookup table
+        UInt32(22), // offset to glyphs data
+
+        // Lookup table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(2), // number of glyphs
+        UInt16(0), UInt16(8), // offsets
+
+        // Glyphs data
+        UInt32(1), // number of points
+        Int16(300), Int16(500), // point [0]
+        UInt32(1), // number of points
+        Int16(50), Int16(-20), // point [0]
+    ]);
+    let ankr = ankr::Table::parse(NonZeroU16::new(10).unwrap(), &ankr_data).unwrap();
+
+    assert_eq!(apply(&data, &[1, 2], Some(&ankr)), vec![(500, 0, 0), (500, -250, 520)]);
+    assert_eq!(apply(&data, &[1, 2], None), vec![(500, 0, 0), (500, 0, 0)]);
+}
+
+// Like `format4`, but glyph `3` has no anchor points.
+#[test]
+fn format4_missing_anchor() {
+    let data = table_data(4, &[
+        UInt32(6), // number of classes
+        UInt32(20), // offset to class table
+        UInt32(32), // offset to state array
+        UInt32(68), // offset to entry table
+        UInt32(0x40000000 | 86), // flags: anchor points, offset to anchor points
+
+        // Class table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(3), // number of glyphs
+        UInt16(4), UInt16(5), UInt16(5), // classes
+
+        // State array
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of text
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of line
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(2), // seen `1`
+
+        // Entry [0]
+        UInt16(0), // new state
+        UInt16(0), // flags
+        UInt16(0xFFFF), // action index
+        // Entry [1]
+        UInt16(2), // new state
+        UInt16(0x8000), // flags: mark
+        UInt16(0xFFFF), // action index
+        // Entry [2]
+        UInt16(2), // new state
+        UInt16(0), // flags
+        UInt16(0), // action index
+
+        // Anchor points
+        UInt16(0), // mark point
+        UInt16(0), // current point
+    ]);
+
+    let ankr_data = convert(&[
+        UInt16(0), // version
+        UInt16(0), // reserved
+        UInt32(12), // offset to lookup table
+        UInt32(22), // offset to glyphs data
+
+        // Lookup table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(2), // number of glyphs
+        UInt16(0), UInt16(8), // offsets
+
+        // Glyphs data
+        UInt32(1), // number of points
+        Int16(300), Int16(500), // point [0]
+        UInt32(1), // number of points
+        Int16(50), Int16(-20), // point [0]
+    ]);
+    let ankr = ankr::Table::parse(NonZeroU16::new(10).unwrap(), &ankr_data).unwrap();
+
+    // The action for `3` is skipped, but `2` is still attached.
+    assert_eq!(
+        apply(&data, &[1, 3, 2], Some(&ankr)),
+        vec![(500, 0, 0), (500, 0, 0), (500, -750, 520)]
+    );
+}
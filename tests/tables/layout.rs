@@ -0,0 +1,481 @@
+use ttf_parser::{GlyphId, RawFaceTables, Tag};
+use crate::{convert, Unit::*};
+
+fn gsub_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(62), // offset to feature list
+        UInt16(90), // offset to lookup list
+
+        // Script list
+        UInt16(2), // number of records
+        Raw(b"DFLT"), UInt16(14), // script record [0]
+        Raw(b"latn"), UInt16(26), // script record [1]
+
+        // Script [0]
+        UInt16(4), // offset to default language system
+        UInt16(0), // number of language systems
+
+        // Default language system
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Script [1]
+        UInt16(10), // offset to default language system
+        UInt16(1), // number of language systems
+        Raw(b"TRK "), UInt16(18), // language system record [0]
+
+        // Default language system
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Language system [0]
+        UInt16(0), // lookup order
+        UInt16(1), // required feature index
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Feature list
+        UInt16(2), // number of records
+        Raw(b"liga"), UInt16(14), // feature record [0]
+        Raw(b"locl"), UInt16(20), // feature record [1]
+
+        // Feature [0]
+        UInt16(0), // offset to feature params
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+
+        // Feature [1]
+        UInt16(0), // offset to feature params
+        UInt16(2), // number of lookups
+        UInt16(1), // lookup index [0]
+        UInt16(2), // lookup index [1]
+
+        // Lookup list
+        UInt16(0), // number of lookups
+    ])
+}
+
+#[test]
+fn enumerate() {
+    let tables = crate::RequiredTables::new(1);
+    let gsub = gsub_data();
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+    let table = face.tables().gsub.unwrap();
+
+    let tag = Tag::from_bytes;
+    let scripts: Vec<_> = table.scripts().collect();
+    assert_eq!(scripts, vec![tag(b"DFLT"), tag(b"latn")]);
+
+    let languages: Vec<_> = table.languages(tag(b"DFLT")).collect();
+    assert_eq!(languages, vec![tag(b"dflt")]);
+    let languages: Vec<_> = table.languages(tag(b"latn")).collect();
+    assert_eq!(languages, vec![tag(b"dflt"), tag(b"TRK ")]);
+    assert_eq!(table.languages(tag(b"cyrl")).count(), 0);
+
+    let features: Vec<_> = table.features(tag(b"latn"), tag(b"dflt")).map(|f| f.tag).collect();
+    assert_eq!(features, vec![tag(b"liga")]);
+
+    // The required feature goes first.
+    let features: Vec<_> = table.features(tag(b"latn"), tag(b"TRK ")).collect();
+    assert_eq!(features.len(), 2);
+    assert_eq!(features[0].tag, tag(b"locl"));
+    assert_eq!(features[0].lookup_indices.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(features[1].tag, tag(b"liga"));
+
+    assert!(table.has_default_script());
+
+    // Fallbacks to the default language system.
+    let features: Vec<_> = table.features(tag(b"latn"), tag(b"DEU ")).map(|f| f.tag).collect();
+    assert_eq!(features, vec![tag(b"liga")]);
+}
+
+fn default_script_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(32), // offset to feature list
+        UInt16(54), // offset to lookup list
+
+        // Script list
+        UInt16(1), // number of records
+        Raw(b"DFLT"), UInt16(8), // script record [0]
+
+        // Script [0]
+        UInt16(4), // offset to default language system
+        UInt16(0), // number of language systems
+
+        // Default language system
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(2), // number of features
+        UInt16(0), // feature index [0]
+        UInt16(1), // feature index [1]
+
+        // Feature list
+        UInt16(2), // number of records
+        Raw(b"ccmp"), UInt16(14), // feature record [0]
+        Raw(b"liga"), UInt16(18), // feature record [1]
+
+        // Feature [0]
+        UInt16(0), // offset to feature params
+        UInt16(0), // number of lookups
+
+        // Feature [1]
+        UInt16(0), // offset to feature params
+        UInt16(0), // number of lookups
+
+        // Lookup list
+        UInt16(0), // number of lookups
+    ])
+}
+
+#[test]
+fn default_script_fallback() {
+    let tables = crate::RequiredTables::new(1);
+    let gsub = default_script_data();
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+    let table = face.tables().gsub.unwrap();
+
+    let tag = Tag::from_bytes;
+    assert!(table.has_default_script());
+    let features: Vec<_> = table.features(tag(b"cyrl"), tag(b"SRB ")).map(|f| f.tag).collect();
+    assert_eq!(features, vec![tag(b"ccmp"), tag(b"liga")]);
+    let features: Vec<_> = table.features(tag(b"DFLT"), tag(b"dflt")).map(|f| f.tag).collect();
+    assert_eq!(features, vec![tag(b"ccmp"), tag(b"liga")]);
+}
+
+#[test]
+fn no_default_script() {
+    let tables = crate::RequiredTables::new(1);
+    let gsub = features_data(&[b"liga"]);
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+    let table = face.tables().gsub.unwrap();
+
+    assert!(!table.has_default_script());
+    assert_eq!(table.features(Tag::from_bytes(b"latn"), Tag::from_bytes(b"dflt")).count(), 0);
+}
+
+fn features_data(tags: &[&'static [u8; 4]]) -> Vec<u8> {
+    let count = tags.len() as u16;
+    let mut data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(12), // offset to feature list
+        UInt16(12 + 2 + count * 10), // offset to lookup list
+
+        // Script list
+        UInt16(0), // number of records
+
+        // Feature list
+        UInt16(count), // number of records
+    ]);
+
+    for (i, tag) in tags.iter().enumerate() {
+        data.extend_from_slice(&convert(&[Raw(*tag), UInt16(2 + count * 6 + i as u16 * 4)]));
+    }
+
+    for _ in tags {
+        data.extend_from_slice(&convert(&[
+            UInt16(0), // offset to feature params
+            UInt16(0), // number of lookups
+        ]));
+    }
+
+    data.extend_from_slice(&convert(&[
+        UInt16(0), // number of lookups
+    ]));
+
+    data
+}
+
+#[test]
+fn all_feature_tags() {
+    let tables = crate::RequiredTables::new(1);
+    let gsub = features_data(&[b"liga", b"liga", b"ss01"]);
+    let gpos = features_data(&[b"kern", b"liga", b"kern", b"mark"]);
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        gpos: Some(&gpos),
+        ..RawFaceTables::default()
+    });
+
+    let tags: Vec<_> = face.all_feature_tags().collect();
+    assert_eq!(tags, vec![
+        Tag::from_bytes(b"liga"),
+        Tag::from_bytes(b"ss01"),
+        Tag::from_bytes(b"kern"),
+        Tag::from_bytes(b"mark"),
+    ]);
+}
+
+#[test]
+fn all_feature_tags_without_layout_tables() {
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables::default());
+    assert_eq!(face.all_feature_tags().count(), 0);
+}
+
+fn layout_data(feature: &'static [u8; 4], lookup_type: u16, subtable: &[u8]) -> Vec<u8> {
+    let mut data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(30), // offset to feature list
+        UInt16(44), // offset to lookup list
+
+        // Script list
+        UInt16(1), // number of records
+        Raw(b"DFLT"), UInt16(8), // script record [0]
+        // Script [0]
+        UInt16(4), // offset to default language system
+        UInt16(0), // number of language systems
+        // Default language system
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Feature list
+        UInt16(1), // number of records
+        Raw(feature), UInt16(8), // feature record [0]
+        // Feature [0]
+        UInt16(0), // offset to feature params
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+
+        // Lookup list
+        UInt16(1), // number of lookups
+        UInt16(4), // offset to lookup [0]
+        // Lookup [0]
+        UInt16(lookup_type), // type
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+    ]);
+    data.extend_from_slice(subtable);
+    data
+}
+
+#[test]
+fn single_substitution() {
+    let gsub = layout_data(b"smcp", 1, &convert(&[
+        UInt16(2), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of glyphs
+        UInt16(7), // substitute [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(1), // glyph [0]
+    ]));
+
+    let tables = crate::RequiredTables::new(10);
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+
+    let table = face.tables().gsub.unwrap();
+    let feature = table.features.find(Tag::from_bytes(b"smcp")).unwrap();
+    assert_eq!(table.single_substitution(feature, GlyphId(1)), Some(GlyphId(7)));
+    assert_eq!(table.single_substitution(feature, GlyphId(2)), None);
+    assert!(table.alternate_substitutions(feature, GlyphId(1)).is_none());
+}
+
+#[test]
+fn alternate_substitutions() {
+    let gsub = layout_data(b"salt", 3, &convert(&[
+        UInt16(1), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of alternate sets
+        UInt16(14), // offset to alternate set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(1), // glyph [0]
+        // Alternate set [0]
+        UInt16(2), // number of glyphs
+        UInt16(8), // alternate [0]
+        UInt16(9), // alternate [1]
+    ]));
+
+    let tables = crate::RequiredTables::new(10);
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+
+    let table = face.tables().gsub.unwrap();
+    let feature = table.features.find(Tag::from_bytes(b"salt")).unwrap();
+    let alternates: Vec<_> = table.alternate_substitutions(feature, GlyphId(1)).unwrap().collect();
+    assert_eq!(alternates, vec![GlyphId(8), GlyphId(9)]);
+    assert!(table.alternate_substitutions(feature, GlyphId(2)).is_none());
+    assert_eq!(table.single_substitution(feature, GlyphId(1)), None);
+}
+
+#[test]
+fn ligature_substitution() {
+    let gsub = layout_data(b"liga", 4, &convert(&[
+        UInt16(1), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of ligature sets
+        UInt16(14), // offset to ligature set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(1), // glyph [0]
+        // Ligature set [0]
+        UInt16(2), // number of ligatures
+        UInt16(6), // offset to ligature [0]
+        UInt16(14), // offset to ligature [1]
+        // Ligature [0]
+        UInt16(4), // ligature glyph
+        UInt16(3), // number of components
+        UInt16(1), // component [1]
+        UInt16(2), // component [2]
+        // Ligature [1]
+        UInt16(3), // ligature glyph
+        UInt16(2), // number of components
+        UInt16(2), // component [1]
+    ]));
+
+    let tables = crate::RequiredTables::new(10);
+    let face = tables.face(RawFaceTables {
+        gsub: Some(&gsub),
+        ..RawFaceTables::default()
+    });
+
+    let table = face.tables().gsub.unwrap();
+    let feature = table.features.find(Tag::from_bytes(b"liga")).unwrap();
+    // f f i
+    assert_eq!(table.ligature_substitution(feature, GlyphId(1), &[GlyphId(1), GlyphId(2)]), Some((GlyphId(4), 3)));
+    // f i
+    assert_eq!(table.ligature_substitution(feature, GlyphId(1), &[GlyphId(2), GlyphId(5)]), Some((GlyphId(3), 2)));
+    // f f
+    assert_eq!(table.ligature_substitution(feature, GlyphId(1), &[GlyphId(1)]), None);
+    assert_eq!(table.ligature_substitution(feature, GlyphId(1), &[]), None);
+    assert_eq!(table.ligature_substitution(feature, GlyphId(2), &[GlyphId(2)]), None);
+}
+
+mod shape_simple {
+    use ttf_parser::{GlyphId, GlyphPosition, RawFaceTables, Tag};
+    use crate::{convert, Unit::*};
+    use super::layout_data;
+
+    // Glyphs: 1 - f, 2 - i, 3 - fi, 4 - A, 5 - V.
+    fn cmap_data() -> Vec<u8> {
+        convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of tables
+            UInt16(0), // platform ID
+            UInt16(4), // encoding ID
+            UInt32(12), // offset
+
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(64), // length
+            UInt32(0), // language
+            UInt32(4), // number of groups
+            UInt32(0x41), UInt32(0x41), UInt32(4), // group [0]: A
+            UInt32(0x56), UInt32(0x56), UInt32(5), // group [1]: V
+            UInt32(0x66), UInt32(0x66), UInt32(1), // group [2]: f
+            UInt32(0x69), UInt32(0x69), UInt32(2), // group [3]: i
+        ])
+    }
+
+    fn hmtx_data() -> Vec<u8> {
+        convert(&[
+            UInt16(500), Int16(0), // metric [0]
+            Int16(0), Int16(0), Int16(0), Int16(0), Int16(0), // side bearings
+        ])
+    }
+
+    fn gsub_data() -> Vec<u8> {
+        layout_data(b"liga", 4, &convert(&[
+            UInt16(1), // format
+            UInt16(8), // offset to coverage
+            UInt16(1), // number of ligature sets
+            UInt16(14), // offset to ligature set [0]
+            // Coverage
+            UInt16(1), // format
+            UInt16(1), // number of glyphs
+            UInt16(1), // glyph [0]
+            // Ligature set [0]
+            UInt16(1), // number of ligatures
+            UInt16(4), // offset to ligature [0]
+            // Ligature [0]
+            UInt16(3), // ligature glyph
+            UInt16(2), // number of components
+            UInt16(2), // component [1]
+        ]))
+    }
+
+    fn gpos_data() -> Vec<u8> {
+        layout_data(b"kern", 2, &convert(&[
+            UInt16(1), // format
+            UInt16(12), // offset to coverage
+            UInt16(0x0004), // value format 1: x advance
+            UInt16(0), // value format 2
+            UInt16(1), // number of pair sets
+            UInt16(18), // offset to pair set [0]
+            // Coverage
+            UInt16(1), // format
+            UInt16(1), // number of glyphs
+            UInt16(4), // glyph [0]
+            // Pair set [0]
+            UInt16(1), // number of pairs
+            UInt16(5), Int16(-80), // pair [0]
+        ]))
+    }
+
+    fn pos(glyph_id: u16, cluster: usize, x_advance: i32) -> GlyphPosition {
+        GlyphPosition { glyph_id: GlyphId(glyph_id), cluster, x_advance, x_offset: 0, y_offset: 0 }
+    }
+
+    #[test]
+    fn basic() {
+        let tables = crate::RequiredTables::new(6);
+        let cmap = cmap_data();
+        let hmtx = hmtx_data();
+        let gsub = gsub_data();
+        let gpos = gpos_data();
+        let face = tables.face(RawFaceTables {
+            cmap: Some(&cmap),
+            hmtx: Some(&hmtx),
+            gsub: Some(&gsub),
+            gpos: Some(&gpos),
+            ..RawFaceTables::default()
+        });
+
+        let mut glyphs = Vec::new();
+        let features = [Tag::from_bytes(b"liga"), Tag::from_bytes(b"kern")];
+        face.shape_simple("AVfi!", &features, &mut glyphs);
+        assert_eq!(glyphs, vec![pos(4, 0, 420), pos(5, 1, 500), pos(3, 2, 500), pos(0, 4, 500)]);
+
+        // Features that are not requested are not applied.
+        face.shape_simple("AVfi", &[], &mut glyphs);
+        assert_eq!(glyphs, vec![pos(4, 0, 500), pos(5, 1, 500), pos(1, 2, 500), pos(2, 3, 500)]);
+
+        // Partial ligature.
+        face.shape_simple("ff", &features, &mut glyphs);
+        assert_eq!(glyphs, vec![pos(1, 0, 500), pos(1, 1, 500)]);
+    }
+}
@@ -1,15 +1,39 @@
 #[rustfmt::skip] mod aat;
 #[rustfmt::skip] mod ankr;
+#[rustfmt::skip] mod avar;
 #[rustfmt::skip] mod cff1;
 #[rustfmt::skip] mod cmap;
+#[rustfmt::skip] mod cpal;
+#[rustfmt::skip] mod ebdt;
 #[rustfmt::skip] mod feat;
+#[rustfmt::skip] mod fvar;
+#[rustfmt::skip] mod gasp;
+#[rustfmt::skip] mod gdef;
 #[rustfmt::skip] mod glyf;
+#[rustfmt::skip] mod gvar;
+#[rustfmt::skip] mod hdmx;
+#[rustfmt::skip] mod head;
+#[rustfmt::skip] mod hhea;
 #[rustfmt::skip] mod hmtx;
+#[rustfmt::skip] mod kern;
+#[rustfmt::skip] mod kerx;
+#[rustfmt::skip] mod layout;
+#[rustfmt::skip] mod math;
 #[rustfmt::skip] mod maxp;
+#[rustfmt::skip] mod meta;
+#[rustfmt::skip] mod morx;
+#[rustfmt::skip] mod name;
+#[rustfmt::skip] mod os2;
+#[rustfmt::skip] mod outline;
+#[rustfmt::skip] mod post;
 #[rustfmt::skip] mod sbix;
+#[rustfmt::skip] mod stat;
+#[rustfmt::skip] mod svg;
 #[rustfmt::skip] mod trak;
+#[rustfmt::skip] mod vdmx;
+#[rustfmt::skip] mod vorg;
 
-use ttf_parser::{fonts_in_collection, Face, FaceParsingError};
+use ttf_parser::{fonts_in_collection, Face, FaceParsingError, RawFace, RawFaceTables, Tag};
 
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
@@ -62,6 +86,80 @@ fn convert_unit(unit: Unit, data: &mut Vec<u8>) {
     }
 }
 
+/// A minimal `head` table. Useful with `Face::from_raw_tables`.
+pub fn head_data() -> Vec<u8> {
+    use Unit::*;
+    convert(&[
+        Fixed(1.0),         // version
+        Fixed(1.0),         // font revision
+        UInt32(0),          // checksum adjustment
+        UInt32(0x5F0F3CF5), // magic number
+        UInt16(0),          // flags
+        UInt16(1000),       // units per EM
+        Raw(&[0; 8]),       // created time
+        Raw(&[0; 8]),       // modified time
+        Int16(0),           // x min
+        Int16(0),           // y min
+        Int16(100),         // x max
+        Int16(100),         // y max
+        UInt16(0),          // mac style
+        UInt16(8),          // lowest PPEM
+        Int16(2),           // font direction hint
+        Int16(0),           // index to location format
+        Int16(0),           // glyph data format
+    ])
+}
+
+/// A minimal `hhea` table. Useful with `Face::from_raw_tables`.
+pub fn hhea_data() -> Vec<u8> {
+    use Unit::*;
+    convert(&[
+        Fixed(1.0),    // version
+        Int16(800),    // ascender
+        Int16(-200),   // descender
+        Int16(0),      // line gap
+        Raw(&[0; 24]), // other data
+        UInt16(1),     // number of metrics
+    ])
+}
+
+/// A minimal `maxp` table. Useful with `Face::from_raw_tables`.
+pub fn maxp_data(number_of_glyphs: u16) -> Vec<u8> {
+    use Unit::*;
+    convert(&[
+        Fixed(0.3125),            // version
+        UInt16(number_of_glyphs), // number of glyphs
+    ])
+}
+
+/// Minimal `head`, `hhea` and `maxp` tables required by `Face::from_raw_tables`.
+pub struct RequiredTables {
+    head: Vec<u8>,
+    hhea: Vec<u8>,
+    maxp: Vec<u8>,
+}
+
+impl RequiredTables {
+    pub fn new(number_of_glyphs: u16) -> Self {
+        RequiredTables {
+            head: head_data(),
+            hhea: hhea_data(),
+            maxp: maxp_data(number_of_glyphs),
+        }
+    }
+
+    /// Creates a face from the required tables and the specified optional ones.
+    pub fn face<'a>(&'a self, tables: RawFaceTables<'a>) -> Face<'a> {
+        Face::from_raw_tables(RawFaceTables {
+            head: &self.head,
+            hhea: &self.hhea,
+            maxp: &self.maxp,
+            ..tables
+        })
+        .unwrap()
+    }
+}
+
 #[test]
 fn empty_font() {
     assert_eq!(
@@ -173,3 +271,95 @@ fn font_index_overflow_on_regular_font() {
         FaceParsingError::FaceIndexOutOfBounds
     );
 }
+
+#[test]
+fn validate_checksums() {
+    use Unit::*;
+    let mut data = convert(&[
+        Raw(&[0x00, 0x01, 0x00, 0x00]), // magic
+        UInt16(1),                      // numTables
+        UInt16(16),                     // searchRange
+        UInt16(0),                      // entrySelector
+        UInt16(0),                      // rangeShift
+        // Table record [0]
+        Raw(b"head"),       // tag
+        UInt32(0x5F774149), // checksum
+        UInt32(28),         // offset
+        UInt32(54),         // length
+    ]);
+    data.extend_from_slice(&head_data());
+    data.extend_from_slice(&[0, 0]); // padding
+
+    // Checksum adjustment.
+    data[36..40].copy_from_slice(&0x8A5ACB62_u32.to_be_bytes());
+    let face = RawFace::parse(&data, 0).unwrap();
+    assert!(face.validate_checksums());
+    let head = face.table(Tag::from_bytes(b"head")).unwrap();
+    let head = ttf_parser::head::Table::parse(head).unwrap();
+    assert_eq!(head.checksum_adjustment, 0x8A5ACB62);
+
+    // Wrong checksum adjustment.
+    data[36..40].copy_from_slice(&0x8A5ACB63_u32.to_be_bytes());
+    let face = RawFace::parse(&data, 0).unwrap();
+    assert!(!face.validate_checksums());
+
+    // Wrong table checksum.
+    data[36..40].copy_from_slice(&0x8A5ACB62_u32.to_be_bytes());
+    data[40] = 0;
+    let face = RawFace::parse(&data, 0).unwrap();
+    assert!(!face.validate_checksums());
+}
+
+#[test]
+fn table_record() {
+    use Unit::*;
+    let data = convert(&[
+        Raw(&[0x00, 0x01, 0x00, 0x00]), // magic
+        UInt16(2),                      // numTables
+        UInt16(32),                     // searchRange
+        UInt16(1),                      // entrySelector
+        UInt16(0),                      // rangeShift
+        // Table record [0]
+        Raw(b"cmap"),   // tag
+        UInt32(0x1234), // checksum
+        UInt32(44),     // offset
+        UInt32(4),      // length
+        // Table record [1]
+        Raw(b"head"),   // tag
+        UInt32(0x5678), // checksum
+        UInt32(48),     // offset
+        UInt32(100),    // length
+        // Data
+        UInt32(0),
+    ]);
+
+    let face = RawFace::parse(&data, 0).unwrap();
+    let record = face.table_record(Tag::from_bytes(b"cmap")).unwrap();
+    assert_eq!(record.check_sum, 0x1234);
+    assert_eq!(record.offset, 44);
+    assert_eq!(record.length, 4);
+    assert_eq!(
+        face.table(Tag::from_bytes(b"cmap")),
+        Some(&[0, 0, 0, 0][..])
+    );
+
+    // The record is returned even when the table data is out of bounds.
+    let record = face.table_record(Tag::from_bytes(b"head")).unwrap();
+    assert_eq!(record.check_sum, 0x5678);
+    assert_eq!(face.table(Tag::from_bytes(b"head")), None);
+
+    assert!(face.table_record(Tag::from_bytes(b"glyf")).is_none());
+
+    assert!(face.has_table(Tag::from_bytes(b"cmap")));
+    assert!(face.has_table(Tag::from_bytes(b"head")));
+    assert!(!face.has_table(Tag::from_bytes(b"glyf")));
+
+    let tables: Vec<_> = face.tables().collect();
+    assert_eq!(
+        tables,
+        vec![
+            (Tag::from_bytes(b"cmap"), 4),
+            (Tag::from_bytes(b"head"), 100)
+        ]
+    );
+}
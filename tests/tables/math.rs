@@ -0,0 +1,133 @@
+use ttf_parser::math::Table;
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+fn data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(0), // constants offset
+        UInt16(10), // glyph info offset
+        UInt16(46), // variants offset
+
+        // Glyph info
+        UInt16(8), // italics correction offset
+        UInt16(22), // top accent attachment offset
+        UInt16(0), // extended shape coverage offset
+        UInt16(0), // kern info offset
+
+        // Italics correction
+        UInt16(8), // coverage offset
+        UInt16(1), // count
+        Int16(50), // value [0]
+        UInt16(0), // device offset [0]
+        UInt16(1), // coverage format
+        UInt16(1), // glyph count
+        UInt16(5), // glyph [0]
+
+        // Top accent attachment
+        UInt16(8), // coverage offset
+        UInt16(1), // count
+        Int16(250), // value [0]
+        UInt16(0), // device offset [0]
+        UInt16(1), // coverage format
+        UInt16(1), // glyph count
+        UInt16(5), // glyph [0]
+
+        // Variants
+        UInt16(20), // min connector overlap
+        UInt16(14), // vertical coverage offset
+        UInt16(20), // horizontal coverage offset
+        UInt16(1), // vertical glyph count
+        UInt16(1), // horizontal glyph count
+        UInt16(26), // vertical glyph construction offset [0]
+        UInt16(64), // horizontal glyph construction offset [0]
+
+        // Vertical coverage
+        UInt16(1), // format
+        UInt16(1), // glyph count
+        UInt16(5), // glyph [0]
+
+        // Horizontal coverage
+        UInt16(1), // format
+        UInt16(1), // glyph count
+        UInt16(6), // glyph [0]
+
+        // Vertical glyph construction [0]
+        UInt16(12), // glyph assembly offset
+        UInt16(2), // variant count
+        UInt16(5), // variant glyph [0]
+        UInt16(1000), // advance measurement [0]
+        UInt16(8), // variant glyph [1]
+        UInt16(1500), // advance measurement [1]
+
+        // Glyph assembly
+        Int16(15), // italics correction value
+        UInt16(0), // italics correction device offset
+        UInt16(2), // part count
+        UInt16(9), // glyph ID [0]
+        UInt16(0), // start connector length [0]
+        UInt16(100), // end connector length [0]
+        UInt16(500), // full advance [0]
+        UInt16(0), // part flags [0]
+        UInt16(10), // glyph ID [1]
+        UInt16(100), // start connector length [1]
+        UInt16(100), // end connector length [1]
+        UInt16(400), // full advance [1]
+        UInt16(1), // part flags [1]: extender
+
+        // Horizontal glyph construction [0]
+        UInt16(0), // glyph assembly offset
+        UInt16(1), // variant count
+        UInt16(7), // variant glyph [0]
+        UInt16(300), // advance measurement [0]
+    ])
+}
+
+#[test]
+fn glyph_info() {
+    let data = data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.italics_correction(GlyphId(5)).unwrap().value, 50);
+    assert_eq!(table.top_accent_attachment(GlyphId(5)).unwrap().value, 250);
+    assert!(table.italics_correction(GlyphId(6)).is_none());
+    assert!(table.top_accent_attachment(GlyphId(6)).is_none());
+}
+
+#[test]
+fn variants() {
+    let data = data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.min_connector_overlap(), Some(20));
+
+    let variants = table.vertical_variants(GlyphId(5)).unwrap();
+    assert_eq!(variants.len(), 2);
+    assert_eq!(variants.get(1).unwrap().variant_glyph, GlyphId(8));
+    assert_eq!(variants.get(1).unwrap().advance_measurement, 1500);
+
+    let variants = table.horizontal_variants(GlyphId(6)).unwrap();
+    assert_eq!(variants.len(), 1);
+    assert_eq!(variants.get(0).unwrap().variant_glyph, GlyphId(7));
+
+    assert!(table.vertical_variants(GlyphId(6)).is_none());
+    assert!(table.horizontal_variants(GlyphId(5)).is_none());
+}
+
+#[test]
+fn assembly() {
+    let data = data();
+    let table = Table::parse(&data).unwrap();
+
+    let assembly = table.vertical_assembly(GlyphId(5)).unwrap();
+    assert_eq!(assembly.italics_correction.value, 15);
+    assert_eq!(assembly.parts.len(), 2);
+    let part = assembly.parts.get(1).unwrap();
+    assert_eq!(part.glyph_id, GlyphId(10));
+    assert_eq!(part.full_advance, 400);
+    assert!(part.part_flags.extender());
+
+    // Covered, but without an assembly.
+    assert!(table.horizontal_assembly(GlyphId(6)).is_none());
+    // Not covered.
+    assert!(table.vertical_assembly(GlyphId(1)).is_none());
+}
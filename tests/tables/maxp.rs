@@ -1,5 +1,6 @@
 use std::num::NonZeroU16;
 use ttf_parser::maxp::Table;
+use ttf_parser::{GlyphId, RawFaceTables};
 use crate::{convert, Unit::*};
 
 #[test]
@@ -9,6 +10,8 @@ fn version_05() {
         UInt16(1), // number of glyphs
     ])).unwrap();
     assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+    assert_eq!(table.max_points, None);
+    assert_eq!(table.max_component_depth, None);
 }
 
 #[test]
@@ -16,21 +19,35 @@ fn version_1_full() {
     let table = Table::parse(&convert(&[
         Fixed(1.0), // version
         UInt16(1), // number of glyphs
-        UInt16(0), // maximum points in a non-composite glyph
-        UInt16(0), // maximum contours in a non-composite glyph
-        UInt16(0), // maximum points in a composite glyph
-        UInt16(0), // maximum contours in a composite glyph
-        UInt16(0), // maximum zones
-        UInt16(0), // maximum twilight points
-        UInt16(0), // number of Storage Area locations
-        UInt16(0), // number of FDEFs
-        UInt16(0), // number of IDEFs
-        UInt16(0), // maximum stack depth
-        UInt16(0), // maximum byte count for glyph instructions
-        UInt16(0), // maximum number of components
-        UInt16(0), // maximum levels of recursion
+        UInt16(1), // maximum points in a non-composite glyph
+        UInt16(2), // maximum contours in a non-composite glyph
+        UInt16(3), // maximum points in a composite glyph
+        UInt16(4), // maximum contours in a composite glyph
+        UInt16(5), // maximum zones
+        UInt16(6), // maximum twilight points
+        UInt16(7), // number of Storage Area locations
+        UInt16(8), // number of FDEFs
+        UInt16(9), // number of IDEFs
+        UInt16(10), // maximum stack depth
+        UInt16(11), // maximum byte count for glyph instructions
+        UInt16(12), // maximum number of components
+        UInt16(13), // maximum levels of recursion
     ])).unwrap();
     assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+    assert_eq!(table.declared_number_of_glyphs(), 1);
+    assert_eq!(table.max_points, Some(1));
+    assert_eq!(table.max_contours, Some(2));
+    assert_eq!(table.max_composite_points, Some(3));
+    assert_eq!(table.max_composite_contours, Some(4));
+    assert_eq!(table.max_zones, Some(5));
+    assert_eq!(table.max_twilight_points, Some(6));
+    assert_eq!(table.max_storage, Some(7));
+    assert_eq!(table.max_function_defs, Some(8));
+    assert_eq!(table.max_instruction_defs, Some(9));
+    assert_eq!(table.max_stack_elements, Some(10));
+    assert_eq!(table.max_size_of_instructions, Some(11));
+    assert_eq!(table.max_component_elements, Some(12));
+    assert_eq!(table.max_component_depth, Some(13));
 }
 
 #[test]
@@ -41,6 +58,8 @@ fn version_1_trimmed() {
         UInt16(1), // number of glyphs
     ])).unwrap();
     assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+    assert_eq!(table.max_points, None);
+    assert_eq!(table.max_component_depth, None);
 }
 
 #[test]
@@ -54,12 +73,47 @@ fn unknown_version() {
 
 #[test]
 fn zero_glyphs() {
-    let table = Table::parse(&convert(&[
+    let data = convert(&[
         Fixed(0.3125), // version
         UInt16(0), // number of glyphs
-    ]));
-    assert!(table.is_none());
+    ]);
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+    assert_eq!(table.declared_number_of_glyphs(), 0);
+
+    // Faces without glyphs are still rejected.
+    let head = crate::head_data();
+    let hhea = crate::hhea_data();
+    let face = ttf_parser::Face::from_raw_tables(ttf_parser::RawFaceTables {
+        head: &head,
+        hhea: &hhea,
+        maxp: &data,
+        ..ttf_parser::RawFaceTables::default()
+    });
+    assert!(face.is_err());
 }
 
 // TODO: what to do when the number of glyphs is 0xFFFF?
 //       we're actually checking this in loca
+
+#[test]
+fn glyphs() {
+    let tables = crate::RequiredTables::new(3);
+    let face = tables.face(RawFaceTables::default());
+
+    let glyphs: Vec<_> = face.glyphs().collect();
+    assert_eq!(glyphs, vec![GlyphId(0), GlyphId(1), GlyphId(2)]);
+    assert!(face.is_valid_glyph(GlyphId(2)));
+    assert!(!face.is_valid_glyph(GlyphId(3)));
+}
+
+#[test]
+fn glyphs_max() {
+    let tables = crate::RequiredTables::new(65535);
+    let face = tables.face(RawFaceTables::default());
+
+    assert_eq!(face.glyphs().count(), 65535);
+    assert_eq!(face.glyphs().last(), Some(GlyphId(65534)));
+    assert!(face.is_valid_glyph(GlyphId(65534)));
+    assert!(!face.is_valid_glyph(GlyphId(65535)));
+}
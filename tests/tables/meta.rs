@@ -0,0 +1,68 @@
+use ttf_parser::{meta::Table, RawFaceTables, Tag};
+use crate::{convert, Unit::*};
+
+fn meta_data() -> Vec<u8> {
+    convert(&[
+        UInt32(1), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(3), // number of data maps
+        // Data map [0]
+        Raw(b"dlng"), // tag
+        UInt32(52), // offset
+        UInt32(4), // length
+        // Data map [1]
+        Raw(b"slng"), // tag
+        UInt32(56), // offset
+        UInt32(10), // length
+        // Data map [2]
+        Raw(b"appl"), // tag
+        UInt32(66), // offset
+        UInt32(100), // length, out of bounds
+        // Data
+        Raw(b"Latn"),
+        Raw(b"Latn, Cyrl"),
+    ])
+}
+
+#[test]
+fn basic() {
+    let data = meta_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.design_languages(), Some("Latn"));
+    assert_eq!(table.supported_languages(), Some("Latn, Cyrl"));
+    assert_eq!(table.get(Tag::from_bytes(b"dlng")), Some(&b"Latn"[..]));
+    assert_eq!(table.get(Tag::from_bytes(b"appl")), None);
+    assert_eq!(table.get(Tag::from_bytes(b"bild")), None);
+
+    let tags: Vec<_> = table.tags().collect();
+    assert_eq!(tags, vec![
+        Tag::from_bytes(b"dlng"),
+        Tag::from_bytes(b"slng"),
+        Tag::from_bytes(b"appl"),
+    ]);
+}
+
+#[test]
+fn face_languages() {
+    let tables = crate::RequiredTables::new(1);
+    let meta = meta_data();
+    let face = tables.face(RawFaceTables {
+        meta: Some(&meta),
+        ..RawFaceTables::default()
+    });
+    assert_eq!(face.design_languages(), Some("Latn"));
+    assert_eq!(face.supported_languages(), Some("Latn, Cyrl"));
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt32(0), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(0), // number of data maps
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
@@ -0,0 +1,172 @@
+use std::num::NonZeroU16;
+use ttf_parser::GlyphId;
+use ttf_parser::morx::Table;
+use crate::{convert, Unit, Unit::*};
+
+fn table_data(subtables: &[Vec<u8>]) -> Vec<u8> {
+    let len: usize = subtables.iter().map(|subtable| subtable.len()).sum();
+    let mut data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // reserved
+        UInt32(1), // number of chains
+
+        // Chain [0]
+        UInt32(1), // default flags
+        UInt32(16 + len as u32), // chain length
+        UInt32(0), // number of features
+        UInt32(subtables.len() as u32), // number of subtables
+    ]);
+
+    for subtable in subtables {
+        data.extend_from_slice(subtable);
+    }
+
+    data
+}
+
+fn subtable_data(kind: u8, feature_flags: u32, body: &[Unit]) -> Vec<u8> {
+    let body = convert(body);
+    let mut data = convert(&[
+        UInt32(12 + body.len() as u32), // length
+        UInt8(0), // coverage
+        UInt16(0), // reserved
+        UInt8(kind), // type
+        UInt32(feature_flags), // sub-feature flags
+    ]);
+    data.extend_from_slice(&body);
+    data
+}
+
+fn rearrangement_data() -> Vec<u8> {
+    subtable_data(0, 1, &[
+        UInt32(6), // number of classes
+        UInt32(16), // offset to class table
+        UInt32(28), // offset to state array
+        UInt32(64), // offset to entry table
+
+        // Class table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(3), // number of glyphs
+        UInt16(4), UInt16(1), UInt16(5), // classes
+
+        // State array
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of text
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of line
+        UInt16(0), UInt16(3), UInt16(0), UInt16(0), UInt16(1), UInt16(2), // first marked
+
+        // Entry [0]
+        UInt16(0), // new state
+        UInt16(0), // flags
+        // Entry [1]
+        UInt16(2), // new state
+        UInt16(0x8000), // flags: mark first
+        // Entry [2]
+        UInt16(0), // new state
+        UInt16(0x2001), // flags: mark last, Ax => xA
+        // Entry [3]
+        UInt16(2), // new state
+        UInt16(0), // flags
+    ])
+}
+
+// Replaces `1 2` with `3`.
+fn ligature_data() -> Vec<u8> {
+    subtable_data(2, 1, &[
+        UInt32(6), // number of classes
+        UInt32(28), // offset to class table
+        UInt32(38), // offset to state array
+        UInt32(74), // offset to entry table
+        UInt32(92), // offset to ligature actions
+        UInt32(100), // offset to components
+        UInt32(106), // offset to ligatures
+
+        // Class table
+        UInt16(8), // format
+        UInt16(1), // first glyph
+        UInt16(2), // number of glyphs
+        UInt16(4), UInt16(5), // classes
+
+        // State array
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of text
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(0), // start of line
+        UInt16(0), UInt16(0), UInt16(0), UInt16(0), UInt16(1), UInt16(2), // seen `1`
+
+        // Entry [0]
+        UInt16(0), // new state
+        UInt16(0), // flags
+        UInt16(0), // ligature action index
+        // Entry [1]
+        UInt16(2), // new state
+        UInt16(0x8000), // flags: set component
+        UInt16(0), // ligature action index
+        // Entry [2]
+        UInt16(0), // new state
+        UInt16(0xA000), // flags: set component, perform action
+        UInt16(0), // ligature action index
+
+        // Ligature actions
+        UInt32(0), // action [0]
+        UInt32(0x80000000), // action [1]: last
+
+        // Components
+        UInt16(0), UInt16(0), UInt16(1),
+
+        // Ligatures
+        UInt16(0), UInt16(3),
+    ])
+}
+
+fn noncontextual_data(feature_flags: u32, from: u16, to: u16) -> Vec<u8> {
+    subtable_data(4, feature_flags, &[
+        UInt16(8), // format
+        UInt16(from), // first glyph
+        UInt16(1), // number of glyphs
+        UInt16(to), // value
+    ])
+}
+
+fn apply(data: &[u8], glyphs: &[u16]) -> Vec<u16> {
+    let table = Table::parse(NonZeroU16::new(10).unwrap(), data).unwrap();
+    let mut glyphs: Vec<_> = glyphs.iter().map(|id| GlyphId(*id)).collect();
+    let len = table.apply(&mut glyphs);
+    glyphs[..len].iter().map(|id| id.0).collect()
+}
+
+#[test]
+fn rearrangement() {
+    let data = table_data(&[rearrangement_data()]);
+    assert_eq!(apply(&data, &[1, 2, 3]), vec![2, 3, 1]);
+    assert_eq!(apply(&data, &[2, 1, 3]), vec![2, 3, 1]);
+    assert_eq!(apply(&data, &[1, 2]), vec![1, 2]);
+}
+
+#[test]
+fn ligature() {
+    let data = table_data(&[ligature_data()]);
+    assert_eq!(apply(&data, &[1, 2, 5]), vec![3, 5]);
+    assert_eq!(apply(&data, &[1, 1, 2]), vec![1, 3]);
+    assert_eq!(apply(&data, &[2, 1]), vec![2, 1]);
+    assert_eq!(apply(&data, &[1]), vec![1]);
+    assert_eq!(apply(&data, &[]), vec![]);
+}
+
+#[test]
+fn chain_order_and_flags() {
+    let data = table_data(&[
+        ligature_data(),
+        noncontextual_data(1, 5, 6),
+        noncontextual_data(1, 3, 4),
+        noncontextual_data(2, 4, 7), // disabled
+    ]);
+    assert_eq!(apply(&data, &[1, 2, 5]), vec![4, 6]);
+}
+
+#[test]
+fn dont_advance_loop() {
+    let mut data = table_data(&[rearrangement_data()]);
+    let len = data.len();
+    // Entry [1]: stay in the same state without advancing.
+    data[len - 12..len - 8].copy_from_slice(&[0x00, 0x00, 0x40, 0x00]);
+    assert_eq!(apply(&data, &[1, 2, 3]), vec![1, 2, 3]);
+}
@@ -0,0 +1,117 @@
+use ttf_parser::name::{name_id, Language, Table};
+use ttf_parser::PlatformId;
+use crate::{convert, Unit::*};
+
+// Records must be sorted by platform, encoding, language and name IDs.
+fn data() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        UInt16(5), // number of records
+        UInt16(66), // offset to storage
+
+        // Record [0]
+        UInt16(1), // platform ID: Macintosh
+        UInt16(0), // encoding ID: Roman
+        UInt16(2), // language ID: German
+        UInt16(name_id::FAMILY),
+        UInt16(4), // length
+        UInt16(0), // offset
+
+        // Record [1]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x0407), // language ID: German
+        UInt16(name_id::SUBFAMILY),
+        UInt16(4), // length
+        UInt16(4), // offset
+
+        // Record [2]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x0409), // language ID: English (United States)
+        UInt16(name_id::FAMILY),
+        UInt16(4), // length
+        UInt16(8), // offset
+
+        // Record [3]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x040C), // language ID: French (France)
+        UInt16(name_id::FAMILY),
+        UInt16(4), // length
+        UInt16(12), // offset
+
+        // Record [4]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x080C), // language ID: French (Belgium)
+        UInt16(name_id::FAMILY),
+        UInt16(4), // length
+        UInt16(16), // offset
+
+        // Storage
+        Raw(b"Name"),
+        Raw(b"\0S\0t"),
+        Raw(b"\0E\0n"),
+        Raw(b"\0F\0r"),
+        Raw(b"\0B\0e"),
+    ])
+}
+
+#[test]
+fn localized_exact() {
+    let data = data();
+    let names = Table::parse(&data).unwrap().names;
+    let name = names.get_localized(name_id::FAMILY, Language::French).unwrap();
+    assert_eq!(name.platform_id, PlatformId::Windows);
+    assert_eq!(name.language_id, 0x040C);
+    assert_eq!(name.name, b"\0F\0r");
+}
+
+#[test]
+fn localized_macintosh_fallback() {
+    let data = data();
+    let names = Table::parse(&data).unwrap().names;
+    let name = names.get_localized(name_id::FAMILY, Language::German).unwrap();
+    assert_eq!(name.platform_id, PlatformId::Macintosh);
+    assert_eq!(name.name, b"Name");
+}
+
+#[test]
+fn localized_first_record_fallback() {
+    let data = data();
+    let names = Table::parse(&data).unwrap().names;
+    let name = names.get_localized(name_id::FAMILY, Language::Japanese).unwrap();
+    assert_eq!(name.platform_id, PlatformId::Macintosh);
+    assert_eq!(name.language_id, 2);
+}
+
+#[test]
+fn localized_missing_id() {
+    let data = data();
+    let names = Table::parse(&data).unwrap().names;
+    assert!(names.get_localized(name_id::DESIGNER, Language::English).is_none());
+}
+
+#[test]
+fn filter_by_id() {
+    let data = data();
+    let names = Table::parse(&data).unwrap().names;
+    let language_ids: Vec<_> = names.filter_by_id(name_id::FAMILY)
+        .map(|name| name.language_id)
+        .collect();
+    assert_eq!(language_ids, vec![2, 0x0409, 0x040C, 0x080C]);
+    assert_eq!(names.filter_by_id(name_id::SUBFAMILY).count(), 1);
+    assert_eq!(names.filter_by_id(name_id::DESIGNER).count(), 0);
+}
+
+#[test]
+fn language_from_bcp47() {
+    assert_eq!(Language::from_bcp47("en"), Some(Language::English));
+    assert_eq!(Language::from_bcp47("en-GB"), Some(Language::English));
+    assert_eq!(Language::from_bcp47("FR-ca"), Some(Language::French));
+    assert_eq!(Language::from_bcp47("zh-Hans-CN"), Some(Language::ChineseSimplified));
+    assert_eq!(Language::from_bcp47("zh-TW"), Some(Language::ChineseTraditional));
+    assert_eq!(Language::from_bcp47("xx"), None);
+    assert_eq!(Language::from_bcp47(""), None);
+}
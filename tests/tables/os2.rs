@@ -0,0 +1,219 @@
+use ttf_parser::os2::{Permissions, Table, UnicodeBlock};
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+fn data_v0() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        Int16(500), // average char width
+        UInt16(400), // weight class
+        UInt16(5), // width class
+        UInt16(0), // type
+        Int16(650), // subscript x size
+        Int16(600), // subscript y size
+        Int16(0), // subscript x offset
+        Int16(75), // subscript y offset
+        Int16(650), // superscript x size
+        Int16(600), // superscript y size
+        Int16(0), // superscript x offset
+        Int16(350), // superscript y offset
+        Int16(50), // strikeout size
+        Int16(250), // strikeout position
+        Int16(0), // family class
+        Raw(&[2, 11, 5, 3, 3, 4, 3, 2, 2, 4]), // panose
+        UInt32(0x0000_0201), // unicode range 1
+        UInt32(0), // unicode range 2
+        UInt32(0), // unicode range 3
+        UInt32(0x0400_0000), // unicode range 4
+        Raw(b"TEST"), // vendor id
+        UInt16(0x0040), // selection
+        UInt16(0x0020), // first char index
+        UInt16(0xFFFF), // last char index
+        Int16(800), // typographic ascender
+        Int16(-200), // typographic descender
+        Int16(100), // typographic line gap
+        UInt16(900), // Windows ascender
+        UInt16(300), // Windows descender
+    ])
+}
+
+fn data_v5() -> Vec<u8> {
+    let mut data = data_v0();
+    data[1] = 5; // version
+    data.extend_from_slice(&convert(&[
+        UInt32(1), // code page range 1
+        UInt32(0x8000_0000), // code page range 2
+        Int16(500), // x height
+        Int16(700), // capital height
+        UInt16(0), // default char
+        UInt16(0x0020), // break char
+        UInt16(2), // max context
+        UInt16(160), // lower optical point size
+        UInt16(960), // upper optical point size
+    ]));
+    data
+}
+
+#[test]
+fn vendor_id() {
+    let data = data_v0();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.vendor_id(), Tag::from_bytes(b"TEST"));
+}
+
+#[test]
+fn panose() {
+    let data = data_v0();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.panose(), [2, 11, 5, 3, 3, 4, 3, 2, 2, 4]);
+}
+
+#[test]
+fn selection_flags() {
+    let mut data = data_v5();
+    data[62] = 0x03; // WWS and OBLIQUE
+    data[63] = 0x41; // REGULAR and ITALIC
+    let table = Table::parse(&data).unwrap();
+    let flags = table.selection_flags();
+    assert_eq!(flags.0, 0x0341);
+    assert!(flags.italic());
+    assert!(flags.regular());
+    assert!(flags.wws());
+    assert!(flags.oblique());
+    assert!(!flags.bold());
+    assert!(!flags.use_typo_metrics());
+    assert!(table.is_wws());
+    assert!(table.is_oblique_bit());
+    assert!(!table.use_typographic_metrics());
+
+    let mut data = data_v0();
+    data[62] = 0x03; // WWS and OBLIQUE
+    data[63] = 0x80; // USE_TYPO_METRICS
+    let table = Table::parse(&data).unwrap();
+    // Not defined before version 4.
+    assert!(!table.is_wws());
+    assert!(!table.is_oblique_bit());
+    assert!(!table.use_typographic_metrics());
+}
+
+#[test]
+fn x_and_capital_height() {
+    let data = data_v0();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.x_height(), None);
+    assert_eq!(table.capital_height(), None);
+    assert_eq!(table.x_height_raw(), None);
+    assert_eq!(table.capital_height_raw(), None);
+
+    let data = data_v5();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.x_height(), Some(500));
+    assert_eq!(table.capital_height(), Some(700));
+    assert_eq!(table.x_height_raw(), Some(500));
+    assert_eq!(table.capital_height_raw(), Some(700));
+}
+
+#[test]
+fn unicode_ranges() {
+    let data = data_v0();
+    let table = Table::parse(&data).unwrap();
+    let ranges = table.unicode_ranges();
+    assert!(ranges.contains(UnicodeBlock::BasicLatin));
+    assert!(ranges.contains(UnicodeBlock::Cyrillic));
+    assert!(ranges.contains(UnicodeBlock::DominoTiles));
+    assert!(!ranges.contains(UnicodeBlock::GreekAndCoptic));
+    assert_eq!(table.code_page_ranges(), None);
+}
+
+#[test]
+fn code_page_ranges() {
+    let data = data_v5();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.code_page_ranges(), Some(0x8000_0000_0000_0001));
+}
+
+#[test]
+fn truncated_v0() {
+    let data = data_v0();
+    assert!(Table::parse(&data[..data.len() - 2]).is_none());
+}
+
+#[test]
+fn optical_size_range() {
+    let data = data_v5();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.optical_size_range(), Some((8.0, 48.0)));
+}
+
+#[test]
+fn optical_size_range_v0() {
+    let data = data_v0();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.optical_size_range(), None);
+}
+
+#[test]
+fn truncated_v5() {
+    let data = data_v5();
+    assert!(Table::parse(&data[..data.len() - 2]).is_none());
+}
+
+#[test]
+fn permissions() {
+    let mut data = data_v5();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), Some(Permissions::Installable));
+    assert!(table.is_subsetting_allowed());
+    assert!(!table.is_bitmap_embedding_only());
+
+    data[8..10].copy_from_slice(&0x0304u16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), Some(Permissions::PreviewAndPrint));
+    assert!(!table.is_subsetting_allowed());
+    assert!(table.is_bitmap_embedding_only());
+
+    // Permission bits must be mutually exclusive since version 3.
+    data[8..10].copy_from_slice(&0x000Cu16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), None);
+}
+
+#[test]
+fn permissions_v0() {
+    let mut data = data_v0();
+    data[8..10].copy_from_slice(&0x030Eu16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    // The least restrictive permission is used.
+    assert_eq!(table.permissions(), Some(Permissions::Editable));
+    assert!(table.is_subsetting_allowed());
+    assert!(!table.is_bitmap_embedding_only());
+}
+
+#[test]
+fn permissions_reserved_bit() {
+    let mut data = data_v5();
+    data[8..10].copy_from_slice(&0x0001u16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), Some(Permissions::Installable));
+
+    data[8..10].copy_from_slice(&0x0009u16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), Some(Permissions::Editable));
+
+    let mut data = data_v0();
+    data[8..10].copy_from_slice(&0x0001u16.to_be_bytes()); // type
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.permissions(), Some(Permissions::Installable));
+}
+
+#[test]
+fn display() {
+    use ttf_parser::{Style, Weight, Width};
+
+    assert_eq!(Weight::Bold.to_string(), "700 (Bold)");
+    assert_eq!(Weight::from(600).to_string(), "600 (Semi Bold)");
+    assert_eq!(Weight::Other(350).to_string(), "350");
+    assert_eq!(Width::Condensed.to_string(), "75% (Condensed)");
+    assert_eq!(Width::SemiExpanded.to_string(), "112.5% (Semi Expanded)");
+    assert_eq!(Style::Oblique.to_string(), "Oblique");
+}
@@ -0,0 +1,171 @@
+use std::fmt::Write;
+use ttf_parser::OutlineBuilder;
+use ttf_parser::outline::{Flattener, FlipX, FlipY, NormalizeWinding, Transform, TransformedOutline, Translate};
+
+struct Builder(String);
+
+impl OutlineBuilder for Builder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        write!(&mut self.0, "M {} {} ", x, y).unwrap();
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        write!(&mut self.0, "L {} {} ", x, y).unwrap();
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+    }
+
+    fn close(&mut self) {
+        write!(&mut self.0, "Z ").unwrap();
+    }
+}
+
+fn square(builder: &mut dyn OutlineBuilder, min: f32, max: f32, ccw: bool) {
+    builder.move_to(min, min);
+    if ccw {
+        builder.line_to(max, min);
+        builder.line_to(max, max);
+        builder.line_to(min, max);
+    } else {
+        builder.line_to(min, max);
+        builder.line_to(max, max);
+        builder.line_to(max, min);
+    }
+    builder.close();
+}
+
+#[test]
+fn normalize_winding_keeps_correct_contours() {
+    let mut builder = Builder(String::new());
+    let mut normalizer = NormalizeWinding::new(&mut builder);
+    square(&mut normalizer, 0.0, 10.0, true);
+    square(&mut normalizer, 2.0, 8.0, false);
+    normalizer.finish();
+
+    assert_eq!(builder.0, "M 0 0 L 10 0 L 10 10 L 0 10 Z M 2 2 L 2 8 L 8 8 L 8 2 Z ");
+}
+
+#[test]
+fn normalize_winding_reverses_wrong_contours() {
+    let mut builder = Builder(String::new());
+    let mut normalizer = NormalizeWinding::new(&mut builder);
+    square(&mut normalizer, 0.0, 10.0, false);
+    square(&mut normalizer, 2.0, 8.0, true);
+    // An island inside of a hole.
+    square(&mut normalizer, 4.0, 6.0, false);
+    normalizer.finish();
+
+    assert_eq!(
+        builder.0,
+        "M 0 0 L 10 0 L 10 10 L 0 10 L 0 0 Z \
+         M 2 2 L 2 8 L 8 8 L 8 2 L 2 2 Z \
+         M 4 4 L 6 4 L 6 6 L 4 6 L 4 4 Z "
+    );
+}
+
+#[test]
+fn normalize_winding_reverses_curves() {
+    let mut builder = Builder(String::new());
+    let mut normalizer = NormalizeWinding::new(&mut builder);
+    normalizer.move_to(0.0, 0.0);
+    normalizer.quad_to(0.0, 10.0, 10.0, 10.0);
+    normalizer.curve_to(10.0, 5.0, 5.0, 0.0, 0.0, 0.0);
+    normalizer.close();
+    normalizer.finish();
+
+    assert_eq!(builder.0, "M 0 0 C 5 0 10 5 10 10 Q 0 10 0 0 Z ");
+}
+
+#[test]
+fn transform_then() {
+    let ts = Transform::scale(2.0, -2.0).then(&Transform::translate(10.0, 20.0));
+    assert_eq!(ts, Transform::new(2.0, 0.0, 0.0, -2.0, 10.0, 20.0));
+    assert_eq!(ts.apply(1.0, 1.0), (12.0, 18.0));
+
+    let ts = Transform::translate(10.0, 20.0).then(&Transform::scale(2.0, -2.0));
+    assert_eq!(ts.apply(1.0, 1.0), (22.0, -42.0));
+
+    assert_eq!(Transform::default().apply(3.0, 4.0), (3.0, 4.0));
+}
+
+#[test]
+fn transformed_outline() {
+    let mut builder = Builder(String::new());
+    let ts = Transform::scale(2.0, 3.0).then(&Transform::translate(1.0, -1.0));
+    let mut transformed = TransformedOutline::new(&mut builder, ts);
+    transformed.move_to(0.0, 0.0);
+    transformed.line_to(1.0, 1.0);
+    transformed.quad_to(2.0, 2.0, 3.0, 3.0);
+    transformed.curve_to(4.0, 4.0, 5.0, 5.0, 6.0, 6.0);
+    transformed.close();
+
+    assert_eq!(builder.0, "M 1 -1 L 3 2 Q 5 5 7 8 C 9 11 11 14 13 17 Z ");
+}
+
+#[test]
+fn flattener_flat_curves() {
+    let mut builder = Builder(String::new());
+    let mut flattener = Flattener::new(&mut builder, 1.0);
+    flattener.move_to(0.0, 0.0);
+    flattener.quad_to(5.0, 1.0, 10.0, 0.0);
+    flattener.curve_to(12.0, 0.0, 14.0, 0.0, 16.0, 0.0);
+    flattener.close();
+
+    assert_eq!(builder.0, "M 0 0 L 10 0 L 16 0 Z ");
+}
+
+#[test]
+fn flattener_adaptive() {
+    struct Points(Vec<(f32, f32)>);
+
+    impl OutlineBuilder for Points {
+        fn move_to(&mut self, x: f32, y: f32) { self.0.push((x, y)); }
+        fn line_to(&mut self, x: f32, y: f32) { self.0.push((x, y)); }
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) { panic!(); }
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) { panic!(); }
+        fn close(&mut self) {}
+    }
+
+    fn flatten(scale: f32) -> Vec<(f32, f32)> {
+        let mut points = Points(Vec::new());
+        let mut flattener = Flattener::new(&mut points, 0.5);
+        flattener.move_to(0.0, 0.0);
+        flattener.curve_to(0.0, scale, scale, scale, scale, 0.0);
+        points.0
+    }
+
+    let small = flatten(10.0);
+    let large = flatten(1000.0);
+    assert!(small.len() > 2);
+    assert!(large.len() > small.len());
+    assert_eq!(*large.last().unwrap(), (1000.0, 0.0));
+}
+
+#[test]
+fn flip_and_translate() {
+    fn draw(builder: &mut dyn OutlineBuilder) {
+        builder.move_to(1.0, 2.0);
+        builder.line_to(3.0, 4.0);
+        builder.quad_to(5.0, 6.0, 7.0, 8.0);
+        builder.curve_to(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        builder.close();
+    }
+
+    let mut builder = Builder(String::new());
+    draw(&mut FlipX::new(&mut builder, 10.0));
+    assert_eq!(builder.0, "M 9 2 L 7 4 Q 5 6 3 8 C 9 2 7 4 5 6 Z ");
+
+    let mut builder = Builder(String::new());
+    draw(&mut FlipY::new(&mut builder, 0.0));
+    assert_eq!(builder.0, "M 1 -2 L 3 -4 Q 5 -6 7 -8 C 1 -2 3 -4 5 -6 Z ");
+
+    let mut builder = Builder(String::new());
+    draw(&mut Translate::new(&mut builder, 1.0, -1.0));
+    assert_eq!(builder.0, "M 2 1 L 4 3 Q 6 5 8 7 C 2 1 4 3 6 5 Z ");
+}
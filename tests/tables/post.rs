@@ -0,0 +1,79 @@
+use ttf_parser::post::Table;
+use crate::{convert, Unit, Unit::*};
+
+fn data(indexes: &[u16], names: &[u8]) -> Vec<u8> {
+    let mut units = vec![
+        Fixed(2.0), // version
+        Fixed(0.0), // italic angle
+        Int16(-100), // underline position
+        Int16(50), // underline thickness
+        UInt32(0), // is fixed pitch
+        UInt32(0), // min memory for Type 42
+        UInt32(0), // max memory for Type 42
+        UInt32(0), // min memory for Type 1
+        UInt32(0), // max memory for Type 1
+        UInt16(indexes.len() as u16), // number of glyphs
+    ];
+    units.extend(indexes.iter().map(|i| Unit::UInt16(*i)));
+
+    let mut data = convert(&units);
+    data.extend_from_slice(names);
+    data
+}
+
+#[test]
+fn glyph_names() {
+    let data = data(
+        &[0, 259, 36, 258, 259],
+        b"\x05alpha\x04beta",
+    );
+    let table = Table::parse(&data).unwrap();
+    let names: Vec<_> = table.glyph_names().collect();
+    assert_eq!(names, [".notdef", "beta", "A", "alpha", "beta"]);
+    assert_eq!(table.names().collect::<Vec<_>>(), ["alpha", "beta"]);
+}
+
+#[test]
+fn glyph_names_malformed() {
+    // The second name length is past the end of the table.
+    let data = data(
+        &[3, 258, 259, 3],
+        b"\x05alpha\x10beta",
+    );
+    let table = Table::parse(&data).unwrap();
+    let names: Vec<_> = table.glyph_names().collect();
+    assert_eq!(names, ["space", "alpha"]);
+}
+
+#[test]
+fn glyph_names_version_3() {
+    let mut data = data(&[], b"");
+    data[1] = 3; // version
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.glyph_names().count(), 0);
+}
+
+#[test]
+fn header_fields() {
+    let data = convert(&[
+        UInt32(0x00025000), // version 2.5
+        Fixed(-12.0), // italic angle
+        Int16(-100), // underline position
+        Int16(50), // underline thickness
+        UInt32(3), // is fixed pitch
+        UInt32(1), // min memory for Type 42
+        UInt32(2), // max memory for Type 42
+        UInt32(3), // min memory for Type 1
+        UInt32(4), // max memory for Type 1
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.version, 0x00025000);
+    assert_eq!(table.italic_angle, -12.0);
+    assert!(table.is_monospaced);
+    assert_eq!(table.is_fixed_pitch, 3);
+    assert_eq!(table.min_mem_type42, 1);
+    assert_eq!(table.max_mem_type42, 2);
+    assert_eq!(table.min_mem_type1, 3);
+    assert_eq!(table.max_mem_type1, 4);
+}
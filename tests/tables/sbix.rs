@@ -1,5 +1,5 @@
 use std::num::NonZeroU16;
-use ttf_parser::{GlyphId, RasterImageFormat};
+use ttf_parser::{GlyphId, RasterImageFormat, Tag};
 use ttf_parser::sbix::Table;
 use crate::{convert, Unit::*};
 
@@ -133,3 +133,124 @@ fn recursive() {
     assert!(strike.get(GlyphId(0)).is_none());
     assert!(strike.get(GlyphId(1)).is_none());
 }
+
+#[test]
+fn jpeg_glyph() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(0), // flags
+        UInt32(1), // number of strikes
+        UInt32(12), // strike offset [0]
+
+        // Strike [0]
+        UInt16(20), // pixels_per_em
+        UInt16(72), // ppi
+        UInt32(12), // glyph data offset [0]
+        UInt32(39), // glyph data offset [1]
+
+        // Glyph Data [0]
+        UInt16(1), // x
+        UInt16(2), // y
+        Raw(b"jpg "), // type tag
+        // JPEG data, just the part we need
+        UInt16(0xFFD8), // SOI
+        UInt16(0xFFE0), // APP0
+        UInt16(6), // length
+        Raw(b"JFIF"),
+        UInt16(0xFFC0), // SOF0
+        UInt16(11), // length
+        UInt8(8), // precision
+        UInt16(30), // height
+        UInt16(20), // width
+    ]);
+
+    let table = Table::parse(NonZeroU16::new(1).unwrap(), &data).unwrap();
+    let strike = table.strikes.get(0).unwrap();
+    let glyph_data = strike.get(GlyphId(0)).unwrap();
+    assert_eq!(glyph_data.width, 20);
+    assert_eq!(glyph_data.height, 30);
+    assert_eq!(glyph_data.format, RasterImageFormat::JPEG);
+    assert_eq!(glyph_data.data.len(), 19);
+}
+
+#[test]
+fn tiff_glyph() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(0), // flags
+        UInt32(1), // number of strikes
+        UInt32(12), // strike offset [0]
+
+        // Strike [0]
+        UInt16(20), // pixels_per_em
+        UInt16(72), // ppi
+        UInt32(12), // glyph data offset [0]
+        UInt32(54), // glyph data offset [1]
+
+        // Glyph Data [0]
+        UInt16(1), // x
+        UInt16(2), // y
+        Raw(b"tiff"), // type tag
+        // TIFF data, just the part we need
+        Raw(b"II*\0"), // little-endian header
+        Raw(&[8, 0, 0, 0]), // IFD offset
+        Raw(&[2, 0]), // number of entries
+        // ImageWidth, SHORT, 1, 20
+        Raw(&[0, 1, 3, 0, 1, 0, 0, 0, 20, 0, 0, 0]),
+        // ImageLength, LONG, 1, 30
+        Raw(&[1, 1, 4, 0, 1, 0, 0, 0, 30, 0, 0, 0]),
+    ]);
+
+    let table = Table::parse(NonZeroU16::new(1).unwrap(), &data).unwrap();
+    let strike = table.strikes.get(0).unwrap();
+    let glyph_data = strike.get(GlyphId(0)).unwrap();
+    assert_eq!(glyph_data.width, 20);
+    assert_eq!(glyph_data.height, 30);
+    assert_eq!(glyph_data.format, RasterImageFormat::TIFF);
+    assert_eq!(glyph_data.data.len(), 34);
+}
+
+#[test]
+fn raw_glyph_data() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(0), // flags
+        UInt32(1), // number of strikes
+        UInt32(12), // strike offset [0]
+
+        // Strike [0]
+        UInt16(20), // pixels_per_em
+        UInt16(72), // ppi
+        UInt32(16), // glyph data offset [0]
+        UInt32(28), // glyph data offset [1]
+        UInt32(38), // glyph data offset [2]
+
+        // Glyph Data [0]
+        UInt16(1), // x
+        UInt16(2), // y
+        Raw(b"pdf "), // type tag
+        Raw(b"%PDF"), // data
+
+        // Glyph Data [1]
+        UInt16(3), // x
+        UInt16(4), // y
+        Raw(b"dupe"), // type tag
+        UInt16(0), // glyph id
+    ]);
+
+    let table = Table::parse(NonZeroU16::new(2).unwrap(), &data).unwrap();
+
+    // Unsupported by `get`, but still accessible.
+    let strike = table.strikes.get(0).unwrap();
+    assert!(strike.get(GlyphId(0)).is_none());
+
+    let glyph_data = strike.glyph_data(GlyphId(0)).unwrap();
+    assert_eq!(glyph_data.x, 1);
+    assert_eq!(glyph_data.y, 2);
+    assert_eq!(glyph_data.graphic_type, Tag::from_bytes(b"pdf "));
+    assert_eq!(glyph_data.data, b"%PDF");
+
+    let glyph_data = table.glyph_data(GlyphId(1), 20).unwrap();
+    assert_eq!(glyph_data.graphic_type, Tag::from_bytes(b"pdf "));
+    assert_eq!(glyph_data.data, b"%PDF");
+}
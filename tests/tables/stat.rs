@@ -0,0 +1,216 @@
+use ttf_parser::stat::{AxisValue, AxisValueFlags, Table};
+use ttf_parser::{RawFaceTables, Tag};
+use crate::{convert, Unit::*};
+
+fn basic_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(1), // minor version
+        UInt16(8), // design axis size
+        UInt16(2), // design axis count
+        UInt32(20), // design axes offset
+        UInt16(4), // axis value count
+        UInt32(36), // axis value offsets offset
+        UInt16(2), // elided fallback name ID
+
+        // Design axis [0]
+        Raw(b"wght"), // tag
+        UInt16(256), // name ID
+        UInt16(0), // ordering
+
+        // Design axis [1]
+        Raw(b"ital"), // tag
+        UInt16(257), // name ID
+        UInt16(1), // ordering
+
+        // Axis value offsets
+        UInt16(8), // offset [0]
+        UInt16(20), // offset [1]
+        UInt16(40), // offset [2]
+        UInt16(56), // offset [3]
+
+        // Axis value [0]
+        UInt16(1), // format
+        UInt16(0), // axis index
+        UInt16(AxisValueFlags::ELIDABLE_AXIS_VALUE_NAME), // flags
+        UInt16(258), // value name ID
+        Fixed(400.0), // value
+
+        // Axis value [1]
+        UInt16(2), // format
+        UInt16(0), // axis index
+        UInt16(0), // flags
+        UInt16(259), // value name ID
+        Fixed(700.0), // nominal value
+        Fixed(650.0), // range min value
+        Fixed(750.0), // range max value
+
+        // Axis value [2]
+        UInt16(3), // format
+        UInt16(0), // axis index
+        UInt16(AxisValueFlags::OLDER_SIBLING_FONT_ATTRIBUTE), // flags
+        UInt16(260), // value name ID
+        Fixed(400.0), // value
+        Fixed(700.0), // linked value
+
+        // Axis value [3]
+        UInt16(4), // format
+        UInt16(2), // axis count
+        UInt16(0), // flags
+        UInt16(261), // value name ID
+        UInt16(0), // axis index [0]
+        Fixed(700.0), // value [0]
+        UInt16(1), // axis index [1]
+        Fixed(1.0), // value [1]
+    ])
+}
+
+#[test]
+fn basic() {
+    let data = basic_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.elided_fallback_name_id, Some(2));
+
+    assert_eq!(table.axes.len(), 2);
+    let axis = table.axes.get(1).unwrap();
+    assert_eq!(axis.tag, Tag::from_bytes(b"ital"));
+    assert_eq!(axis.name_id, 257);
+    assert_eq!(axis.ordering, 1);
+
+    assert_eq!(table.axis_values.len(), 4);
+
+    let value = table.axis_values.get(0).unwrap();
+    assert!(value.flags().elidable_axis_value_name());
+    assert!(!value.flags().older_sibling_font_attribute());
+    match value {
+        AxisValue::Format1(v) => assert_eq!(v.value, 400.0),
+        _ => panic!("invalid format"),
+    }
+
+    match table.axis_values.get(1).unwrap() {
+        AxisValue::Format2(v) => {
+            assert_eq!(v.value_name_id, 259);
+            assert_eq!(v.nominal_value, 700.0);
+            assert_eq!(v.range_min_value, 650.0);
+            assert_eq!(v.range_max_value, 750.0);
+        }
+        _ => panic!("invalid format"),
+    }
+
+    let value = table.axis_values.get(2).unwrap();
+    assert!(value.flags().older_sibling_font_attribute());
+    match value {
+        AxisValue::Format3(v) => {
+            assert_eq!(v.value, 400.0);
+            assert_eq!(v.linked_value, 700.0);
+        }
+        _ => panic!("invalid format"),
+    }
+
+    match table.axis_values.get(3).unwrap() {
+        AxisValue::Format4(v) => {
+            assert_eq!(v.value_name_id, 261);
+            assert_eq!(v.values.len(), 2);
+            assert_eq!(v.values.get(1).unwrap().axis_index, 1);
+            assert_eq!(v.values.get(1).unwrap().value, 1.0);
+        }
+        _ => panic!("invalid format"),
+    }
+}
+
+#[test]
+fn axis_value() {
+    let data = basic_data();
+    let table = Table::parse(&data).unwrap();
+    let wght = Tag::from_bytes(b"wght");
+    let name_id = |value| table.axis_value(wght, value).map(|v| v.value_name_id());
+
+    // Format 1 goes before format 3.
+    assert_eq!(name_id(400.0), Some(258));
+    // Format 2 range. Format 4 is ignored.
+    assert_eq!(name_id(700.0), Some(259));
+    assert_eq!(name_id(650.0), Some(259));
+    assert_eq!(name_id(750.0), Some(259));
+    assert_eq!(name_id(800.0), None);
+    assert!(table.axis_value(Tag::from_bytes(b"ital"), 1.0).is_none());
+    assert!(table.axis_value(Tag::from_bytes(b"wdth"), 100.0).is_none());
+}
+
+#[test]
+fn axis_value_name() {
+    let name = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of records
+        UInt16(18), // offset to storage
+
+        // Record [0]
+        UInt16(3), // platform ID: Windows
+        UInt16(1), // encoding ID: Unicode BMP
+        UInt16(0x0409), // language ID: English (United States)
+        UInt16(259), // name ID
+        UInt16(8), // length
+        UInt16(0), // offset
+
+        // Storage
+        Raw(b"\0B\0o\0l\0d"),
+    ]);
+
+    let tables = crate::RequiredTables::new(1);
+    let stat = basic_data();
+    let face = tables.face(RawFaceTables {
+        name: Some(&name),
+        stat: Some(&stat),
+        ..RawFaceTables::default()
+    });
+
+    let wght = Tag::from_bytes(b"wght");
+    assert_eq!(face.axis_value_name(wght, 700.0).as_deref(), Some("Bold"));
+    // Name ID 258 is not present in the `name` table.
+    assert_eq!(face.axis_value_name(wght, 400.0), None);
+}
+
+#[test]
+fn larger_design_axis_records() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // design axis size
+        UInt16(2), // design axis count
+        UInt32(18), // design axes offset
+        UInt16(0), // axis value count
+        UInt32(0), // axis value offsets offset
+
+        // Design axis [0]
+        Raw(b"wght"), // tag
+        UInt16(256), // name ID
+        UInt16(0), // ordering
+        UInt16(0), // unknown data
+
+        // Design axis [1]
+        Raw(b"wdth"), // tag
+        UInt16(257), // name ID
+        UInt16(1), // ordering
+        UInt16(0), // unknown data
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.elided_fallback_name_id, None);
+    assert!(table.axis_values.is_empty());
+    let tags: Vec<Tag> = table.axes.into_iter().map(|a| a.tag).collect();
+    assert_eq!(tags, vec![Tag::from_bytes(b"wght"), Tag::from_bytes(b"wdth")]);
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt16(2), // major version
+        UInt16(0), // minor version
+        UInt16(8), // design axis size
+        UInt16(0), // design axis count
+        UInt32(0), // design axes offset
+        UInt16(0), // axis value count
+        UInt32(0), // axis value offsets offset
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
@@ -0,0 +1,239 @@
+use ttf_parser::{GlyphId, RawFaceTables};
+use crate::{convert, Unit::*};
+
+fn svg_table(document: &[u8]) -> Vec<u8> {
+    let mut data = convert(&[
+        UInt16(0), // version
+        UInt32(10), // offset to document list
+        UInt32(0), // reserved
+
+        // SVG Document List
+        UInt16(1), // number of records
+        UInt16(1), // start glyph ID
+        UInt16(1), // end glyph ID
+        UInt32(14), // document offset
+        UInt32(document.len() as u32), // document length
+    ]);
+
+    data.extend_from_slice(document);
+    data
+}
+
+#[test]
+fn plain_document() {
+    let svg = svg_table(b"<svg/>");
+    let tables = crate::RequiredTables::new(2);
+    let face = tables.face(RawFaceTables {
+        svg: Some(&svg),
+        ..RawFaceTables::default()
+    });
+
+    assert_eq!(face.glyph_svg_image(GlyphId(1)), Some(&b"<svg/>"[..]));
+    assert_eq!(face.glyph_svg_image(GlyphId(0)), None);
+}
+
+#[test]
+fn color_glyph_format() {
+    use ttf_parser::ColorGlyphFormat;
+
+    let svg = svg_table(b"<svg/>");
+    let tables = crate::RequiredTables::new(2);
+    let face = tables.face(RawFaceTables {
+        svg: Some(&svg),
+        ..RawFaceTables::default()
+    });
+
+    assert!(face.has_color_glyphs());
+    assert_eq!(face.color_glyph_format(GlyphId(1)), Some(ColorGlyphFormat::Svg));
+    assert_eq!(face.color_glyph_format(GlyphId(0)), None);
+
+    let face = tables.face(RawFaceTables::default());
+
+    assert!(!face.has_color_glyphs());
+    assert_eq!(face.color_glyph_format(GlyphId(1)), None);
+}
+
+#[test]
+fn document_range() {
+    let svg = convert(&[
+        UInt16(0), // version
+        UInt32(10), // offset to document list
+        UInt32(0), // reserved
+
+        // SVG Document List
+        UInt16(2), // number of records
+        // Record [0]
+        UInt16(1), // start glyph ID
+        UInt16(3), // end glyph ID
+        UInt32(26), // document offset
+        UInt32(3), // document length
+        // Record [1]
+        UInt16(5), // start glyph ID
+        UInt16(5), // end glyph ID
+        UInt32(29), // document offset
+        UInt32(3), // document length
+
+        Raw(b"abc"), // document [0]
+        Raw(b"def"), // document [1]
+    ]);
+
+    let tables = crate::RequiredTables::new(6);
+    let face = tables.face(RawFaceTables {
+        svg: Some(&svg),
+        ..RawFaceTables::default()
+    });
+
+    let doc = face.glyph_svg_document_range(GlyphId(2)).unwrap();
+    assert_eq!(doc.start_glyph_id, GlyphId(1));
+    assert_eq!(doc.end_glyph_id, GlyphId(3));
+    assert_eq!(doc.glyphs_range(), GlyphId(1)..=GlyphId(3));
+    assert_eq!(doc.data, b"abc");
+
+    let doc = face.glyph_svg_document_range(GlyphId(5)).unwrap();
+    assert_eq!(doc.glyphs_range(), GlyphId(5)..=GlyphId(5));
+    assert_eq!(doc.data, b"def");
+
+    assert!(face.glyph_svg_document_range(GlyphId(4)).is_none());
+
+    let documents = face.tables().svg.unwrap().documents;
+    let ranges: Vec<_> = documents.documents().map(|doc| doc.glyphs_range()).collect();
+    assert_eq!(ranges, [GlyphId(1)..=GlyphId(3), GlyphId(5)..=GlyphId(5)]);
+    assert_eq!(documents.documents().count(), 2);
+}
+
+#[cfg(feature = "svgz")]
+mod svgz {
+    use ttf_parser::{Face, GlyphId, ImageKind, RawFaceTables};
+    use crate::{convert, Unit::*};
+
+    fn decompress(document: &[u8]) -> Option<(ImageKind, Vec<u8>)> {
+        let svg = super::svg_table(document);
+        let tables = crate::RequiredTables::new(2);
+        let face = tables.face(RawFaceTables {
+            svg: Some(&svg),
+            ..RawFaceTables::default()
+        });
+
+        let mut out = b"garbage".to_vec();
+        let kind = face.glyph_svg_image_decompressed(GlyphId(1), &mut out)?;
+        Some((kind, out))
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(decompress(b"<svg/>"), Some((ImageKind::Svg, b"<svg/>".to_vec())));
+    }
+
+    #[test]
+    fn compressed() {
+        let data = convert(&[
+            UInt8(0x1F), UInt8(0x8B), // magic
+            UInt8(8), // compression method
+            UInt8(1 << 3), // flags, has a file name
+            UInt32(0), // modification time
+            UInt8(0), // extra flags
+            UInt8(3), // operating system
+            Raw(b"a.svg\0"), // file name
+
+            // A final stored deflate block.
+            UInt8(1),
+            Raw(&[6, 0]), // length, little-endian
+            Raw(&[0xF9, 0xFF]), // one's complement of length
+            Raw(b"<svg/>"),
+
+            UInt32(0), // CRC-32
+            Raw(&[6, 0, 0, 0]), // uncompressed size
+        ]);
+
+        assert_eq!(decompress(&data), Some((ImageKind::Svgz, b"<svg/>".to_vec())));
+
+        // The caller's buffer is reused.
+        let svg = super::svg_table(&data);
+        let tables = crate::RequiredTables::new(2);
+        let face = tables.face(RawFaceTables {
+            svg: Some(&svg),
+            ..RawFaceTables::default()
+        });
+
+        let mut out = Vec::with_capacity(1024);
+        out.extend_from_slice(b"garbage");
+        let ptr = out.as_ptr();
+        assert_eq!(face.glyph_svg_image_decompressed(GlyphId(1), &mut out), Some(ImageKind::Svgz));
+        assert_eq!(out, b"<svg/>");
+        assert_eq!(out.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn too_large() {
+        // Deflate bits are packed starting from the least significant bit.
+        struct BitWriter {
+            data: Vec<u8>,
+            len: usize,
+        }
+
+        impl BitWriter {
+            fn push(&mut self, bit: u8) {
+                if self.len % 8 == 0 {
+                    self.data.push(0);
+                }
+
+                *self.data.last_mut().unwrap() |= bit << (self.len % 8);
+                self.len += 1;
+            }
+
+            // Huffman codes are packed starting from the most significant bit.
+            fn push_code(&mut self, code: u16, len: u8) {
+                for i in (0..len).rev() {
+                    self.push((code >> i) as u8 & 1);
+                }
+            }
+        }
+
+        let mut data = convert(&[
+            UInt8(0x1F), UInt8(0x8B), // magic
+            UInt8(8), // compression method
+            UInt8(0), // flags
+            UInt32(0), // modification time
+            UInt8(0), // extra flags
+            UInt8(3), // operating system
+        ]);
+
+        // A final deflate block with fixed Huffman codes.
+        let mut w = BitWriter { data: Vec::new(), len: 0 };
+        w.push(1); // final
+        w.push(1); // fixed Huffman codes
+        w.push(0);
+        w.push_code(0x30 + u16::from(b'a'), 8); // literal
+        // Each length/distance pair repeats the previous 258 bytes.
+        for _ in 0..ttf_parser::svg::MAX_DECOMPRESSED_SIZE / 258 + 1 {
+            w.push_code(0xC5, 8); // length 258
+            w.push_code(0, 5); // distance 1
+        }
+        w.push_code(0, 7); // end of block
+        data.extend_from_slice(&w.data);
+
+        assert_eq!(decompress(&data), None);
+    }
+
+    #[test]
+    fn malformed() {
+        let data = convert(&[
+            UInt8(0x1F), UInt8(0x8B), // magic
+            UInt8(7), // unknown compression method
+        ]);
+
+        assert_eq!(decompress(&data), None);
+
+        // `out` is left empty on failure.
+        let svg = super::svg_table(&data);
+        let tables = crate::RequiredTables::new(2);
+        let face = tables.face(RawFaceTables {
+            svg: Some(&svg),
+            ..RawFaceTables::default()
+        });
+
+        let mut out = b"garbage".to_vec();
+        assert!(face.glyph_svg_image_decompressed(GlyphId(1), &mut out).is_none());
+        assert!(out.is_empty());
+    }
+}
@@ -18,9 +18,8 @@ fn empty() {
     assert_eq!(table.vertical.sizes.len(), 0);
 }
 
-#[test]
-fn basic() {
-    let data = convert(&[
+fn basic_data() -> Vec<u8> {
+    convert(&[
         Fixed(1.0), // version
         UInt16(0), // format
         UInt16(12), // horizontal data offset
@@ -59,8 +58,12 @@ fn basic() {
         Int16(20),
         Int16(0),
         Int16(0),
-    ]);
+    ])
+}
 
+#[test]
+fn basic() {
+    let data = basic_data();
     let table = Table::parse(&data).unwrap();
 
     assert_eq!(table.horizontal.tracks.len(), 3);
@@ -86,3 +89,26 @@ fn basic() {
     assert_eq!(table.vertical.tracks.len(), 0);
     assert_eq!(table.vertical.sizes.len(), 0);
 }
+
+#[test]
+fn tracking() {
+    let data = basic_data();
+    let table = Table::parse(&data).unwrap();
+
+    assert_eq!(table.horizontal_tracking(1.0, 12.0), Some(50));
+    assert_eq!(table.horizontal_tracking(1.0, 24.0), Some(20));
+    assert_eq!(table.horizontal_tracking(1.0, 18.0), Some(35));
+    assert_eq!(table.horizontal_tracking(-1.0, 18.0), Some(-11));
+    assert_eq!(table.horizontal_tracking(-1.0, 15.0), Some(-13));
+
+    // Sizes outside the range are clamped.
+    assert_eq!(table.horizontal_tracking(1.0, 6.0), Some(50));
+    assert_eq!(table.horizontal_tracking(1.0, 72.0), Some(20));
+
+    // The nearest track is used.
+    assert_eq!(table.horizontal_tracking(0.8, 12.0), Some(50));
+    assert_eq!(table.horizontal_tracking(0.2, 12.0), Some(0));
+    assert_eq!(table.horizontal_tracking(-5.0, 24.0), Some(-7));
+
+    assert_eq!(table.vertical_tracking(0.0, 12.0), None);
+}
@@ -0,0 +1,90 @@
+use ttf_parser::{vdmx::Table, RawFaceTables};
+use crate::{convert, Unit::*};
+
+fn vdmx_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of groups
+        UInt16(2), // number of ratio ranges
+
+        // RatioRange [0]
+        UInt8(1), // char set
+        UInt8(2), // x ratio
+        UInt8(1), // y start ratio
+        UInt8(1), // y end ratio
+
+        // RatioRange [1]
+        UInt8(1), // char set
+        UInt8(0), // x ratio
+        UInt8(0), // y start ratio
+        UInt8(0), // y end ratio
+
+        UInt16(18), // offset [0]
+        UInt16(34), // offset [1]
+
+        // VDMX group [0]
+        UInt16(2), // number of records
+        UInt8(10), // start size
+        UInt8(12), // end size
+        UInt16(10), // pixel height [0]
+        Int16(9), // y max [0]
+        Int16(-3), // y min [0]
+        UInt16(12), // pixel height [1]
+        Int16(11), // y max [1]
+        Int16(-3), // y min [1]
+
+        // VDMX group [1]
+        UInt16(2), // number of records
+        UInt8(10), // start size
+        UInt8(12), // end size
+        UInt16(10), // pixel height [0]
+        Int16(8), // y max [0]
+        Int16(-2), // y min [0]
+        UInt16(12), // pixel height [1]
+        Int16(10), // y max [1]
+        Int16(-3), // y min [1]
+    ])
+}
+
+#[test]
+fn ratios() {
+    let data = vdmx_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.ratios.len(), 2);
+
+    // 2:1
+    let group = table.find_group(2, 1).unwrap();
+    assert_eq!(group.start_size, 10);
+    assert_eq!(group.end_size, 12);
+    assert_eq!(group.get(12).unwrap().y_max, 11);
+
+    // 1:1 falls back to the default ratio.
+    let group = table.find_group(1, 1).unwrap();
+    assert_eq!(group.get(12).unwrap().y_max, 10);
+    assert!(group.get(11).is_none());
+    assert!(table.group(2).is_none());
+}
+
+#[test]
+fn face_metrics() {
+    let data = vdmx_data();
+    let tables = crate::RequiredTables::new(1);
+    let face = tables.face(RawFaceTables {
+        vdmx: Some(&data),
+        ..RawFaceTables::default()
+    });
+
+    assert_eq!(face.vertical_device_metrics(10), Some((8, -2)));
+    assert_eq!(face.vertical_device_metrics(11), None);
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // number of groups
+        UInt16(0), // number of ratio ranges
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
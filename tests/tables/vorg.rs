@@ -0,0 +1,29 @@
+use ttf_parser::vorg::Table;
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+#[test]
+fn basic() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        Int16(880), // default vertical origin
+        UInt16(2), // number of metrics
+        // Metrics [0]
+        UInt16(2), // glyph ID
+        Int16(900), // Y origin
+        // Metrics [1]
+        UInt16(5), // glyph ID
+        Int16(880), // Y origin
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.default_y, 880);
+    assert_eq!(table.default_y_origin(), 880);
+    assert_eq!(table.glyph_y_origin(GlyphId(2)), 900);
+    assert_eq!(table.glyph_y_origin(GlyphId(3)), 880);
+    assert_eq!(table.glyph_y_origin_explicit(GlyphId(2)), Some(900));
+    // Same as default, but still explicit.
+    assert_eq!(table.glyph_y_origin_explicit(GlyphId(5)), Some(880));
+    assert_eq!(table.glyph_y_origin_explicit(GlyphId(3)), None);
+}